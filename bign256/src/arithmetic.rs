@@ -5,7 +5,15 @@
 //!
 //! See table B.1: l = 128.
 
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "critical-section", feature = "std"))
+))]
+compile_error!("`precomputed-tables` feature requires either `critical-section` or `std`");
+
 pub(crate) mod field;
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
 pub(crate) mod scalar;
 
 pub use self::scalar::Scalar;
@@ -15,6 +23,9 @@ use crate::BignP256;
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 use primeorder::{point_arithmetic, PrimeCurveParams};
 
+#[cfg(feature = "precomputed-tables")]
+use once_cell::sync::Lazy;
+
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = primeorder::AffinePoint<BignP256>;
 
@@ -42,4 +53,15 @@ impl PrimeCurveParams for BignP256 {
         FieldElement::ZERO,
         FieldElement::from_hex("6BF7FC3CFB16D69F5CE4C9A351D6835D78913966C408F6521E29CF1804516A93"),
     );
+
+    /// Calculates `k * G` using a precomputed basepoint table.
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        BASEPOINT_TABLE.mul(k)
+    }
 }
+
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: Lazy<primeorder::BasepointTable<BignP256, 33>> =
+    Lazy::new(primeorder::BasepointTable::new);
@@ -0,0 +1,105 @@
+//! Hash-to-curve support for BIGN P-256 using the
+//! `bign-curve256v1_XMD:SHA-256_SSWU_RO_` suite, following [RFC 9380].
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use super::{FieldElement, Scalar};
+use crate::BignP256;
+use elliptic_curve::{
+    consts::U48,
+    hash2curve::{OsswuMap, OsswuMapParams},
+};
+use primeorder::PrimeCurveParams;
+
+primeorder::impl_hash2curve!(BignP256, FieldElement, Scalar, U48);
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        c1: &[
+            0xffff_ffff_ffff_ffd0,
+            0xffff_ffff_ffff_ffff,
+            0xffff_ffff_ffff_ffff,
+            0x3fff_ffff_ffff_ffff,
+        ],
+        c2: FieldElement::from_hex(
+            "4c362989b7ea39a3864161356bc414a11177cfae85bb73e8571617355d32e271",
+        ),
+        map_a: BignP256::EQUATION_A,
+        map_b: BignP256::EQUATION_B,
+        z: FieldElement::from_u64(11).neg(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::BignP256;
+    use hex_literal::hex;
+    use sha2::Sha256;
+
+    primeorder::impl_hash2curve_tests!(
+        BignP256,
+        FieldElement,
+        Scalar,
+        Sha256,
+        b"QUUX-V01-CS02-with-bign-curve256v1_XMD:SHA-256_SSWU_RO_",
+        hex!("75cc9c178ac7b8732bb59934a59551d5f45c533f431f9a1e92cfe5ad9496d909"),
+        [
+            {
+                msg: b"",
+                p_x: hex!("5631d6c7f2622231dd8acb221ab2067d5141d97c334d6ce3b740d89d5e005779"),
+                p_y: hex!("5d9f2b155202b9cd39d4dfadfa8cc911635319bb690db47650a550defdd8dba8"),
+                u_0: hex!("4b26223428f337dd8399fe7ef6dbeaf0813d8c81e6a5a84c64a20314e9b3a546"),
+                u_1: hex!("2c7aed6d2f21a858df1a8177e303b6d9cb8b6fc6e8d344f5b34b2af8146985fa"),
+                q0_x: hex!("240524010808a82003748a96761a3718686e9a984f40c4caafdf4531f950082c"),
+                q0_y: hex!("79402482f842507d890d42015414719cd5bc0b674b59b922a52a0c94808e9b14"),
+                q1_x: hex!("a8e0759dafc4b2e5c7be9f3fc636cc629fc696209a39a38e138f6cde7972bef4"),
+                q1_y: hex!("f0a92422dba98716811faf285fe4933297d9a18ff99709de19fa8955497639f2"),
+            },
+            {
+                msg: b"abc",
+                p_x: hex!("afd7e75d715f13cc9a78f7cbe32b8992e22d53eb46b414b7e58a5a19195ce05e"),
+                p_y: hex!("aeee2465bb70e7a3f7e36e9dd407bc5f8d7abaa1057c4dbbdaa71cd77522ae6e"),
+                u_0: hex!("4e7d59d400b2d7775c046274931ec2d768be0b59f4f34cf2886b044adb8751f0"),
+                u_1: hex!("0b73d377c7ac057b704fe960eabc82e7df993025089e746eb1c6dfc7a5a8ec23"),
+                q0_x: hex!("36e08bbd3e7369cdfa323cbd4c7aeaead4e97e847ac5e5f1d5a4a71e2126f24e"),
+                q0_y: hex!("a5ec270a18e681427383fe0cb3ab738efbb00920b9b120baeea65065dbb8f446"),
+                q1_x: hex!("18d1d5ecc88a6dca0d947a4c81764704d7f83fc21485b0397e9315ad8845d353"),
+                q1_y: hex!("32ec6bbb97f783812dadc1c81075aa785b1653e9cab6cd0f351f45e223d72fa3"),
+            },
+            {
+                msg: b"abcdef0123456789",
+                p_x: hex!("2f069bfdeac2706a35f2ded1e59e44cbc3a55833032b36c46aa87d4465274bf1"),
+                p_y: hex!("6b6386000e1e139345aaf26557ece4f98a36b54c7ebec736bb5967cc448c8cdc"),
+                u_0: hex!("f03c6aab5b7f255213e38962bb6cb32774e7c7b81be230e00a77d2414d33fd3d"),
+                u_1: hex!("eef41255e3d2ef12c7163ddf41bd45531ff07afdebdb64a635a769272cf3f75e"),
+                q0_x: hex!("1b1c0826ed5ad59178a82aefbff8fbb9cec3359b6f63288ec9fac4ceb152c432"),
+                q0_y: hex!("751066a093ed68f67d0368325fa71243e06cf833de3f10202e4791cda0e5af7d"),
+                q1_x: hex!("75d84e3ab4e45fe86a107d5ac780e4935d1bf70885760343ddaabd285f09b330"),
+                q1_y: hex!("0bcdee007bdbb0376e12dcde4d1c2461fd09be3ceb82fc852cc341b7cbea17a8"),
+            },
+            {
+                msg: b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                p_x: hex!("adc299b11c0e9c9d28778f74e2f76a5799244d775d18312b44dbaad8f5c6a5ef"),
+                p_y: hex!("250254698d4a527dc1f7a098db5aeeac53a20764a4f734e4617750cea2021d7f"),
+                u_0: hex!("ff3127f483b55ef54c29129dc752b9fb9097fe191aac4ffb86057423f4d65cf5"),
+                u_1: hex!("e111722c28d98b0eb8d67f6dc84a21fde2dbf13dec91919ba094b25b4ab1a8ed"),
+                q0_x: hex!("59c5d514988b60bf0d3fb1be4ec54c879875c30a104a270a1b20400a61b7daa7"),
+                q0_y: hex!("99c06bdaeb1edbb17b2dd6a6e37cc0055d2c27f3e3a0e44e8f1dfedce1f8a573"),
+                q1_x: hex!("6059954fa825eeaa5554459d672cd44c5dc0d2ee9adcd38ba873eecd2215ef93"),
+                q1_y: hex!("30a8c597392d5f8b8cf4ca1a9d313354d2b3f91d160943a65c567cec201cba29"),
+            },
+            {
+                msg: b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                p_x: hex!("7b16c650957ee9ba151556d0f829a2eb7f92e6675cf51a45a4f577a4cb1fb950"),
+                p_y: hex!("87f6b24a7b72f17d3639e0671793ee597731bb88acef18a5cceaf28481d99277"),
+                u_0: hex!("c2a3985575cd08d695b158142bf7e4440b2325e46b1168c5e9455a2333aa2476"),
+                u_1: hex!("ce90df927428fea3fb93afe6fd0b5866f8bf9b1e310f115f57dfd07e6d32ba05"),
+                q0_x: hex!("29471eb0221d448d25d54b10e52921e6aab36a21ec764926d5cbfd1525b013f8"),
+                q0_y: hex!("50dc1b71c58dc93ef3f41d8761d88eff44e051a0587a1d5426aed03b94624a86"),
+                q1_x: hex!("d2c4bf78564bf332caf08f5a23429ac6df7136247f3acd8a45d6b0b9dc787158"),
+                q1_y: hex!("308855c519d61c4d231e6f72ab433da6ba1eeaa02dc28a15e36b03357b91facd"),
+            },
+        ]
+    );
+}
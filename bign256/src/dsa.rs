@@ -0,0 +1,240 @@
+//! Bign digital signature algorithm as defined in [STB 34.101.45-2013 § 7].
+//!
+//! Messages are hashed with belt-hash (STB 34.101.31), whose object
+//! identifier is bound into every signature, and the one-time key `k` is
+//! generated deterministically from the secret key and the hash as described
+//! in § 6.3.
+//!
+//! Signatures are serialized as the 48-byte string `s0 ‖ s1` defined by the
+//! standard. Note that STB 34.101.45 encodes integers as little endian, whereas
+//! [`FieldBytes`] and [`SecretKey`][`crate::SecretKey`] are big endian.
+//!
+//! ## Usage
+//!
+//! NOTE: requires the `dsa` crate feature enabled, and `rand_core` dependency
+//! with `getrandom` feature enabled.
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use rand_core::OsRng; // requires 'getrandom` feature
+//! use bign256::{
+//!     dsa::{Signature, SigningKey, signature::Signer},
+//!     SecretKey
+//! };
+//!
+//! // Signing
+//! let secret_key = SecretKey::random(&mut OsRng); // serialize with `::to_bytes()`
+//! let signing_key = SigningKey::new(&secret_key);
+//! let verifying_key_bytes = signing_key.verifying_key().to_sec1_bytes();
+//! let message = b"test message";
+//! let signature: Signature = signing_key.sign(message);
+//!
+//! // Verifying
+//! use bign256::dsa::{VerifyingKey, signature::Verifier};
+//!
+//! let verifying_key = VerifyingKey::from_sec1_bytes(&verifying_key_bytes)?;
+//! verifying_key.verify(message, &signature)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [STB 34.101.45-2013 § 7]: https://apmi.bsu.by/assets/files/std/bign-spec294.pdf
+
+mod signing;
+mod verifying;
+
+pub use signature;
+
+pub use self::{signing::SigningKey, verifying::VerifyingKey};
+
+use crate::{FieldBytes, Scalar};
+use belt_hash::{BeltHash, Digest};
+use core::fmt::{self, Debug};
+use elliptic_curve::{ops::Reduce, PrimeField};
+use signature::{Error, Result, SignatureEncoding};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Bign signature serialized as bytes.
+pub type SignatureBytes = [u8; Signature::BYTE_SIZE];
+
+/// DER encoding of the belt-hash object identifier `1.2.112.0.2.0.34.101.31.81`.
+const BELT_HASH_OID: [u8; 11] = [
+    0x06, 0x09, 0x2A, 0x70, 0x00, 0x02, 0x00, 0x22, 0x65, 0x1F, 0x51,
+];
+
+/// Size of `s0` in bytes, i.e. the security level `l = 128` in bits.
+const S0_SIZE: usize = 16;
+
+/// Size of a message hash in bytes.
+const HASH_SIZE: usize = 32;
+
+/// Bign signature.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Signature {
+    /// First part of the signature, `s0 ∈ {0, 1}^l`.
+    s0: [u8; S0_SIZE],
+
+    /// Second part of the signature, `s1 ∈ {0, 1, ..., q - 1}`.
+    s1: Scalar,
+}
+
+impl Signature {
+    /// Size of an encoded Bign signature in bytes.
+    pub const BYTE_SIZE: usize = 48;
+
+    /// Parse a Bign signature from a byte array.
+    pub fn from_bytes(bytes: &SignatureBytes) -> Result<Self> {
+        let (s0_bytes, s1_bytes) = bytes.split_at(S0_SIZE);
+
+        let mut s0 = [0; S0_SIZE];
+        s0.copy_from_slice(s0_bytes);
+
+        let s1 = Option::from(Scalar::from_repr(reverse_bytes(s1_bytes))).ok_or_else(Error::new)?;
+
+        Ok(Self { s0, s1 })
+    }
+
+    /// Parse a Bign signature from a byte slice.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        SignatureBytes::try_from(bytes)
+            .map_err(|_| Error::new())?
+            .try_into()
+    }
+
+    /// Serialize this signature as bytes.
+    pub fn to_bytes(&self) -> SignatureBytes {
+        let mut ret = [0; Self::BYTE_SIZE];
+        let (s0_bytes, s1_bytes) = ret.split_at_mut(S0_SIZE);
+        s0_bytes.copy_from_slice(&self.s0);
+        s1_bytes.copy_from_slice(&reverse_bytes(&self.s1.to_repr()));
+        ret
+    }
+
+    /// Bytes for the `s0` component of a signature.
+    pub fn s0_bytes(&self) -> [u8; S0_SIZE] {
+        self.s0
+    }
+
+    /// Little endian-encoded bytes for the `s1` component of a signature.
+    pub fn s1_bytes(&self) -> FieldBytes {
+        reverse_bytes(&self.s1.to_repr())
+    }
+
+    /// Convert this signature into a byte vector.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    /// Get `s0 + 2^l` as a scalar.
+    fn s0_scalar(&self) -> Scalar {
+        s0_scalar(&self.s0)
+    }
+}
+
+impl Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bign256::dsa::Signature(")?;
+
+        for byte in self.to_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl From<Signature> for SignatureBytes {
+    fn from(signature: Signature) -> SignatureBytes {
+        signature.to_bytes()
+    }
+}
+
+impl From<&Signature> for SignatureBytes {
+    fn from(signature: &Signature) -> SignatureBytes {
+        signature.to_bytes()
+    }
+}
+
+impl SignatureEncoding for Signature {
+    type Repr = SignatureBytes;
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.into()
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::BYTE_SIZE
+    }
+}
+
+impl TryFrom<SignatureBytes> for Signature {
+    type Error = Error;
+
+    fn try_from(signature: SignatureBytes) -> Result<Signature> {
+        Signature::from_bytes(&signature)
+    }
+}
+
+impl TryFrom<&SignatureBytes> for Signature {
+    type Error = Error;
+
+    fn try_from(signature: &SignatureBytes) -> Result<Signature> {
+        Signature::from_bytes(signature)
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Signature> {
+        Signature::from_slice(bytes)
+    }
+}
+
+/// Hash a message with belt-hash (STB 34.101.31-2020 section 6.9).
+fn hash_msg(msg: &[u8]) -> [u8; HASH_SIZE] {
+    BeltHash::digest(msg).into()
+}
+
+/// Compute `s0 = belt-hash(OID(h) ‖ ⟨R⟩_2l ‖ H)` truncated to `l` bits, where
+/// `x` is the big endian-encoded x-coordinate of `R`.
+fn hash_s0(x: &FieldBytes, prehash: &[u8]) -> [u8; S0_SIZE] {
+    let hash = BeltHash::new()
+        .chain_update(BELT_HASH_OID)
+        .chain_update(reverse_bytes(x))
+        .chain_update(prehash)
+        .finalize();
+
+    let mut s0 = [0; S0_SIZE];
+    s0.copy_from_slice(&hash[..S0_SIZE]);
+    s0
+}
+
+/// Interpret a message hash `H` as a little endian integer modulo `q`.
+fn hash_scalar(prehash: &[u8]) -> Scalar {
+    Scalar::reduce_bytes(&reverse_bytes(prehash))
+}
+
+/// Compute `s0 + 2^l` as a scalar, which never overflows `q`.
+fn s0_scalar(s0: &[u8; S0_SIZE]) -> Scalar {
+    let mut bytes = [0; HASH_SIZE];
+    bytes[..S0_SIZE].copy_from_slice(s0);
+    bytes[S0_SIZE] = 1;
+    Scalar::reduce_bytes(&reverse_bytes(&bytes))
+}
+
+/// Convert between the little endian encoding of STB 34.101.45 and the big
+/// endian [`FieldBytes`] used by this crate.
+fn reverse_bytes(bytes: &[u8]) -> FieldBytes {
+    let mut ret = FieldBytes::default();
+
+    for (dst, src) in ret.iter_mut().zip(bytes.iter().rev()) {
+        *dst = *src;
+    }
+
+    ret
+}
@@ -0,0 +1,218 @@
+//! Support for Bign signing.
+//!
+//! ## Algorithm
+//!
+//! ```text
+//! 1: calculate H=h(X), where h is belt-hash
+//! 2: generate the one-time key k in {1, 2, ..., q-1} as described in § 6.3
+//! 3: calculate the elliptic curve point R=[k]G
+//! 4: calculate s0=belt-hash(OID(h) || <R>_2l || H) truncated to l bits
+//! 5: calculate s1=(k - H - (s0 + 2^l)d) mod q
+//! 6: the digital signature of X is S=s0 || s1
+//! ```
+
+#![allow(non_snake_case)]
+
+use super::{
+    hash_msg, hash_s0, hash_scalar, reverse_bytes, s0_scalar, Signature, VerifyingKey,
+    BELT_HASH_OID, HASH_SIZE,
+};
+use crate::{FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, SecretKey};
+use belt_block::{belt_wblock_enc, to_u32};
+use belt_hash::{BeltHash, Digest};
+use core::fmt::{self, Debug};
+use elliptic_curve::{
+    ops::MulByGenerator,
+    point::AffineCoordinates,
+    subtle::{Choice, ConstantTimeEq},
+    zeroize::Zeroize,
+    PrimeField,
+};
+use signature::{hazmat::PrehashSigner, Error, KeypairRef, Result, Signer};
+
+/// Bign secret key used for signing messages and producing signatures.
+///
+/// ## Usage
+///
+/// The [`signature`] crate defines the following traits which are the
+/// primary API for signing:
+///
+/// - [`Signer`]: sign a message using this key
+/// - [`PrehashSigner`]: sign the low-level raw output bytes of a message digest
+#[derive(Clone)]
+pub struct SigningKey {
+    /// Secret key.
+    secret_scalar: NonZeroScalar,
+
+    /// Verifying key for this signing key.
+    verifying_key: VerifyingKey,
+}
+
+impl SigningKey {
+    /// Create signing key from a secret key.
+    pub fn new(secret_key: &SecretKey) -> Self {
+        Self::from_nonzero_scalar(secret_key.to_nonzero_scalar())
+    }
+
+    /// Parse signing key from big endian-encoded bytes.
+    pub fn from_bytes(bytes: &FieldBytes) -> Result<Self> {
+        Self::from_slice(bytes)
+    }
+
+    /// Parse signing key from big endian-encoded byte slice containing a secret
+    /// scalar value.
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        let secret_scalar = NonZeroScalar::try_from(slice).map_err(|_| Error::new())?;
+        Ok(Self::from_nonzero_scalar(secret_scalar))
+    }
+
+    /// Create a signing key from a non-zero scalar.
+    pub fn from_nonzero_scalar(secret_scalar: NonZeroScalar) -> Self {
+        let public_key = PublicKey::from_secret_scalar(&secret_scalar);
+
+        Self {
+            secret_scalar,
+            verifying_key: public_key.into(),
+        }
+    }
+
+    /// Serialize as bytes.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.secret_scalar.to_bytes()
+    }
+
+    /// Borrow the secret [`NonZeroScalar`] value for this key.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This value is key material.
+    ///
+    /// Please treat it with the care it deserves!
+    pub fn as_nonzero_scalar(&self) -> &NonZeroScalar {
+        &self.secret_scalar
+    }
+
+    /// Get the [`VerifyingKey`] which corresponds to this [`SigningKey`].
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Deterministically generate the one-time key `k` from the message hash
+    /// `H` as described in STB 34.101.45-2013 § 6.3.3, without additional data.
+    ///
+    /// ```text
+    /// 1: set θ=belt-hash(OID(h) || <d>_2l || t)
+    /// 2: set r=H
+    /// 3: set r=belt-wblock(r, θ) until r in {1, 2, ..., q-1}, then k=r
+    /// ```
+    ///
+    /// belt-wblock is the wide block encryption of STB 34.101.31-2020
+    /// (section 6.2), keyed with the little endian words of `θ`.
+    fn generate_k(&self, prehash: &[u8]) -> Result<NonZeroScalar> {
+        let mut theta = BeltHash::new()
+            .chain_update(BELT_HASH_OID)
+            .chain_update(reverse_bytes(&self.secret_scalar.to_repr()))
+            .finalize();
+        let mut key = to_u32::<8>(&theta);
+        theta.as_mut_slice().zeroize();
+
+        let mut r = [0; HASH_SIZE];
+        r.copy_from_slice(prehash);
+
+        let k = loop {
+            if belt_wblock_enc(&mut r, &key).is_err() {
+                break Err(Error::new());
+            }
+
+            if let Some(k) = Option::from(NonZeroScalar::from_repr(reverse_bytes(&r))) {
+                break Ok(k);
+            }
+        };
+
+        key.zeroize();
+        r.zeroize();
+        k
+    }
+}
+
+//
+// `*Signer` trait impls
+//
+
+impl PrehashSigner<Signature> for SigningKey {
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature> {
+        if prehash.len() != HASH_SIZE {
+            return Err(Error::new());
+        }
+
+        // 2: generate the one-time key k in {1, 2, ..., q-1} as described in § 6.3
+        let k = self.generate_k(prehash)?;
+
+        // 3: calculate the elliptic curve point R=[k]G
+        let R = ProjectivePoint::mul_by_generator(&k).to_affine();
+
+        // 4: calculate s0=belt-hash(OID(h) || <R>_2l || H) truncated to l bits
+        let s0 = hash_s0(&R.x(), prehash);
+
+        // 5: calculate s1=(k - H - (s0 + 2^l)d) mod q
+        let s1 = *k - hash_scalar(prehash) - s0_scalar(&s0) * *self.secret_scalar;
+
+        // 6: the digital signature of X is S=s0 || s1
+        Ok(Signature { s0, s1 })
+    }
+}
+
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature> {
+        // 1: calculate H=h(X), where h is belt-hash
+        self.sign_prehash(&hash_msg(msg))
+    }
+}
+
+//
+// Other trait impls
+//
+
+impl AsRef<VerifyingKey> for SigningKey {
+    fn as_ref(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+}
+
+impl ConstantTimeEq for SigningKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.secret_scalar.ct_eq(&other.secret_scalar)
+    }
+}
+
+impl Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("verifying_key", &self.verifying_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Constant-time comparison
+impl Eq for SigningKey {}
+impl PartialEq for SigningKey {
+    fn eq(&self, other: &SigningKey) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl From<SecretKey> for SigningKey {
+    fn from(secret_key: SecretKey) -> Self {
+        Self::new(&secret_key)
+    }
+}
+
+impl From<&SecretKey> for SigningKey {
+    fn from(secret_key: &SecretKey) -> Self {
+        Self::new(secret_key)
+    }
+}
+
+impl KeypairRef for SigningKey {
+    type VerifyingKey = VerifyingKey;
+}
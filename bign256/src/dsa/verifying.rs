@@ -0,0 +1,162 @@
+//! Support for verifying Bign signatures.
+//!
+//! ## Algorithm
+//!
+//! ```text
+//! 1: verify whether |S| = 3l, verification failed if not
+//! 2: split S=s0 || s1, verification failed if s1 >= q
+//! 3: calculate H=h(X), where h is belt-hash
+//! 4: calculate the point R=[(s1 + H) mod q]G + [(s0 + 2^l) mod q]Q
+//! 5: verification failed if R is the point at infinity
+//! 6: calculate t=belt-hash(OID(h) || <R>_2l || H) truncated to l bits
+//! 7: verification pass if t = s0, otherwise failed
+//! ```
+
+#![allow(non_snake_case)]
+
+use super::{hash_msg, hash_s0, hash_scalar, Signature, HASH_SIZE};
+use crate::{AffinePoint, BignP256, EncodedPoint, ProjectivePoint, PublicKey};
+use elliptic_curve::{point::AffineCoordinates, sec1::ToEncodedPoint, Group};
+use signature::{hazmat::PrehashVerifier, Error, Result, Verifier};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// Bign public key used for verifying signatures are valid for a given
+/// message.
+///
+/// ## Usage
+///
+/// The [`signature`] crate defines the following traits which are the
+/// primary API for verifying:
+///
+/// - [`Verifier`]: verify a message against a provided key and signature
+/// - [`PrehashVerifier`]: verify the low-level raw output bytes of a message digest
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VerifyingKey {
+    /// Signer's public key.
+    public_key: PublicKey,
+}
+
+impl VerifyingKey {
+    /// Initialize [`VerifyingKey`] from a signer's public key.
+    pub fn new(public_key: PublicKey) -> Self {
+        Self { public_key }
+    }
+
+    /// Initialize [`VerifyingKey`] from a SEC1-encoded public key.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        let public_key = PublicKey::from_sec1_bytes(bytes).map_err(|_| Error::new())?;
+        Ok(Self::new(public_key))
+    }
+
+    /// Initialize [`VerifyingKey`] from an affine point.
+    ///
+    /// Returns an [`Error`] if the given affine point is the additive identity
+    /// (a.k.a. point at infinity).
+    pub fn from_affine(affine: AffinePoint) -> Result<Self> {
+        let public_key = PublicKey::from_affine(affine).map_err(|_| Error::new())?;
+        Ok(Self::new(public_key))
+    }
+
+    /// Borrow the inner [`AffinePoint`] for this public key.
+    pub fn as_affine(&self) -> &AffinePoint {
+        self.public_key.as_affine()
+    }
+
+    /// Convert this [`VerifyingKey`] into the
+    /// `Elliptic-Curve-Point-to-Octet-String` encoding described in
+    /// SEC 1: Elliptic Curve Cryptography (Version 2.0) section 2.3.3
+    /// (page 10).
+    ///
+    /// <http://www.secg.org/sec1-v2.pdf>
+    #[cfg(feature = "alloc")]
+    pub fn to_sec1_bytes(&self) -> Box<[u8]> {
+        self.public_key.to_sec1_bytes()
+    }
+}
+
+//
+// `*Verifier` trait impls
+//
+
+impl PrehashVerifier<Signature> for VerifyingKey {
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<()> {
+        if prehash.len() != HASH_SIZE {
+            return Err(Error::new());
+        }
+
+        // 1-2: s1 < q checked at signature parse time
+
+        // 4: calculate the point R=[(s1 + H) mod q]G + [(s0 + 2^l) mod q]Q
+        // (all inputs are public, so this can be computed in variable time)
+        let R = ProjectivePoint::lincomb_vartime(
+            &ProjectivePoint::generator(),
+            &(signature.s1 + hash_scalar(prehash)),
+            &ProjectivePoint::from(&self.public_key),
+            &signature.s0_scalar(),
+        );
+
+        // 5: verification failed if R is the point at infinity
+        if R.is_identity().into() {
+            return Err(Error::new());
+        }
+
+        // 6: calculate t=belt-hash(OID(h) || <R>_2l || H) truncated to l bits
+        let t = hash_s0(&R.to_affine().x(), prehash);
+
+        // 7: verification pass if t = s0, otherwise failed
+        if t == signature.s0 {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl Verifier<Signature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<()> {
+        // 3: calculate H=h(X), where h is belt-hash
+        self.verify_prehash(&hash_msg(msg), signature)
+    }
+}
+
+//
+// Other trait impls
+//
+
+impl AsRef<AffinePoint> for VerifyingKey {
+    fn as_ref(&self) -> &AffinePoint {
+        self.as_affine()
+    }
+}
+
+impl From<PublicKey> for VerifyingKey {
+    fn from(public_key: PublicKey) -> VerifyingKey {
+        Self::new(public_key)
+    }
+}
+
+impl From<&PublicKey> for VerifyingKey {
+    fn from(public_key: &PublicKey) -> VerifyingKey {
+        Self::new(*public_key)
+    }
+}
+
+impl From<VerifyingKey> for PublicKey {
+    fn from(verifying_key: VerifyingKey) -> PublicKey {
+        verifying_key.public_key
+    }
+}
+
+impl From<&VerifyingKey> for PublicKey {
+    fn from(verifying_key: &VerifyingKey) -> PublicKey {
+        verifying_key.public_key
+    }
+}
+
+impl ToEncodedPoint<BignP256> for VerifyingKey {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        self.as_affine().to_encoded_point(compress)
+    }
+}
@@ -0,0 +1,154 @@
+//! Bign key transport as defined in [STB 34.101.45-2013 § 7.2].
+//!
+//! A key `X` of at least 16 bytes is wrapped for a recipient with
+//! belt-keywrap, using a key `θ` derived from the recipient's public key and
+//! a fresh one-time key. The resulting token also authenticates a 16-byte
+//! [`Header`] `I`, which must be presented again to unwrap the key.
+//!
+//! ## Usage
+//!
+//! NOTE: requires the `kt` crate feature enabled, and `rand_core` dependency
+//! with `getrandom` feature enabled.
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use rand_core::OsRng; // requires 'getrandom` feature
+//! use bign256::{kt, SecretKey};
+//!
+//! let secret_key = SecretKey::random(&mut OsRng); // recipient's key pair
+//! let header = [0; 16];
+//! let key = [0x42; 32];
+//!
+//! // Sender
+//! let token = kt::create_token(&mut OsRng, &secret_key.public_key(), &key, &header)?;
+//!
+//! // Recipient
+//! assert_eq!(kt::unwrap_token(&secret_key, &token, &header)?, key);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Algorithm
+//!
+//! ```text
+//! Token creation:
+//! 1: generate a random k in {1, 2, ..., q-1}
+//! 2: calculate the elliptic curve point R=[k]G
+//! 3: calculate θ=<[k]Q>_2l
+//! 4: the token is Y=<R>_2l || belt-keywrap(X, I, θ)
+//!
+//! Token unwrapping:
+//! 1: error if |Y| < 512 bits
+//! 2: recover R from its x-coordinate <R>_2l, error if it isn't a point on the curve
+//! 3: calculate θ=<[d]R>_2l
+//! 4: calculate X || r=belt-keyunwrap(Y without <R>_2l, θ), error if r != I
+//! ```
+//!
+//! [STB 34.101.45-2013 § 7.2]: https://apmi.bsu.by/assets/files/std/bign-spec294.pdf
+
+#![allow(non_snake_case)]
+
+use crate::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, SecretKey};
+use alloc::vec::Vec;
+use belt_block::{belt_wblock_dec, belt_wblock_enc, to_u32};
+use elliptic_curve::{
+    ops::MulByGenerator,
+    point::{AffineCoordinates, DecompressPoint},
+    rand_core::CryptoRngCore,
+    subtle::{Choice, ConstantTimeEq},
+    zeroize::Zeroize,
+    Error, Result,
+};
+
+/// Header `I` which is bound to a wrapped key.
+pub type Header = [u8; 16];
+
+/// Minimum size of a transported key in bytes.
+pub const MIN_KEY_SIZE: usize = 16;
+
+/// Size of the encoded point `<R>_2l` in bytes.
+const POINT_SIZE: usize = 32;
+
+/// Size of the smallest valid token in bytes.
+const MIN_TOKEN_SIZE: usize = POINT_SIZE + MIN_KEY_SIZE + 16;
+
+/// Wrap `key` for the owner of `public_key`, binding it to `header`.
+///
+/// Returns a token of `48 + key.len()` bytes.
+pub fn create_token(
+    rng: &mut impl CryptoRngCore,
+    public_key: &PublicKey,
+    key: &[u8],
+    header: &Header,
+) -> Result<Vec<u8>> {
+    if key.len() < MIN_KEY_SIZE {
+        return Err(Error);
+    }
+
+    // 1: generate a random k in {1, 2, ..., q-1}
+    let k = NonZeroScalar::random(rng);
+
+    // 2: calculate the elliptic curve point R=[k]G
+    let R = ProjectivePoint::mul_by_generator(&k).to_affine();
+
+    // 3: calculate θ=<[k]Q>_2l
+    let mut theta = shared_key(&(public_key.to_projective() * *k).to_affine());
+
+    // 4: the token is Y=<R>_2l || belt-keywrap(X, I, θ)
+    let mut x = R.x();
+    x.reverse();
+    let mut token = [x.as_slice(), key, header].concat();
+    let wrapped = belt_wblock_enc(&mut token[POINT_SIZE..], &theta);
+    theta.zeroize();
+    wrapped.map_err(|_| Error)?;
+
+    Ok(token)
+}
+
+/// Unwrap the key contained in `token` using the recipient's `secret_key`,
+/// checking that it was bound to `header`.
+pub fn unwrap_token(secret_key: &SecretKey, token: &[u8], header: &Header) -> Result<Vec<u8>> {
+    // 1: error if |Y| < 512 bits
+    if token.len() < MIN_TOKEN_SIZE {
+        return Err(Error);
+    }
+
+    // 2: recover R from its x-coordinate <R>_2l, error if it isn't a point on
+    // the curve (either y-coordinate gives the same shared key)
+    let (x, wrapped) = token.split_at(POINT_SIZE);
+    let mut x = FieldBytes::clone_from_slice(x);
+    x.reverse();
+    let R =
+        Option::<AffinePoint>::from(AffinePoint::decompress(&x, Choice::from(0))).ok_or(Error)?;
+
+    // 3: calculate θ=<[d]R>_2l
+    let mut theta =
+        shared_key(&(ProjectivePoint::from(R) * *secret_key.to_nonzero_scalar()).to_affine());
+
+    // 4: calculate X || r=belt-keyunwrap(Y without <R>_2l, θ), error if r != I
+    let mut key = wrapped.to_vec();
+    let unwrapped = belt_wblock_dec(&mut key, &theta);
+    theta.zeroize();
+    unwrapped.map_err(|_| Error)?;
+
+    let key_len = wrapped.len().saturating_sub(header.len());
+    if key[key_len..].ct_eq(header).into() {
+        key.truncate(key_len);
+        Ok(key)
+    } else {
+        key.zeroize();
+        Err(Error)
+    }
+}
+
+/// Compute the belt-keywrap key `θ`, i.e. the little endian-encoded
+/// x-coordinate of the shared point.
+fn shared_key(point: &AffinePoint) -> [u32; 8] {
+    let mut x = point.x();
+    x.reverse();
+
+    let key = to_u32(&x);
+    x.zeroize();
+    key
+}
@@ -29,6 +29,13 @@
 #[allow(unused_extern_crates)]
 extern crate alloc;
 
+#[cfg(feature = "dsa")]
+pub mod dsa;
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+#[cfg(feature = "kt")]
+pub mod kt;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
@@ -0,0 +1,132 @@
+//! Bign signature tests.
+
+#![cfg(feature = "dsa")]
+
+use bign256::{
+    dsa::{
+        signature::{
+            hazmat::{PrehashSigner, PrehashVerifier},
+            Signer, Verifier,
+        },
+        Signature, SigningKey, VerifyingKey,
+    },
+    EncodedPoint, NonZeroScalar, Scalar, U256,
+};
+use elliptic_curve::{ops::Reduce, sec1::ToEncodedPoint};
+use hex_literal::hex;
+use proptest::prelude::*;
+
+// Test vectors from STB 34.101.45-2013 Appendix G (table G.2), where integers
+// and points are little endian-encoded
+const SECRET_KEY: [u8; 32] =
+    hex!("1F66B5B84B7339674533F0329C74F21834281FED0732429E0C79235FC273E269");
+const PUBLIC_KEY: [u8; 64] = hex!(
+    "BD1A5650179D79E03FCEE49D4C2BD5DDF54CE46D0CF11E4FF87BF7A890857FD0"
+    "7AC6A60361E8C8173491686D461B2826190C2EDA5909054A9AB84D2AB9D99A90"
+);
+
+/// Signature with a deterministically generated one-time key.
+const MSG_1: [u8; 13] = hex!("B194BAC80A08F53B366D008E58");
+const HASH_1: [u8; 32] = hex!("ABEF9725D4C5A83597A367D14494CC2542F20F659DDFECC961A3EC550CBA8C75");
+const SIGNATURE_1: [u8; 48] = hex!(
+    "19D32B7E01E25BAE4A70EB6BCA42602C"
+    "CA6A13944451BCC5D4C54CFD8737619C328B8A58FB9C68FD17D569F7D06495FB"
+);
+
+/// Signature with a random one-time key.
+const MSG_2: [u8; 48] = hex!(
+    "B194BAC80A08F53B366D008E584A5DE48504FA9D1BB6C7AC252E72C202FDCE0D"
+    "5BE3D61217B96181FE6786AD716B890B"
+);
+const SIGNATURE_2: [u8; 48] = hex!(
+    "47A63C8B9C936E94B5FAB3D9CBD78366"
+    "290F3210E163EEC8DB4E921E8479D4138F112CC23E6DCE65EC5FF21DF4231C28"
+);
+
+/// Reverse the byte order of a little endian-encoded value.
+fn reversed<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut ret = [0; N];
+    ret.copy_from_slice(bytes);
+    ret.reverse();
+    ret
+}
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&reversed::<32>(&SECRET_KEY).into()).unwrap()
+}
+
+#[test]
+fn public_key_test_vector() {
+    let expected = EncodedPoint::from_affine_coordinates(
+        &reversed::<32>(&PUBLIC_KEY[..32]).into(),
+        &reversed::<32>(&PUBLIC_KEY[32..]).into(),
+        false,
+    );
+    let verifying_key = signing_key().verifying_key().to_encoded_point(false);
+    assert_eq!(verifying_key, expected);
+    assert!(VerifyingKey::from_sec1_bytes(expected.as_bytes()).is_ok());
+}
+
+#[test]
+fn sign_test_vector() {
+    let signing_key = signing_key();
+
+    let signature: Signature = signing_key.sign(&MSG_1);
+    assert_eq!(signature.to_bytes(), SIGNATURE_1);
+
+    let signature: Signature = signing_key.sign_prehash(&HASH_1).unwrap();
+    assert_eq!(signature.to_bytes(), SIGNATURE_1);
+}
+
+#[test]
+fn verify_test_vectors() {
+    let verifying_key = *signing_key().verifying_key();
+
+    for (msg, signature) in [(&MSG_1[..], SIGNATURE_1), (&MSG_2, SIGNATURE_2)] {
+        let signature = Signature::try_from(signature).unwrap();
+        assert!(verifying_key.verify(msg, &signature).is_ok());
+    }
+
+    let signature = Signature::try_from(SIGNATURE_1).unwrap();
+    assert!(verifying_key.verify_prehash(&HASH_1, &signature).is_ok());
+    assert!(verifying_key.verify(&MSG_2, &signature).is_err());
+}
+
+#[test]
+fn reject_s1_out_of_range() {
+    let mut bytes = SIGNATURE_1;
+    bytes[16..].fill(0xFF);
+    assert!(Signature::try_from(bytes).is_err());
+    assert!(Signature::from_slice(&SIGNATURE_1[..47]).is_err());
+}
+
+prop_compose! {
+    fn signing_key_strategy()(bytes in any::<[u8; 32]>()) -> SigningKey {
+        loop {
+            let scalar = <Scalar as Reduce<U256>>::reduce_bytes(&bytes.into());
+            if let Some(scalar) = Option::from(NonZeroScalar::new(scalar)) {
+                return SigningKey::from_nonzero_scalar(scalar);
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn sign_and_verify(sk in signing_key_strategy(), msg in prop::collection::vec(any::<u8>(), 0..100)) {
+        let signature: Signature = sk.sign(&msg);
+        prop_assert!(sk.verifying_key().verify(&msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn reject_invalid_signature(sk in signing_key_strategy(), byte in 0usize..48, bit in 0usize..8) {
+        let mut signature_bytes = sk.sign(&MSG_1).to_bytes();
+
+        // tweak signature to make it invalid
+        signature_bytes[byte] ^= 1 << bit;
+
+        if let Ok(signature) = Signature::try_from(signature_bytes) {
+            prop_assert!(sk.verifying_key().verify(&MSG_1, &signature).is_err());
+        }
+    }
+}
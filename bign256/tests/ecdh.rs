@@ -0,0 +1,46 @@
+//! Bign Diffie-Hellman tests.
+
+#![cfg(feature = "ecdh")]
+
+use bign256::{ecdh::diffie_hellman, NonZeroScalar, PublicKey, SecretKey};
+use elliptic_curve::point::AffineCoordinates;
+use hex_literal::hex;
+
+// Key transport example from STB 34.101.45-2013 Appendix G, where
+// integers and points are little endian-encoded. The sender's one-time key
+// `k` and the recipient's secret key `d` agree on the shared point
+// `[k]Q=[d]R`. Unwrapping the transported key is covered by the key
+// transport tests.
+const SECRET_KEY: [u8; 32] =
+    hex!("1F66B5B84B7339674533F0329C74F21834281FED0732429E0C79235FC273E269");
+const K: [u8; 32] = hex!("0F51D91347617C20BD4AB07AEF4F26A1AD1362A8F9A3D42FBE1B8E6F1C88AAD5");
+const TOKEN: [u8; 66] = hex!(
+    "9B4EA669DABDF100A7D4B6E6EB76EE5251912531F426750AAC8A9DBB51C54D8D"
+    "EB9289B50A46952D0531861E45A8814B008FDC65DE9FF1FA2A1F16B6A280E957"
+    "A814"
+);
+
+/// Reverse the byte order of a little endian-encoded value.
+fn reversed(bytes: &[u8]) -> [u8; 32] {
+    let mut ret = [0; 32];
+    ret.copy_from_slice(bytes);
+    ret.reverse();
+    ret
+}
+
+#[test]
+fn shared_secret_test_vector() {
+    let secret_key = SecretKey::from_slice(&reversed(&SECRET_KEY)).unwrap();
+    let k = NonZeroScalar::try_from(reversed(&K).as_slice()).unwrap();
+
+    // R=[k]G is the first half of the token
+    let r = PublicKey::from_secret_scalar(&k);
+    assert_eq!(r.as_affine().x().as_slice(), reversed(&TOKEN[..32]));
+
+    let sender_shared = diffie_hellman(k, secret_key.public_key().as_affine());
+    let recipient_shared = diffie_hellman(secret_key.to_nonzero_scalar(), r.as_affine());
+    assert_eq!(
+        sender_shared.raw_secret_bytes(),
+        recipient_shared.raw_secret_bytes()
+    );
+}
@@ -0,0 +1,101 @@
+//! Bign key transport tests.
+
+#![cfg(feature = "kt")]
+
+use bign256::{kt, SecretKey};
+use elliptic_curve::rand_core::OsRng;
+use hex_literal::hex;
+use primeorder::dev::FixedRng;
+use proptest::prelude::*;
+
+// Test vector from STB 34.101.45-2013 Appendix G, where integers and points
+// are little endian-encoded
+const SECRET_KEY: [u8; 32] =
+    hex!("1F66B5B84B7339674533F0329C74F21834281FED0732429E0C79235FC273E269");
+const K: [u8; 32] = hex!("0F51D91347617C20BD4AB07AEF4F26A1AD1362A8F9A3D42FBE1B8E6F1C88AAD5");
+const KEY: [u8; 18] = hex!("B194BAC80A08F53B366D008E584A5DE48504");
+const HEADER: [u8; 16] = hex!("5BE3D61217B96181FE6786AD716B890B");
+const TOKEN: [u8; 66] = hex!(
+    "9B4EA669DABDF100A7D4B6E6EB76EE5251912531F426750AAC8A9DBB51C54D8D"
+    "EB9289B50A46952D0531861E45A8814B008FDC65DE9FF1FA2A1F16B6A280E957"
+    "A814"
+);
+
+/// Reverse the byte order of a little endian-encoded value.
+fn reversed(bytes: &[u8; 32]) -> [u8; 32] {
+    let mut ret = *bytes;
+    ret.reverse();
+    ret
+}
+
+fn secret_key() -> SecretKey {
+    SecretKey::from_slice(&reversed(&SECRET_KEY)).unwrap()
+}
+
+#[test]
+fn create_token_test_vector() {
+    let public_key = secret_key().public_key();
+    let token = kt::create_token(&mut FixedRng(&reversed(&K)), &public_key, &KEY, &HEADER).unwrap();
+    assert_eq!(token, TOKEN);
+}
+
+#[test]
+fn unwrap_token_test_vector() {
+    assert_eq!(
+        kt::unwrap_token(&secret_key(), &TOKEN, &HEADER).unwrap(),
+        KEY
+    );
+}
+
+#[test]
+fn reject_short_key() {
+    let public_key = secret_key().public_key();
+    assert!(kt::create_token(&mut OsRng, &public_key, &KEY[..15], &HEADER).is_err());
+    assert!(kt::unwrap_token(&secret_key(), &TOKEN[..63], &HEADER).is_err());
+}
+
+#[test]
+fn reject_invalid_point() {
+    let mut token = TOKEN;
+    token[..32].fill(0xFF);
+    assert!(kt::unwrap_token(&secret_key(), &token, &HEADER).is_err());
+}
+
+fn secret_key_strategy() -> impl Strategy<Value = SecretKey> {
+    any::<[u8; 32]>().prop_filter_map("invalid secret key", |bytes| {
+        SecretKey::from_slice(&bytes).ok()
+    })
+}
+
+proptest! {
+    #[test]
+    fn create_and_unwrap(
+        sk in secret_key_strategy(),
+        key in prop::collection::vec(any::<u8>(), 16..100),
+        header in any::<[u8; 16]>(),
+    ) {
+        let token = kt::create_token(&mut OsRng, &sk.public_key(), &key, &header).unwrap();
+        prop_assert_eq!(token.len(), 48 + key.len());
+        prop_assert_eq!(kt::unwrap_token(&sk, &token, &header).unwrap(), key);
+    }
+
+    #[test]
+    fn reject_wrong_header(sk in secret_key_strategy(), byte in 0usize..16, bit in 0usize..8) {
+        let token = kt::create_token(&mut OsRng, &sk.public_key(), &KEY, &HEADER).unwrap();
+
+        let mut header = HEADER;
+        header[byte] ^= 1 << bit;
+
+        prop_assert!(kt::unwrap_token(&sk, &token, &header).is_err());
+    }
+
+    #[test]
+    fn reject_invalid_token(sk in secret_key_strategy(), byte in 32usize..66, bit in 0usize..8) {
+        let mut token = kt::create_token(&mut OsRng, &sk.public_key(), &KEY, &HEADER).unwrap();
+
+        // tweak the wrapped key to make the token invalid
+        token[byte] ^= 1 << bit;
+
+        prop_assert!(kt::unwrap_token(&sk, &token, &HEADER).is_err());
+    }
+}
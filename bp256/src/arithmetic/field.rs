@@ -94,7 +94,7 @@ impl FieldElement {
 
     /// Returns the big-endian encoding of this [`FieldElement`].
     pub fn to_bytes(self) -> FieldBytes {
-        self.0.to_be_byte_array()
+        self.to_canonical().to_be_byte_array()
     }
 
     /// Translate [`FieldElement`] out of the Montgomery domain, returning a
@@ -254,7 +254,7 @@ primeorder::impl_mont_field_element_arithmetic!(
 
 impl Debug for FieldElement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "FieldElement(0x{:X})", &self.0)
+        write!(f, "FieldElement(0x{:X})", &self.to_canonical())
     }
 }
 
@@ -1,12 +1,15 @@
 //! brainpoolP256r1 elliptic curve: verifiably pseudo-random variant
 
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+
+#[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
-#[cfg(feature = "wip-arithmetic-do-not-use")]
+#[cfg(feature = "arithmetic")]
 mod arithmetic;
 
-#[cfg(feature = "wip-arithmetic-do-not-use")]
+#[cfg(feature = "arithmetic")]
 pub use {
     self::arithmetic::{AffinePoint, ProjectivePoint},
     crate::Scalar,
@@ -67,8 +70,12 @@ impl FieldBytesEncoding<BrainpoolP256r1> for U256 {
     }
 }
 
+/// brainpoolP256r1 public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<BrainpoolP256r1>;
+
 /// brainpoolP256r1 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<BrainpoolP256r1>;
 
-#[cfg(not(feature = "wip-arithmetic-do-not-use"))]
+#[cfg(not(feature = "arithmetic"))]
 impl elliptic_curve::sec1::ValidatePublicKey for BrainpoolP256r1 {}
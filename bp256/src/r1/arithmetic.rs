@@ -1,5 +1,8 @@
 //! brainpoolP256r1 curve arithmetic implementation.
 
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
+
 use super::BrainpoolP256r1;
 use crate::{FieldElement, Scalar};
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
@@ -0,0 +1,107 @@
+//! Hash-to-curve support for brainpoolP256r1 using the
+//! `brainpoolP256r1_XMD:SHA-256_SSWU_RO_` suite, following [RFC 9380].
+//!
+//! brainpoolP256t1 shares the same base field, so only one of the two curves
+//! can provide [`OsswuMap`] for [`FieldElement`]; it is not supported.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use crate::{BrainpoolP256r1, FieldElement, Scalar};
+use elliptic_curve::{
+    consts::U48,
+    hash2curve::{OsswuMap, OsswuMapParams},
+};
+use primeorder::PrimeCurveParams;
+
+primeorder::impl_hash2curve!(BrainpoolP256r1, FieldElement, Scalar, U48);
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        c1: &[
+            0x0804_d207_47db_94dd,
+            0x9b8e_fd88_f549_880a,
+            0x0f99_82a4_2760_e35c,
+            0x2a7e_d5f6_e87b_aa6f,
+        ],
+        c2: FieldElement::from_hex(
+            "6284fe95994e49a63c5c4e1cafff32b3bd634dafc6da95cf2d030a82b77f3d98",
+        ),
+        map_a: BrainpoolP256r1::EQUATION_A,
+        map_b: BrainpoolP256r1::EQUATION_B,
+        z: FieldElement::from_u64(2).neg(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::BrainpoolP256r1;
+    use hex_literal::hex;
+    use sha2::Sha256;
+
+    primeorder::impl_hash2curve_tests!(
+        BrainpoolP256r1,
+        FieldElement,
+        Scalar,
+        Sha256,
+        b"QUUX-V01-CS02-with-brainpoolP256r1_XMD:SHA-256_SSWU_RO_",
+        hex!("2d05a25943f9a2f2b2e61a9610f09d41463ad724ea8eaf10d9ca9e87a9b3a24d"),
+        [
+            {
+                msg: b"",
+                p_x: hex!("9a484fdf34de4fafd202075830da780348ebefcf393fa76d5d61cd7081d97e17"),
+                p_y: hex!("73048c0ac3a1ecf76942fde05a8db5b77c18810af756c14a79b46be0541d547a"),
+                u_0: hex!("50a525548003245aa523f0c425b3e5ed58778768a0ce8ad973806055a53a0890"),
+                u_1: hex!("841667ad201228236a75d7765e6594a56480454d67dbbe4f379dc7df9dde9aa0"),
+                q0_x: hex!("5aee1c22b0e53943c6fab53e37e7fcbc7d7a8b15baf1d17fcba8a2b808e45945"),
+                q0_y: hex!("3474b3c9c138f264c2e91f8937cfd0df2244d6b5407ceb6cc2623305469d8016"),
+                q1_x: hex!("3749e8da2731e5706adb82f55b4ae57bc6dc3f809f45464a499b4f197bd9d13f"),
+                q1_y: hex!("93fa2e6d1310c4e33836ff5e1a3baf6f38636b474ee936fe8a4c87451ab97440"),
+            },
+            {
+                msg: b"abc",
+                p_x: hex!("3bbca5dc555331323759629f56baf39060e18f13886b9511a4980b89960ec595"),
+                p_y: hex!("2712d6633c2d6c5e144b60350a137c190c25a2e993f5be0cde6b6b03222e3e57"),
+                u_0: hex!("5afadb6895c054615a083e51c17eb74aa0935f5b2fcc16371969edecc1572933"),
+                u_1: hex!("0226260c382ec4b26943fa652269131c4e547571335198c1dbd23ec63c0d8d85"),
+                q0_x: hex!("a4eec814a2f48333f46b6e75aef3551a16c96050ff3c1dabdc763dce255e05d7"),
+                q0_y: hex!("6dd6134e3cfce3242e0a61abadfe741463e5c8af6ab0bcf7027fc4b620bcd5a5"),
+                q1_x: hex!("7afcf58dd34a165efb182a5d79bdaf9aa90b6689de1e91d98aa467f0f0c46c06"),
+                q1_y: hex!("25475d965da07a8dfff743d77461f1226fccd2b8d10889afd2db2ff0e338dc5d"),
+            },
+            {
+                msg: b"abcdef0123456789",
+                p_x: hex!("3bb7ee9b2bf274c66c87c6788be8abb71ba1c75ee57daf3db9afd9ef2ecb527e"),
+                p_y: hex!("9ec01f986a2fe6521fc5dfd6835595c7139d3190a3071457f1542f80105837ce"),
+                u_0: hex!("0a00aaeeb169d61607aa8c7953240ff88aa6357b548bbe65b0d3eb2cdf3dc8d9"),
+                u_1: hex!("297760cdc5e86409498e9cbfc2d2b4c01be97da9e293ed71fa67c9f0ff721b68"),
+                q0_x: hex!("40292937fe39f2354f08f25ebd925a5bea6856af052793667a7ca6e2f3cb2a7b"),
+                q0_y: hex!("5ea8841da06c2e806b0d6febb4aa652a463dd0c261bdf07d051277716ccd5c59"),
+                q1_x: hex!("6404cdd0ab46e275760810a7a06eacc31f4b1f39ae2e754862c039db16687e52"),
+                q1_y: hex!("468587e3e90665e11aa912cbdbf1c2ab558c5d7bbf0573fff83a4f873cbdd2fe"),
+            },
+            {
+                msg: b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                p_x: hex!("1a7b3b35bb22cc709afe802e936cfc5a23f5444ee7fd586d7689a49e41ced19d"),
+                p_y: hex!("0d047ef0a0a27e982677819bb866c4bc4b986ac96d92c28b7fa56de00eb4aef2"),
+                u_0: hex!("69c6d705eb7a45613ab51b3e5594b62ac8ac5ac9ae3d14ae8b4ac96630d79157"),
+                u_1: hex!("76a0dbf5cca65c566e9bcacebce83ddc83393119143531eab56685b7cbbc8852"),
+                q0_x: hex!("1bf0fa3f6af993c4f3a2c59ee177308499b6dd90eb62564ba67a99565654edc0"),
+                q0_y: hex!("06491e44ae2176a98f0ec88ca91bb0b8a76015f34ab309033abd8983742a5195"),
+                q1_x: hex!("5bb861d5259ba46c93405899ee042be5332ecb517e69d405b868aead965f3454"),
+                q1_y: hex!("7949f69820a32c5e000a1d3c287586e6f9498d208b6c20dddfaf58d2b7544cf2"),
+            },
+            {
+                msg: b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                p_x: hex!("6195a764f643d3bc572b7537004e60c15ee001ae612ef3cb8e48ba5e31426e95"),
+                p_y: hex!("66e545a2d210ed4d44e275f2daa323961d25cccf2d46b516487e4f7fa1e2c5c6"),
+                u_0: hex!("88a4ce7a7ae4dd221f39af49ba19df0141956ce50e95d0e8f5cb3c328e00f198"),
+                u_1: hex!("201e835176218bf61fc2fed72866bc065f1ed4fe6e412839360d84fb1e7807c5"),
+                q0_x: hex!("07ea3cb5ee3c29d02307f6fe80331887209a79e202e2cd5ce660c678ee7d13a4"),
+                q0_y: hex!("4252200225d21f35150fdf8d8d013cce7022aed490aada195f4bd9386c1ac8a8"),
+                q1_x: hex!("441feabba6aaa08c36d216c416321ed3cf4abb48663cb4509a80b6288c0d16f2"),
+                q1_y: hex!("72f9b4e4a4223c12d061b0179d47fcd4efb46371d61986cd8f9213cdcc05be75"),
+            },
+        ]
+    );
+}
@@ -0,0 +1,4 @@
+//! brainpoolP256 test vectors.
+
+pub mod r1;
+pub mod t1;
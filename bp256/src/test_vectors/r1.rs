@@ -0,0 +1,3 @@
+//! brainpoolP256r1 test vectors.
+
+pub mod group;
@@ -0,0 +1,113 @@
+//! Test vectors for the brainpoolP256r1 group.
+
+use hex_literal::hex;
+
+/// Repeated addition of the generator.
+///
+/// These are the multiples `kG` for `k = 1..=20`, where `G` is the generator of
+/// brainpoolP256r1 from RFC 5639 § 3.4.
+pub const ADD_TEST_VECTORS: &[([u8; 32], [u8; 32])] = &[
+    (
+        hex!("8BD2AEB9CB7E57CB2C4B482FFC81B7AFB9DE27E1E3BD23C23A4453BD9ACE3262"),
+        hex!("547EF835C3DAC4FD97F8461A14611DC9C27745132DED8E545C1D54C72F046997"),
+    ),
+    (
+        hex!("743CF1B8B5CD4F2EB55F8AA369593AC436EF044166699E37D51A14C2CE13EA0E"),
+        hex!("36ED163337DEBA9C946FE0BB776529DA38DF059F69249406892ADA097EEB7CD4"),
+    ),
+    (
+        hex!("A8F217B77338F1D4D6624C3AB4F6CC16D2AA843D0C0FCA016B91E2AD25CAE39D"),
+        hex!("4B49CAFC7DAC26BB0AA2A6850A1B40F5FAC10E4589348FB77E65CC5602B74F9D"),
+    ),
+    (
+        hex!("3672030BACE787AA319E21D40645B2999006BEEC437FD084DD3FC592F5FCD77C"),
+        hex!("335B226CE5FAC0C36A18CE42E95F43C9EED3E256BDD0C98E55A069595515D15B"),
+    ),
+    (
+        hex!("855433A3A4C8E334A5F863E8B69FC1477CF41589C0D8C3FB32F95F7C85FE101D"),
+        hex!("A50C95EFC2AD06C4D7E172E40350D911097082129591C88BEF9E224A5FD8814C"),
+    ),
+    (
+        hex!("78EA164AA2A74A67A04B680BD8BB1384E7CC4DB8774C50ECB9DFB344771026B1"),
+        hex!("10D988FF681802469B49D341F8DA0A2500CAD34F1E745B1437E336573D08B1BE"),
+    ),
+    (
+        hex!("6B8BB7F53E36B6824D3300AFBC27257BD432568E24E5FB5702295ECD04E9DE4C"),
+        hex!("382F9AF51CE9A3D30965A09661223AF5646067C55B1A928F7252376BFC79EBF0"),
+    ),
+    (
+        hex!("545A6FAF6B031B267409483A38D1942C91DB2B4EB917D2BDDA994B4CB3985461"),
+        hex!("76F4942D7CA7B4143CBEDFC72C7A65194596BDA3D83213BBCFB32792456303FC"),
+    ),
+    (
+        hex!("8B5FA06D31D59D690811364099019B7CD283BD714A67C06A420D27D6784F8F12"),
+        hex!("41E0E0C34464B5C7AE64ED13D26D038E146F15EEA266B22842BE764F293B3348"),
+    ),
+    (
+        hex!("A4348DB079F7FFBCFB3DFC35BD8AC67C22A85A50025CB1F37A22BA81728B1CAF"),
+        hex!("2444FA0F5B79BE1A2BD1D073C38FD136C77977F417B550D954E46DC4C8B737C1"),
+    ),
+    (
+        hex!("50EA43E33D2D48978DDC9C5870EA163180C350B1E1DB41B03406AFFFDE3EEED0"),
+        hex!("4685DCECA1753941782129D70CEEB10F951970A9B39A21F923AF9BDADF6FBD40"),
+    ),
+    (
+        hex!("7E21EAAF386828A98FCD5B4F07C9E855E4035E293FBD18273BEE7E520810F159"),
+        hex!("6B00DA07D32CE8A06DD01764C1D87A3B67C6EA5B590D0CA7BB74AD0B29B9C160"),
+    ),
+    (
+        hex!("8D4243F928EE1B6A7862AC771CE2CB743439BBF4E2B459B662C969C86253556B"),
+        hex!("6CB4B54150658725C257D5E888EB9DADF5C5AFBB15E5C033616A664E902CB740"),
+    ),
+    (
+        hex!("1D36A037AB842C1D557513E3B04D9166A09AA186EE1E9916674D33A6C2B6B191"),
+        hex!("5B811A55DD8BF3FB10D4FF18900017E9290D2F38DB9B105035E15701BC4413E6"),
+    ),
+    (
+        hex!("04306F8D5631EE7AC6E07A490CEE907848E0917A7D5EDC4B7A309A0B21557A8E"),
+        hex!("2AB9E5213104BC7F3AA032DAF9FFD870A510F13A83E146A29377C731F7E833BD"),
+    ),
+    (
+        hex!("653583661EF339866B0798FB767757ED3543957E92F08735B3DDCF32EAA36568"),
+        hex!("A6B73D0616FF459ABE017D72168A0385212B4EA2D5069F1615B7EE3666C078E1"),
+    ),
+    (
+        hex!("884D1F975768CE45C3663E5F98B9DAFADA7B65585BFBAADA0B73FCB1A0C86824"),
+        hex!("569EA2DD9A21654F601705FE7F902E370F42120813FC7E966205D05254C57AD7"),
+    ),
+    (
+        hex!("81380431B06D64672F370DE2AF51D2E7CFC0B0E92651132FBEBDA3814E33C774"),
+        hex!("3E919A4860E8D356F353B53494AC6524F1CAFCA72258A0B56AD1A9E34BEC5265"),
+    ),
+    (
+        hex!("09A299ED5649E1EA8C6280C5F7796CDB20411E949B5343F7A58EAB8AB0AFBAE7"),
+        hex!("3EE027407A6089DFF82C6EB87B8812EA899ADED51179513114F5E940D854688A"),
+    ),
+    (
+        hex!("0ECA4BE77F21E1EC417F28FF285452EC726F66AFA4739CC4EA40FDD1C2EF7ED8"),
+        hex!("7013CF6067CB57284BCA029B1CD89E9727D3447EAC29314A7CA1FF58D3C20BD2"),
+    ),
+];
+
+/// Scalar multiplication with the generator.
+///
+/// These are the key pairs `(dA, QA)` and `(dB, QB)` of the brainpoolP256r1 test
+/// vectors in RFC 7027 Appendix A, followed by their shared point as the
+/// multiple `(dA * dB mod q)G`.
+pub const MUL_TEST_VECTORS: &[([u8; 32], [u8; 32], [u8; 32])] = &[
+    (
+        hex!("81DB1EE100150FF2EA338D708271BE38300CB54241D79950F77B063039804F1D"),
+        hex!("44106E913F92BC02A1705D9953A8414DB95E1AAA49E81D9E85F929A8E3100BE5"),
+        hex!("8AB4846F11CACCB73CE49CBDD120F5A900A69FD32C272223F789EF10EB089BDC"),
+    ),
+    (
+        hex!("55E40BC41E37E3E2AD25C3C6654511FFA8474A91A0032087593852D3E7D76BD3"),
+        hex!("8D2D688C6CF93E1160AD04CC4429117DC2C41825E1E9FCA0ADDD34E6F1B39F7B"),
+        hex!("990C57520812BE512641E47034832106BC7D3E8DD0E4C7F1136D7006547CEC6A"),
+    ),
+    (
+        hex!("73C6C9BF9E42BD2ED725AABE919B1E1F7B42C301275D2E8C257D49C98D72B537"),
+        hex!("89AFC39D41D3B327814B80940B042590F96556EC91E6AE7939BCE31F3A18BF2B"),
+        hex!("49C27868F4ECA2179BFD7D59B1E3BF34C1DBDE61AE12931648F43E59632504DE"),
+    ),
+];
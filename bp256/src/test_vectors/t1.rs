@@ -0,0 +1,3 @@
+//! brainpoolP256t1 test vectors.
+
+pub mod group;
@@ -0,0 +1,121 @@
+//! Test vectors for the brainpoolP256t1 group.
+
+use hex_literal::hex;
+
+/// Repeated addition of the generator.
+///
+/// These are the multiples `kG` for `k = 1..=20`, where `G` is the generator of
+/// brainpoolP256t1 from RFC 5639 § 3.4.
+pub const ADD_TEST_VECTORS: &[([u8; 32], [u8; 32])] = &[
+    (
+        hex!("A3E8EB3CC1CFE7B7732213B23A656149AFA142C47AAFBC2B79A191562E1305F4"),
+        hex!("2D996C823439C56D7F7B22E14644417E69BCB6DE39D027001DABE8F35B25C9BE"),
+    ),
+    (
+        hex!("8338427C7CF4D11CB981D9B18793E3779C494C502C75BD739E578DE2A700578D"),
+        hex!("546B03682557E9F72E9D6ECD39F5BBF241BC1CF07808F04A9948B25BF2378AFF"),
+    ),
+    (
+        hex!("46B2A45FDD881ABEA0CB4E5FEA19C5A72D399245643B06E0FBE24A5E4058D806"),
+        hex!("4F88CD8D4BC69ACC7B7032D98460B2C23160441F40562C00BEE2AA7860C19AA8"),
+    ),
+    (
+        hex!("829BBF8DDB256013451532C1F1BCF55785B2C4A6655155D244C3E62153B7395B"),
+        hex!("9C971C3F0BD8D687AECE4F3AECACE8CA20AED0579235F35726F4B209B1457A22"),
+    ),
+    (
+        hex!("01560A2DC392B1FF8EB744D6E9A6A050656FEFB61FC1EC02ED0CECBB6F206426"),
+        hex!("0A7F86D19FBFE335759D46E8E834046B6790AF04B27760558F14C6300381570E"),
+    ),
+    (
+        hex!("73506810EEDDD16C82BA2A372B880B9F5A56FDCC7729F288FECBC516F88E3AFF"),
+        hex!("3912B51EDB58690166F32F0B44A13A68FAACF8923A1A8C8E8C91CB0C7F8A0F8E"),
+    ),
+    (
+        hex!("2AB8CC148CDE00E0666BB3B59D89FBF4189EA97283452E62737F61699DC292D0"),
+        hex!("529935809AF909809D3F8C2BFB5ED895746A87461AF806E2B0D7A87DC71EA70C"),
+    ),
+    (
+        hex!("719BDB25E1389A011E1AED8A6CCCA57518045B70A2A55AEDE01C79B1E48E6137"),
+        hex!("3DDFB232CBEAFA009AB1640D6346659BC52A19C007561763753900846C4419EE"),
+    ),
+    (
+        hex!("6BF1219838C00C721306D29AFB1044245332FC80EDFD3B1D614C4D5DDD73214E"),
+        hex!("9883D5A3AAFEBCA5D02F25D93BD0570A7A1963BAB3869580EA224D5E10F11DAC"),
+    ),
+    (
+        hex!("22790F422E94EA7D39AC29C9512CE9E189C6BEA0C5561584F932EA1EF466B301"),
+        hex!("22BDE6165F5E37EDD88483415AA329FC1F4A8905D8083E773BD33238D4523A3A"),
+    ),
+    (
+        hex!("5F03D8F11E1EC5903C9505B0BB0C2321CD612BCAF51817E1798086D0A81B4CC1"),
+        hex!("57D24457C42283C600A30A6A6651EC9FE759761DDE1F297D102AF104942A5902"),
+    ),
+    (
+        hex!("8A2FB53E381AA4E0223AF1FF99EE48F25259CB77A0D926368AEC863AD5FBEC02"),
+        hex!("95C632B5DD656975CB3E1C4B8183545D32C4AB941DA19FEEA6FA2E7B1B72A895"),
+    ),
+    (
+        hex!("978AC60DA6471B4E4607415DF110A037370A85BD23D3F621B8BB39884F6ADB03"),
+        hex!("89672A348FDD317675A9B0DC4C07EA87BBB99187DF6740BB7E6093390F1653F2"),
+    ),
+    (
+        hex!("3017AA6EC734F98905A12E2C984802FE3FD727CEE9CC0AD89D11DB37732A2DA0"),
+        hex!("6CB82D469929D5E34AF3DA283D98B75940DEEF8BEF66FB77DA023A808E98BE74"),
+    ),
+    (
+        hex!("52D938824A4D9B51A2D071716C0E6BE1BCA96175070AA14256963B1C96198E9E"),
+        hex!("8A61D63AD814F797E4743762826E6D0A6C765859FA6DCB8B85FBFE0B873F6179"),
+    ),
+    (
+        hex!("8049F6C4B85B8878BB44F3F62C366C7095BF90288872B7F114B4222CE27B99A2"),
+        hex!("003030C9CD3B7F30DDC8DBA268F24D36D89417929DD41C258951722F1A4B4206"),
+    ),
+    (
+        hex!("3C8A8C264C4BD3BD5708552EB61CAFBF69114360CCE756688CD572966BF1D11F"),
+        hex!("79F992BDFC53179F58F4F28938ABFDE395071EE1C5352615720BFF0AC2B9597A"),
+    ),
+    (
+        hex!("10919B5AFA4BF77955DD61C9831B999B649515DC443E89A844A6DCB757E44BE8"),
+        hex!("7B76DC3BDAB23660FF360DF2ED688B5AABD7E0054932E5A8C5B73A9F1B375048"),
+    ),
+    (
+        hex!("43F279F49714EC74EFAB5886FF6D18B7EE82B6F7F68979CE13C9934C5C02702E"),
+        hex!("02FF3F50D2B4B2757219CBA215D560D23AE4EB01072A7F7081BCC97D9524041F"),
+    ),
+    (
+        hex!("912552EAFFDFAC2D56B5FCDD12180148EA48C116B05D028F0F844D0593360640"),
+        hex!("3F6EBD8A68F35EC558B2F4576CE88E2410E883BA2959F11047F2201EBF9777B6"),
+    ),
+];
+
+/// Scalar multiplication with the generator.
+///
+/// RFC 7027 only covers brainpoolP256r1, so these are its key pairs `(dA, QA)`
+/// and `(dB, QB)` and their shared point (as the multiple `(dA * dB mod q)G`),
+/// mapped to brainpoolP256t1 with the isomorphism
+/// `(x, y) -> (x * Z^2, y * Z^3)` of RFC 5639, where:
+///
+/// ```text
+/// Z = 3E2D4BD9597B58639AE7AA669CAB9837CF5CF20A2C852D10F655668DFC150EF0
+/// ```
+///
+/// This isomorphism maps the generator of brainpoolP256r1 to the one of
+/// brainpoolP256t1, so the scalars are unchanged.
+pub const MUL_TEST_VECTORS: &[([u8; 32], [u8; 32], [u8; 32])] = &[
+    (
+        hex!("81DB1EE100150FF2EA338D708271BE38300CB54241D79950F77B063039804F1D"),
+        hex!("A5F81698AEEE80C41A71796FC5E51FCC09B679BB3076C78E9AF22F8373DC503E"),
+        hex!("A59F316BB8D9ACAD8AC8467BD94878B6F294306E70034575FE6BF8D2EADD58CB"),
+    ),
+    (
+        hex!("55E40BC41E37E3E2AD25C3C6654511FFA8474A91A0032087593852D3E7D76BD3"),
+        hex!("A935A9A9FE5C7BDB914F4E49D37255887B7122339B37F179A668A4666298693F"),
+        hex!("004E506E16438EA28B534C1782917137ECF99FACE2F24A7A54F763D90FCCFB60"),
+    ),
+    (
+        hex!("73C6C9BF9E42BD2ED725AABE919B1E1F7B42C301275D2E8C257D49C98D72B537"),
+        hex!("12CC403912543A9131162F3FC604046F2DEB3501DDAB82D17FFFF7566270B22C"),
+        hex!("377D0BA7610CF8B67AB692EB930A7C8A0DD0E8818AD00D68844CA2F9FB4DE91D"),
+    ),
+];
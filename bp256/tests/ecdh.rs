@@ -0,0 +1,77 @@
+//! ECDH tests.
+
+#![cfg(feature = "ecdh")]
+
+use hex_literal::hex;
+
+// Test vectors from RFC 7027 Appendix A for brainpoolP256r1
+mod r1 {
+    use super::*;
+    use bp256::r1::{ecdh::diffie_hellman, PublicKey, SecretKey};
+
+    const SECRET_A: [u8; 32] =
+        hex!("81DB1EE100150FF2EA338D708271BE38300CB54241D79950F77B063039804F1D");
+    const PUBLIC_A: [u8; 65] = hex!(
+        "04"
+        "44106E913F92BC02A1705D9953A8414DB95E1AAA49E81D9E85F929A8E3100BE5"
+        "8AB4846F11CACCB73CE49CBDD120F5A900A69FD32C272223F789EF10EB089BDC"
+    );
+    const SECRET_B: [u8; 32] =
+        hex!("55E40BC41E37E3E2AD25C3C6654511FFA8474A91A0032087593852D3E7D76BD3");
+    const PUBLIC_B: [u8; 65] = hex!(
+        "04"
+        "8D2D688C6CF93E1160AD04CC4429117DC2C41825E1E9FCA0ADDD34E6F1B39F7B"
+        "990C57520812BE512641E47034832106BC7D3E8DD0E4C7F1136D7006547CEC6A"
+    );
+    const SHARED_SECRET: [u8; 32] =
+        hex!("89AFC39D41D3B327814B80940B042590F96556EC91E6AE7939BCE31F3A18BF2B");
+
+    #[test]
+    fn public_keys() {
+        let secret_a = SecretKey::from_slice(&SECRET_A).unwrap();
+        let secret_b = SecretKey::from_slice(&SECRET_B).unwrap();
+        assert_eq!(
+            secret_a.public_key(),
+            PublicKey::from_sec1_bytes(&PUBLIC_A).unwrap()
+        );
+        assert_eq!(
+            secret_b.public_key(),
+            PublicKey::from_sec1_bytes(&PUBLIC_B).unwrap()
+        );
+    }
+
+    #[test]
+    fn shared_secret() {
+        let secret_a = SecretKey::from_slice(&SECRET_A).unwrap();
+        let secret_b = SecretKey::from_slice(&SECRET_B).unwrap();
+        let public_a = PublicKey::from_sec1_bytes(&PUBLIC_A).unwrap();
+        let public_b = PublicKey::from_sec1_bytes(&PUBLIC_B).unwrap();
+
+        let shared_a = diffie_hellman(secret_a.to_nonzero_scalar(), public_b.as_affine());
+        let shared_b = diffie_hellman(secret_b.to_nonzero_scalar(), public_a.as_affine());
+        assert_eq!(shared_a.raw_secret_bytes().as_slice(), SHARED_SECRET);
+        assert_eq!(shared_b.raw_secret_bytes().as_slice(), SHARED_SECRET);
+    }
+}
+
+// RFC 7027 doesn't provide test vectors for brainpoolP256t1
+mod t1 {
+    use bp256::t1::{ecdh::EphemeralSecret, EncodedPoint, PublicKey};
+    use rand_core::OsRng;
+
+    #[test]
+    fn ephemeral_shared_secret() {
+        let alice = EphemeralSecret::random(&mut OsRng);
+        let bob = EphemeralSecret::random(&mut OsRng);
+
+        let alice_public =
+            PublicKey::from_sec1_bytes(EncodedPoint::from(alice.public_key()).as_bytes()).unwrap();
+        let bob_public =
+            PublicKey::from_sec1_bytes(EncodedPoint::from(bob.public_key()).as_bytes()).unwrap();
+
+        assert_eq!(
+            alice.diffie_hellman(&bob_public).raw_secret_bytes(),
+            bob.diffie_hellman(&alice_public).raw_secret_bytes()
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! Projective arithmetic tests.
+
+#![cfg(all(feature = "arithmetic", feature = "test-vectors"))]
+
+mod r1 {
+    use bp256::{
+        r1::{AffinePoint, ProjectivePoint},
+        test_vectors::r1::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
+        Scalar,
+    };
+    use elliptic_curve::{
+        group::ff::PrimeField,
+        sec1::{self, ToEncodedPoint},
+    };
+    use primeorder::{impl_projective_arithmetic_tests, Double};
+
+    impl_projective_arithmetic_tests!(
+        AffinePoint,
+        ProjectivePoint,
+        Scalar,
+        ADD_TEST_VECTORS,
+        MUL_TEST_VECTORS
+    );
+}
+
+mod t1 {
+    use bp256::{
+        t1::{AffinePoint, ProjectivePoint},
+        test_vectors::t1::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
+        Scalar,
+    };
+    use elliptic_curve::{
+        group::ff::PrimeField,
+        sec1::{self, ToEncodedPoint},
+    };
+    use primeorder::{impl_projective_arithmetic_tests, Double};
+
+    impl_projective_arithmetic_tests!(
+        AffinePoint,
+        ProjectivePoint,
+        Scalar,
+        ADD_TEST_VECTORS,
+        MUL_TEST_VECTORS
+    );
+}
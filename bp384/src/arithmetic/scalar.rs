@@ -54,7 +54,7 @@ impl Scalar {
 
     /// Decode [`Scalar`] from a big endian byte slice.
     pub fn from_slice(slice: &[u8]) -> Result<Self> {
-        if slice.len() == 32 {
+        if slice.len() == 48 {
             Option::from(Self::from_bytes(FieldBytes::from_slice(slice))).ok_or(Error)
         } else {
             Err(Error)
@@ -99,7 +99,7 @@ impl Scalar {
 
     /// Returns the big-endian encoding of this [`Scalar`].
     pub fn to_bytes(self) -> FieldBytes {
-        self.0.to_be_byte_array()
+        self.to_canonical().to_be_byte_array()
     }
 
     /// Translate [`Scalar`] out of the Montgomery domain, returning a
@@ -281,7 +281,7 @@ impl AsRef<Scalar> for Scalar {
 
 impl Debug for Scalar {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Scalar(0x{:X})", &self.0)
+        write!(f, "Scalar(0x{:X})", &self.to_canonical())
     }
 }
 
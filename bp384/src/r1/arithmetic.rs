@@ -1,5 +1,8 @@
 //! brainpoolP384r1 curve arithmetic implementation.
 
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
+
 use super::BrainpoolP384r1;
 use crate::{FieldElement, Scalar};
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
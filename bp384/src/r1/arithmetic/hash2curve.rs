@@ -0,0 +1,109 @@
+//! Hash-to-curve support for brainpoolP384r1 using the
+//! `brainpoolP384r1_XMD:SHA-384_SSWU_RO_` suite, following [RFC 9380].
+//!
+//! brainpoolP384t1 shares the same base field, so only one of the two curves
+//! can provide [`OsswuMap`] for [`FieldElement`]; it is not supported.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use crate::{BrainpoolP384r1, FieldElement, Scalar};
+use elliptic_curve::{
+    consts::U72,
+    hash2curve::{OsswuMap, OsswuMapParams},
+};
+use primeorder::PrimeCurveParams;
+
+primeorder::impl_hash2curve!(BrainpoolP384r1, FieldElement, Scalar, U72);
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        c1: &[
+            0x61d1_c004_cc41_fb14,
+            0xeb34_e9ca_6407_469c,
+            0x04ac_7686_5fed_c448,
+            0xc54b_dc42_7b55_15ad,
+            0x03d7_5bdf_9439_9077,
+            0x232e_47a0_a8ce_1b4a,
+        ],
+        c2: FieldElement::from_hex(
+            "50f8965f2bc8ee47840e4def050afff5c04789bf32272522d1eb5e8628b05f5268c86982901cb1c33171954799c117ad",
+        ),
+        map_a: BrainpoolP384r1::EQUATION_A,
+        map_b: BrainpoolP384r1::EQUATION_B,
+        z: FieldElement::from_u64(5).neg(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::BrainpoolP384r1;
+    use hex_literal::hex;
+    use sha2::Sha384;
+
+    primeorder::impl_hash2curve_tests!(
+        BrainpoolP384r1,
+        FieldElement,
+        Scalar,
+        Sha384,
+        b"QUUX-V01-CS02-with-brainpoolP384r1_XMD:SHA-384_SSWU_RO_",
+        hex!("80b4b0d3f41f7d43049162cc3237064222f7c044bf1853a7ffc0ea586d5097d01b4f799f6faf7c737539eda575149596"),
+        [
+            {
+                msg: b"",
+                p_x: hex!("570fae1a12ebda55530b400ab7c47e2d852846134b568713a215b2eeeb1381478169ff7630a6c0bfd9b4230191a44c44"),
+                p_y: hex!("5c8a79d2bf2c80d68766f6769e38e6b84da77ac80c5ab560f3682328a836ce0781fe85ae1dc1c1c1141edb7f677549e9"),
+                u_0: hex!("6094f538dd8970c4c0b966dd13744bd033bf9802644600a1c67df6363ffe055778a0393b85aa90ec32df9c59c892c9b1"),
+                u_1: hex!("6a92bdf23d66d04b322f5c03927905ec90ab653eac54f0925f5a1dab742a15895da8a118722e3efd5c92ed737b273245"),
+                q0_x: hex!("111be3b337e8037d2912afbfa7e5c4ddb9606f97ced5caf36beb1b43b61ef50ed4447d1513d2e2bda91e54f6018a43a1"),
+                q0_y: hex!("158afe3d2389274cd0a4f4beee0d70e050007cfc4c8264082a5289eb1be09117650553675afa3195dd5fd1392350eec7"),
+                q1_x: hex!("874b1620bd96f83a732a833d7075cd80c1bf1ce9744d8f24423d0b4b2a49fa7cc05e63ed13ad0390242d9287943b7f77"),
+                q1_y: hex!("02745679af4ec37a4f52ace4eeab4b7281042bd04eaf43d9d6daf2b5f91d51a75589e7da095197a85afe5545c4ec6107"),
+            },
+            {
+                msg: b"abc",
+                p_x: hex!("6e348eec7b9a542c5064a917965b2a58b4bed839e72ef5c9f34625eb0b98785137f9a79e556a0743b127c00d1a04113c"),
+                p_y: hex!("58c2b272d367068e4b9759dcb79c90ab462352538a10fc36bbaaf05eb1834d1a200c144ea1326b2f2603064ae657affd"),
+                u_0: hex!("5c92ffff7d7b6a2f875c69760990903fb676676026143906bb71cef609138d5abfbed969090384860f0dc95fa640e14b"),
+                u_1: hex!("2b1ecc349aa71e5ba12d6f0aeeac455051648797e6a7d90a171f696c3daa4583243b0e280b7ee5c970fd3cf70ad0748d"),
+                q0_x: hex!("40cf19104f201ba42010665dcbb09cc9bb326a8cf801fe407acfddc9b062824faddcc267b57c3756aed29381b6e29765"),
+                q0_y: hex!("17700116a99e156f11f2780e1283cbb058be65f5a6463fb1d570b4908d7bdadfef76cd6d44aa8ce529929dd9bbfab597"),
+                q1_x: hex!("79979a112217932d59021d30723d871982ab22dd5fa31ea46465121460d0c3ade1f4ac0f49363942f1f4bdd76d0c1505"),
+                q1_y: hex!("5aec0ab5a0389d4d80f6f52b11f33f454dc59fb2d5c5e607d0ba056e790588a57a4e88178d15baa0ce736e32b0f830d5"),
+            },
+            {
+                msg: b"abcdef0123456789",
+                p_x: hex!("25e5339f9fed6dce45e72ab99469df6646791fa25ae37342a9e9627228eb63239a83da98cd35ff81be74b56ca5aa636c"),
+                p_y: hex!("4bf5d6358a7dde458e3b377ee89fcaec2cad00be4b1dc924a6817bc4bde32013c51784e887abe2be45f9d7074f6e2167"),
+                u_0: hex!("3b400ecf7ca13f70a6a7a60b44ce36cecc1bfb17678a6d4bbd23e70721ba1301276e541cd3c11f8811535d1d2c11607c"),
+                u_1: hex!("72c3cbd8253cc177ae2ceb6b5206ccdb0e5c649259328ea295f97953ee354b9e57a48466df1fbf24ec89ca751cc2cc2c"),
+                q0_x: hex!("85dbbb87a951ed63393b5e51a5990de550c353ed4a99ea3e81d7bd97a9805938694c8406ecba752b6eae999983186b71"),
+                q0_y: hex!("769c5aded7a17cbe7e2813f41c32035285776a1a8f02cc3e0010f911e163d6489888401323fbd8fef3e482508d9570c0"),
+                q1_x: hex!("7c8ce3f932ed8db4b8c28538709f4ce77ed24a617d39e7b42d6dc941786c4fec13c1b522296aa47bb5ab65fd84031c1a"),
+                q1_y: hex!("1454ea78342de6dca52bebfa5a2f6664ab7381b6b5d38ae2e519da137eec7ccaa98e4212c809ad75ece3e520dab56e56"),
+            },
+            {
+                msg: b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                p_x: hex!("38c5e8776e880c082dc02fa96b877a0fb0c6fb04ec863a3363f949f96a8a90b623fee5a488177a7712703e651aa3f205"),
+                p_y: hex!("57b30a4c75fcdf0fe4ac0199a59f5ab439d66c946d6ef3570b0948a6fb0e8a7bd78e97f4c80425401bbcd53695367e32"),
+                u_0: hex!("89253d76c8c0ac0a6d647ed03c0500fdd638a61a3a106b4a2421a5c3c3c5b3a407812e69b2801af1e7e4fd64f8f6815b"),
+                u_1: hex!("56c5b88f0a1d3ee60b97bfc14e33a0385794330ceb4c69104819762d4e057f585be795ac9106728fd5dc33af554b1e6b"),
+                q0_x: hex!("3fde580237567d0c4e329b46e25efbadda3675f2f8e330c74a2727e76576bf5e57b4a2f1edb7203cd5768e6a704ac61a"),
+                q0_y: hex!("352c906aeffbf614a6562e4e4d5c018ca405af3ee1ea4849896eeaf8a3ae0bd96d2e571b022f3d40566a9759a5f96d09"),
+                q1_x: hex!("88753a735e002b11aa34db72b8eb5462d1ef32e269ece31e6bfbfd797c897efd69c6ca04a3071a1c674fea0e04fc8b40"),
+                q1_y: hex!("256b8d38eb3aa3842ed9092f1fbcf992a045a6bc23af4ba7a28c29660267f9730a035d9336f0d16b2e9ab6dadf025f2d"),
+            },
+            {
+                msg: b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                p_x: hex!("5b7feba5b04f416f7359c3ea795862f99f889c040d15899b62a7ca47dbd15ff7e9253d70eb80d0d3abc87854c9696107"),
+                p_y: hex!("3645ffa520932e774164fd730e50c40a5f34f6c08e3fcd209260057a9081cdd35e24a4e39494ac82194b28e97af51167"),
+                u_0: hex!("71695d7a97392f7db6a0bb73ae0abb16a31ab54d9d1b695017b1fd8e7882e6e7ea7c1713b875857729a126875f6906a5"),
+                u_1: hex!("81c1b8f92ab2f50589aef663adc382dc138e8171f7777c0d31347338e804629ced0253877d0128be3ec09301b4e95348"),
+                q0_x: hex!("3b174027a12752b4f17f9c1b66df44482aa1a19b3289bb9d2113e76809e2868bbc5d907eaf951ebb6cbfa565086e7685"),
+                q0_y: hex!("507fdaab400675dc3ecbb0e545bf20b60cb82911a9fac0e4da367734b522bfb454203f56812c6ce7a0b75d4468641517"),
+                q1_x: hex!("219410d3dfea152d7294f4c770d1aa5af3eb0bbea5355e4bc25d80ec9b22d6ec619c788d1d48d23335e646f1e686ecc1"),
+                q1_y: hex!("7241658b89cbb5f298ad37559b00bb363102a8799741a40589f828b68afeee62c0d77854268bf3664a91f4cba5e49cc0"),
+            },
+        ]
+    );
+}
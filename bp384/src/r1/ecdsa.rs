@@ -1,14 +1,55 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! To use it, you will need to enable one of the two following Cargo features:
+//!
+//! - `ecdsa-core`: provides only the [`Signature`] type (which represents an
+//!   ECDSA/brainpoolP384r1 signature). Does not require the `arithmetic` feature.
+//!   This is useful for 3rd-party crates which wish to use the `Signature`
+//!   type for interoperability purposes.
+//! - `ecdsa`: provides `ecdsa-core` features plus the [`SigningKey`] and
+//!   [`VerifyingKey`] types which natively implement ECDSA/brainpoolP384r1
+//!   signing and verification.
+//!
+//! ## Signing/Verification Example
+//!
+//! This example requires the `ecdsa` Cargo feature is enabled:
+//!
+//! ```
+//! # #[cfg(feature = "ecdsa")]
+//! # {
+//! use bp384::r1::ecdsa::{signature::Signer, Signature, SigningKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Signing
+//! let signing_key = SigningKey::random(&mut OsRng); // Serialize with `::to_bytes()`
+//! let message = b"ECDSA proves knowledge of a secret number in the context of a single message";
+//! let signature: Signature = signing_key.sign(message);
+//!
+//! // Verification
+//! use bp384::r1::ecdsa::{signature::Verifier, VerifyingKey};
+//!
+//! let verifying_key = VerifyingKey::from(&signing_key); // Serialize with `::to_encoded_point()`
+//! assert!(verifying_key.verify(message, &signature).is_ok());
+//! # }
+//! ```
 
 pub use super::BrainpoolP384r1;
+pub use ecdsa_core::signature::{self, Error};
+
+#[cfg(feature = "ecdsa")]
+use crate::Scalar;
 
 /// ECDSA/brainpoolP384r1 signature (fixed-size)
-pub type Signature = ecdsa::Signature<BrainpoolP384r1>;
+pub type Signature = ecdsa_core::Signature<BrainpoolP384r1>;
 
 /// ECDSA/brainpoolP384r1 signature (ASN.1 DER encoded)
-pub type DerSignature = ecdsa::der::Signature<BrainpoolP384r1>;
+pub type DerSignature = ecdsa_core::der::Signature<BrainpoolP384r1>;
 
 #[cfg(feature = "sha384")]
-impl ecdsa::hazmat::DigestPrimitive for BrainpoolP384r1 {
+impl ecdsa_core::hazmat::DigestPrimitive for BrainpoolP384r1 {
     type Digest = sha2::Sha384;
 }
+
+#[cfg(feature = "ecdsa")]
+primeorder::impl_ecdsa!(BrainpoolP384r1, Scalar, wycheproof: "../test_vectors/data/wycheproof-r1.blb");
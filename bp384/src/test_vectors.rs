@@ -0,0 +1,4 @@
+//! brainpoolP384 test vectors.
+
+pub mod r1;
+pub mod t1;
@@ -0,0 +1,3 @@
+//! brainpoolP384t1 test vectors.
+
+pub mod group;
@@ -0,0 +1,121 @@
+//! Test vectors for the brainpoolP384t1 group.
+
+use hex_literal::hex;
+
+/// Repeated addition of the generator.
+///
+/// These are the multiples `kG` for `k = 1..=20`, where `G` is the generator of
+/// brainpoolP384t1 from RFC 5639 § 3.6.
+pub const ADD_TEST_VECTORS: &[([u8; 48], [u8; 48])] = &[
+    (
+        hex!("18DE98B02DB9A306F2AFCD7235F72A819B80AB12EBD653172476FECD462AABFFC4FF191B946A5F54D8D0AA2F418808CC"),
+        hex!("25AB056962D30651A114AFD2755AD336747F93475B7A1FCA3B88F2B6A208CCFE469408584DC2B2912675BF5B9E582928"),
+    ),
+    (
+        hex!("0B2D196565EACB7879A12ECA76E23C6A82036BDE5AEEA2F27227F117B42CEFBD6480396AD5B3E734E93DD1F3FCCE7B80"),
+        hex!("0A7239B0822CEBF205ED4F5A8DC4EF53729F32065E7120773EA1E06E9032C9093B632518E5E8AA82137E16078F93D782"),
+    ),
+    (
+        hex!("03E7E83B88BA8D99A004F1C92EE361648A922F773F96D64B2BB66D1F3C0EEAC30485CFEF216F68B596B8861FBC005EA9"),
+        hex!("298585E0C24722037F09DD015C2FEFEEC87058D76A07FE43ED52E8641B7248D2C8BABA631D9D68ADC2BD7E748C753C0D"),
+    ),
+    (
+        hex!("1D6B6A42D0251FD757BDBFC66B9B7657BF3776939F7A268095277A58047167A8EDAEBDC247182380D15FDD5C3558E418"),
+        hex!("6810EC318B2ED51F66C55E9E5AFA6A020ADCCE0EA6EF06F1D6E5DB442ECE048E6392C536223B14CAAD6681402852AD46"),
+    ),
+    (
+        hex!("5E57B7BF10C5640D6491978055BC6CE9D2813C043772F87255CFE09C917CE7180F3C9737B1B00E567935BA8425375558"),
+        hex!("71C731CBCE2A8468EE077B65CD1E512FFECB5B84070E5E8B74D3DB03577047C96BD5044093C7B20B7C85AB334912FB7E"),
+    ),
+    (
+        hex!("25766F5B5D7F5AC9A6BA59CFFD2633C564BBEF62F77B587A57CCB9196EFB8A559428687F73FD8B368B06DDBE7E7B36C8"),
+        hex!("391B731B5766BC3BC5262EDEE960FB0BBFA0668FFFA40E951D0929B7BB86660F0FF776AD6AB05BB81B0700F8069B310B"),
+    ),
+    (
+        hex!("100F235B23EE1C24AA189429F88FC856B094BBC184E89EA6CC57CC19824817A2623413C30C9AAB50504545D2E53B4084"),
+        hex!("7DEEBC055E8F50B1CF8B8716C6880189802CDB447481C8FB320339C4FCBDED4832C28E6106F20ACA7E3E59273E4861E5"),
+    ),
+    (
+        hex!("030167161281524613F6782C879D9565367D30BF17743B78122936BD3D74E1882BBE792BC90A1C6E02399CF3682DFD2E"),
+        hex!("7D512A1D4DC92A4DD3A42A847B3CF62B97FEAAE0D5797CAFF51C9714D37CA6ED011B1DB85F95C503368F860972A1A25A"),
+    ),
+    (
+        hex!("3733C50D309C98DB4DCC412C7093BBE4C420A5B168F3689A15AE1AF552D2F34505A596A737720DECB08B59DF4E5869ED"),
+        hex!("3E477B31A353F8C619D1C4049E36144D51FE5EDE1C0528BDA04BC4F19DD65024AB13A2D479F0BD7D33AADB1AF8A21966"),
+    ),
+    (
+        hex!("3A0664E1D7F0FC4DD0D3C224DBA15B635F8C3502B2A6F91FE6367C8DA33F9854537B38CA5FAE829D17F95F48DE727612"),
+        hex!("67FD908E81D89FF0B5618656077426496C0A11F30B67007B358F2014F7DC18380575A78FE009F0AB19AE3E252AF1367E"),
+    ),
+    (
+        hex!("62B7E04ECA756B31969788DC6974B32F13E2D284C11E227F2C173AE0757F0F1D002F9471927E3565F53A8F3C1AFE8A9D"),
+        hex!("81A7903BE4AE8DDB5C713CED5AF2D1BF5A0533753253E1B221D5A2FE1EAE669A3D11EC835C0E1127EAACEECF8121FF2E"),
+    ),
+    (
+        hex!("3C4A84EAFFBB85DC1546FE245D1EBE12FA9536F7C8D96FC0CF880D24473EEAFC43C5F07488CB437D2495603D5D4B6642"),
+        hex!("61DFB73A155C758EB54824B887B42A5216A9CC3A4BDD8AB778E5F1D30485CFC184E455789FE6897FD7EEC3629B4C1116"),
+    ),
+    (
+        hex!("80E6A9013F417D0313B07DBA99C61AAAF250DBD7789562BAA805209B5D8105FACDE12FF40F25F6CFA9BD86DB191AF39B"),
+        hex!("1CBAA9997BEBCCF7A8AB7B4D3D5F1C900E0F2CD2DBB26B6B6F39FD57B5A9D4254ADF810DA4FB5DB16A7821136F508084"),
+    ),
+    (
+        hex!("5FF580FDF4D873CB981038C6D0885CD28FEB2E512DCDC5EAFF57AD6DDC7DF72B9947B2B778E31476C1A9B3F484B82046"),
+        hex!("54D54EA2871D72004A51CED3A1FCB3F6A539754D5429542DAFD144DE4B45F5FC1887A795C1AC121FAFE454FCAA1FE9CC"),
+    ),
+    (
+        hex!("7377C64E1EE6345192F8ADC894DAE0E399B227280ECD93D63BF8E192365FE2591E5F02709FE3A8545443824E0076E178"),
+        hex!("11C3DC36082794A8E155819A1E25CAAD5FE92A2B37288BA9E1137CAEDC4C845A181BD8106907166AC9BD7AD28D880A9C"),
+    ),
+    (
+        hex!("687C80003A0DF1493DA5C409E81710CD4ED0940725CF807CF83395ABC18A3602F67AFCFD78D2A0B0FE6DA28488419435"),
+        hex!("50E11FEDF09FE3E3083CB357CF270189EB0458CF611D5AABABA460099233926B4E3703634A3E1BEBCA580A19E489BCF9"),
+    ),
+    (
+        hex!("5F473F77DC5B8600887EAA99838DD25B7DFDCF801A4D875139782A2A29098DB19FD50934558ADDE2212E6D9DA65BD797"),
+        hex!("3FC0591B35BACF959A02B7EBF21524B1B4B9719AD2C038649E24A6BCDD53AD236915A3C6CE1E2DFBBE0A03D44A92EF9C"),
+    ),
+    (
+        hex!("1EC5A2F93B9085E6567713492A5F04FDF5DB5868AD451E34A28CABAEBF0A84FAD84B53D731BDF5577525068D20FA57C1"),
+        hex!("3EFD5E5059DBE18214D6BEA97FB56278FDD0D49C2DB754E50AB0F7EAC00B2635970F1896746A3ACE1015647B8DC09C3E"),
+    ),
+    (
+        hex!("201BBEBB843C773672480F27AF34B09A8F49183F3188CF617C26BAA4CEBCC29DDD97056C1A0E8FDCB0F02AE10DB7472F"),
+        hex!("5FFFB4713CCB334F940D4C0528E53D78E478CDDF370D54387840676A7A29A3815CD36186E4D7177943EE4A7655E12BAD"),
+    ),
+    (
+        hex!("4CACE9E9E7EC7FF6FC9DCCAFC61FCC6F22130E1958265FEC6EAC2F41F26D3D97934BF4EA9483ABD144DC10276F3BA765"),
+        hex!("254D8EECDBA5BEDD786BD6ABC24F4B36B7B7EC5283693041340E32C1118A764EDA1937DC57AF180C15204285DEE9F75D"),
+    ),
+];
+
+/// Scalar multiplication with the generator.
+///
+/// RFC 7027 only covers brainpoolP384r1, so these are its key pairs `(dA, QA)`
+/// and `(dB, QB)` and their shared point (as the multiple `(dA * dB mod q)G`),
+/// mapped to brainpoolP384t1 with the isomorphism
+/// `(x, y) -> (x * Z^2, y * Z^3)` of RFC 5639, where:
+///
+/// ```text
+/// Z = 41DFE8DD399331F7166A66076734A89CD0D2BCDB7D068E44E1F378F41ECBAE97D2D63DBC87BCCDDCCC5DA39E8589291C
+/// ```
+///
+/// This isomorphism maps the generator of brainpoolP384r1 to the one of
+/// brainpoolP384t1, so the scalars are unchanged.
+pub const MUL_TEST_VECTORS: &[([u8; 48], [u8; 48], [u8; 48])] = &[
+    (
+        hex!("1E20F5E048A5886F1F157C74E91BDE2B98C8B52D58E5003D57053FC4B0BD65D6F15EB5D1EE1610DF870795143627D042"),
+        hex!("30883B5F8686F6BE6C2F2FDD1D1AD997429887208451FC1F87BDB59C98C12DE42DFE6872BF1A987A0E4E74AC54101B62"),
+        hex!("07A9529D9A26174106CA47D66B56696A89C48F54F9489E1DC8C40FBE97E833A104F12B55A728D08E018AF2E784C32FCD"),
+    ),
+    (
+        hex!("032640BC6003C59260F7250C3DB58CE647F98E1260ACCE4ACDA3DD869F74E01F8BA5E0324309DB6A9831497ABAC96670"),
+        hex!("346ABD196A03555941CFA9CD927D2E5A38A879F22F21EA073B05E9020DA8D1270D353E0066492BE2009EE84A643036E5"),
+        hex!("456634266F22AAA0DB788C833AD931CA89467AACD700002644B6F49F6CB4A92396D5194692CE03555CEB443F632F5AB5"),
+    ),
+    (
+        hex!("823FBD647170C36AB27D27437E0F5D6E1306192718D4E2B655591BB036ED2FC2BFB5586609AA987447B5CC67C3764D53"),
+        hex!("19C8B336F14F547B2389099313A2DFD92F769185866302937A2B53F658B53E601A82459D123B75F6CA5295EED071EC3F"),
+        hex!("22418421CE1F33D2014E813A47166246F6C15EE009DADC8AF26A2C386B28E5FCA196F9A68E38DEFA5184ACB366978EDA"),
+    ),
+];
@@ -0,0 +1,77 @@
+//! ECDH tests.
+
+#![cfg(feature = "ecdh")]
+
+use hex_literal::hex;
+
+// Test vectors from RFC 7027 Appendix A for brainpoolP384r1
+mod r1 {
+    use super::*;
+    use bp384::r1::{ecdh::diffie_hellman, PublicKey, SecretKey};
+
+    const SECRET_A: [u8; 48] =
+    hex!("1E20F5E048A5886F1F157C74E91BDE2B98C8B52D58E5003D57053FC4B0BD65D6F15EB5D1EE1610DF870795143627D042");
+    const PUBLIC_A: [u8; 97] = hex!(
+        "04"
+        "68B665DD91C195800650CDD363C625F4E742E8134667B767B1B476793588F885AB698C852D4A6E77A252D6380FCAF068"
+        "55BC91A39C9EC01DEE36017B7D673A931236D2F1F5C83942D049E3FA20607493E0D038FF2FD30C2AB67D15C85F7FAA59"
+    );
+    const SECRET_B: [u8; 48] =
+    hex!("032640BC6003C59260F7250C3DB58CE647F98E1260ACCE4ACDA3DD869F74E01F8BA5E0324309DB6A9831497ABAC96670");
+    const PUBLIC_B: [u8; 97] = hex!(
+        "04"
+        "4D44326F269A597A5B58BBA565DA5556ED7FD9A8A9EB76C25F46DB69D19DC8CE6AD18E404B15738B2086DF37E71D1EB4"
+        "62D692136DE56CBE93BF5FA3188EF58BC8A3A0EC6C1E151A21038A42E9185329B5B275903D192F8D4E1F32FE9CC78C48"
+    );
+    const SHARED_SECRET: [u8; 48] =
+    hex!("0BD9D3A7EA0B3D519D09D8E48D0785FB744A6B355E6304BC51C229FBBCE239BBADF6403715C35D4FB2A5444F575D4F42");
+
+    #[test]
+    fn public_keys() {
+        let secret_a = SecretKey::from_slice(&SECRET_A).unwrap();
+        let secret_b = SecretKey::from_slice(&SECRET_B).unwrap();
+        assert_eq!(
+            secret_a.public_key(),
+            PublicKey::from_sec1_bytes(&PUBLIC_A).unwrap()
+        );
+        assert_eq!(
+            secret_b.public_key(),
+            PublicKey::from_sec1_bytes(&PUBLIC_B).unwrap()
+        );
+    }
+
+    #[test]
+    fn shared_secret() {
+        let secret_a = SecretKey::from_slice(&SECRET_A).unwrap();
+        let secret_b = SecretKey::from_slice(&SECRET_B).unwrap();
+        let public_a = PublicKey::from_sec1_bytes(&PUBLIC_A).unwrap();
+        let public_b = PublicKey::from_sec1_bytes(&PUBLIC_B).unwrap();
+
+        let shared_a = diffie_hellman(secret_a.to_nonzero_scalar(), public_b.as_affine());
+        let shared_b = diffie_hellman(secret_b.to_nonzero_scalar(), public_a.as_affine());
+        assert_eq!(shared_a.raw_secret_bytes().as_slice(), SHARED_SECRET);
+        assert_eq!(shared_b.raw_secret_bytes().as_slice(), SHARED_SECRET);
+    }
+}
+
+// RFC 7027 doesn't provide test vectors for brainpoolP384t1
+mod t1 {
+    use bp384::t1::{ecdh::EphemeralSecret, EncodedPoint, PublicKey};
+    use rand_core::OsRng;
+
+    #[test]
+    fn ephemeral_shared_secret() {
+        let alice = EphemeralSecret::random(&mut OsRng);
+        let bob = EphemeralSecret::random(&mut OsRng);
+
+        let alice_public =
+            PublicKey::from_sec1_bytes(EncodedPoint::from(alice.public_key()).as_bytes()).unwrap();
+        let bob_public =
+            PublicKey::from_sec1_bytes(EncodedPoint::from(bob.public_key()).as_bytes()).unwrap();
+
+        assert_eq!(
+            alice.diffie_hellman(&bob_public).raw_secret_bytes(),
+            bob.diffie_hellman(&alice_public).raw_secret_bytes()
+        );
+    }
+}
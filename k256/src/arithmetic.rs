@@ -13,6 +13,9 @@ mod dev;
 
 pub use field::FieldElement;
 
+#[cfg(feature = "alloc")]
+pub(crate) use self::mul::lincomb_slice;
+
 use self::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
 use crate::Secp256k1;
 use elliptic_curve::CurveArithmetic;
@@ -51,6 +51,9 @@ use elliptic_curve::{
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "precomputed-tables")]
 use once_cell::sync::Lazy;
 
@@ -236,7 +239,7 @@ fn decompose_scalar(k: &Scalar) -> (Scalar, Scalar) {
 }
 
 // This needs to be an object to have Default implemented for it
-// (required because it's used in static_map later)
+// (required because it's used in static_zip_map later)
 // Otherwise we could just have a function returning an array.
 #[derive(Copy, Clone)]
 struct Radix16Decomposition<const D: usize>([i8; D]);
@@ -278,22 +281,6 @@ impl<const D: usize> Default for Radix16Decomposition<D> {
     }
 }
 
-/// Maps an array `x` to an array using the predicate `f`.
-/// We can't use the standard `map()` because as of Rust 1.51 we cannot collect into arrays.
-/// Consequently, since we cannot have an uninitialized array (without `unsafe`),
-/// a default value needs to be provided.
-fn static_map<T: Copy, V: Copy, const N: usize>(
-    f: impl Fn(T) -> V,
-    x: &[T; N],
-    default: V,
-) -> [V; N] {
-    let mut res = [default; N];
-    for i in 0..N {
-        res[i] = f(x[i]);
-    }
-    res
-}
-
 /// Maps two arrays `x` and `y` into an array using a predicate `f` that takes two arguments.
 fn static_zip_map<T: Copy, S: Copy, V: Copy, const N: usize>(
     f: impl Fn(T, S) -> V,
@@ -308,63 +295,70 @@ fn static_zip_map<T: Copy, S: Copy, V: Copy, const N: usize>(
     res
 }
 
+/// Lookup tables and radix-16 digits for a single term `x * k` of a linear
+/// combination, with `k` split using the endomorphism.
+#[derive(Copy, Clone, Default)]
+struct LincombTerm {
+    tables: [LookupTable; 2],
+    digits: [Radix16Decomposition<33>; 2],
+}
+
+impl LincombTerm {
+    fn new(x: &ProjectivePoint, k: &Scalar) -> Self {
+        let (r1, r2) = decompose_scalar(k);
+        let x_beta = x.endomorphism();
+
+        let r1_sign = r1.is_high();
+        let r2_sign = r2.is_high();
+
+        let r1_c = Scalar::conditional_select(&r1, &-r1, r1_sign);
+        let r2_c = Scalar::conditional_select(&r2, &-r2, r2_sign);
+
+        Self {
+            tables: [
+                LookupTable::from(&ProjectivePoint::conditional_select(x, &-x, r1_sign)),
+                LookupTable::from(&ProjectivePoint::conditional_select(
+                    &x_beta, &-x_beta, r2_sign,
+                )),
+            ],
+            digits: [
+                Radix16Decomposition::<33>::new(&r1_c),
+                Radix16Decomposition::<33>::new(&r2_c),
+            ],
+        }
+    }
+}
+
 /// Calculates a linear combination `sum(x[i] * k[i])`, `i = 0..N`
 #[inline(always)]
 fn lincomb_generic<const N: usize>(xs: &[ProjectivePoint; N], ks: &[Scalar; N]) -> ProjectivePoint {
-    let rs = static_map(
-        |k| decompose_scalar(&k),
-        ks,
-        (Scalar::default(), Scalar::default()),
-    );
-    let r1s = static_map(|(r1, _r2)| r1, &rs, Scalar::default());
-    let r2s = static_map(|(_r1, r2)| r2, &rs, Scalar::default());
-
-    let xs_beta = static_map(|x| x.endomorphism(), xs, ProjectivePoint::default());
-
-    let r1_signs = static_map(|r| r.is_high(), &r1s, Choice::from(0u8));
-    let r2_signs = static_map(|r| r.is_high(), &r2s, Choice::from(0u8));
-
-    let r1s_c = static_zip_map(
-        |r, r_sign| Scalar::conditional_select(&r, &-r, r_sign),
-        &r1s,
-        &r1_signs,
-        Scalar::default(),
-    );
-    let r2s_c = static_zip_map(
-        |r, r_sign| Scalar::conditional_select(&r, &-r, r_sign),
-        &r2s,
-        &r2_signs,
-        Scalar::default(),
-    );
-
-    let tables1 = static_zip_map(
-        |x, r_sign| LookupTable::from(&ProjectivePoint::conditional_select(&x, &-x, r_sign)),
+    let terms = static_zip_map(
+        |x, k| LincombTerm::new(&x, &k),
         xs,
-        &r1_signs,
-        LookupTable::default(),
-    );
-    let tables2 = static_zip_map(
-        |x, r_sign| LookupTable::from(&ProjectivePoint::conditional_select(&x, &-x, r_sign)),
-        &xs_beta,
-        &r2_signs,
-        LookupTable::default(),
+        ks,
+        LincombTerm::default(),
     );
+    lincomb_terms(&terms)
+}
 
-    let digits1 = static_map(
-        |r| Radix16Decomposition::<33>::new(&r),
-        &r1s_c,
-        Radix16Decomposition::<33>::default(),
-    );
-    let digits2 = static_map(
-        |r| Radix16Decomposition::<33>::new(&r),
-        &r2s_c,
-        Radix16Decomposition::<33>::default(),
-    );
+/// Calculates a linear combination `sum(x * k)` over an arbitrary number of
+/// `(x, k)` terms.
+#[cfg(feature = "alloc")]
+pub(crate) fn lincomb_slice(terms: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+    let terms = terms
+        .iter()
+        .map(|(x, k)| LincombTerm::new(x, k))
+        .collect::<Vec<_>>();
+    lincomb_terms(&terms)
+}
 
+/// Evaluates a linear combination of precomputed terms in constant time.
+#[inline(always)]
+fn lincomb_terms(terms: &[LincombTerm]) -> ProjectivePoint {
     let mut acc = ProjectivePoint::IDENTITY;
-    for component in 0..N {
-        acc += &tables1[component].select(digits1[component].0[32]);
-        acc += &tables2[component].select(digits2[component].0[32]);
+    for term in terms {
+        acc += &term.tables[0].select(term.digits[0].0[32]);
+        acc += &term.tables[1].select(term.digits[1].0[32]);
     }
 
     for i in (0..32).rev() {
@@ -372,9 +366,9 @@ fn lincomb_generic<const N: usize>(xs: &[ProjectivePoint; N], ks: &[Scalar; N])
             acc = acc.double();
         }
 
-        for component in 0..N {
-            acc += &tables1[component].select(digits1[component].0[i]);
-            acc += &tables2[component].select(digits2[component].0[i]);
+        for term in terms {
+            acc += &term.tables[0].select(term.digits[0].0[i]);
+            acc += &term.tables[1].select(term.digits[1].0[i]);
         }
     }
     acc
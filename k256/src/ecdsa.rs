@@ -142,11 +142,19 @@
 //! # }
 //! ```
 
+#[cfg(feature = "ecdsa")]
+mod batch;
+
 pub use ecdsa_core::{
     signature::{self, Error},
     RecoveryId,
 };
 
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+pub use self::batch::verify_batch;
+#[cfg(feature = "ecdsa")]
+pub use self::batch::SignatureWithR;
+
 #[cfg(any(feature = "ecdsa", feature = "sha256"))]
 pub use ecdsa_core::hazmat;
 
@@ -423,4 +431,53 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "alloc")]
+    mod batch {
+        use crate::ecdsa::{verify_batch, SignatureWithR, SigningKey, VerifyingKey};
+        use alloc::vec::Vec;
+        use elliptic_curve::rand_core::OsRng;
+        use sha2::{Digest, Sha256};
+
+        fn sign_batch<'a>(
+            prehashes: &'a [impl AsRef<[u8]>],
+        ) -> Vec<(VerifyingKey, &'a [u8], SignatureWithR)> {
+            prehashes
+                .iter()
+                .map(|prehash| {
+                    let signing_key = SigningKey::random(&mut OsRng);
+                    let (signature, recovery_id) = signing_key
+                        .sign_prehash_recoverable(prehash.as_ref())
+                        .unwrap();
+                    let signature = SignatureWithR::new(signature, recovery_id).unwrap();
+                    (*signing_key.verifying_key(), prehash.as_ref(), signature)
+                })
+                .collect()
+        }
+
+        #[test]
+        fn valid() {
+            let prehashes = [b"first", b"other", b"third"].map(Sha256::digest);
+            let items = sign_batch(&prehashes);
+            assert!(verify_batch(&mut OsRng, &items).is_ok());
+            assert!(verify_batch(&mut OsRng, &items[..1]).is_ok());
+            assert!(verify_batch(&mut OsRng, &[]).is_ok());
+        }
+
+        #[test]
+        fn wrong_prehash() {
+            let prehashes = [b"first", b"other", b"third"].map(Sha256::digest);
+            let mut items = sign_batch(&prehashes);
+            items[1].1 = &prehashes[0];
+            assert!(verify_batch(&mut OsRng, &items).is_err());
+        }
+
+        #[test]
+        fn wrong_verifying_key() {
+            let prehashes = [b"first", b"other", b"third"].map(Sha256::digest);
+            let mut items = sign_batch(&prehashes);
+            items[2].0 = items[0].0;
+            assert!(verify_batch(&mut OsRng, &items).is_err());
+        }
+    }
 }
@@ -0,0 +1,112 @@
+//! Batch verification of ECDSA signatures.
+
+use super::{Error, RecoveryId, Signature};
+use crate::{AffinePoint, Secp256k1, U256};
+use elliptic_curve::{
+    bigint::CheckedAdd, point::DecompressPoint, Curve, FieldBytesEncoding, PrimeField,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    super::VerifyingKey,
+    crate::{arithmetic::lincomb_slice, NonZeroScalar, ProjectivePoint, Scalar},
+    alloc::vec::Vec,
+    ecdsa_core::hazmat::bits2field,
+    elliptic_curve::{group::Group, ops::Reduce, rand_core::CryptoRngCore, scalar::IsHigh},
+};
+
+/// ECDSA/secp256k1 signature along with its ephemeral point `R` (a.k.a. ECDSA*).
+///
+/// ECDSA signatures only contain the x-coordinate of `R` reduced modulo the
+/// curve order, which isn't enough to check several signatures at once.
+/// `R` can be reconstructed from `r` using the [`RecoveryId`] returned when
+/// signing with e.g. [`SigningKey::sign_prehash_recoverable`].
+///
+/// [`SigningKey::sign_prehash_recoverable`]: super::SigningKey::sign_prehash_recoverable
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SignatureWithR {
+    signature: Signature,
+    r_point: AffinePoint,
+}
+
+impl SignatureWithR {
+    /// Reconstruct the point `R` of `signature` using `recovery_id`.
+    pub fn new(signature: Signature, recovery_id: RecoveryId) -> Result<Self, Error> {
+        let mut x = signature.r().to_repr();
+
+        if recovery_id.is_x_reduced() {
+            let x_reduced = <U256 as FieldBytesEncoding<Secp256k1>>::decode_field_bytes(&x);
+            let x_full = Option::<U256>::from(x_reduced.checked_add(&Secp256k1::ORDER))
+                .ok_or_else(Error::new)?;
+            x = FieldBytesEncoding::<Secp256k1>::encode_field_bytes(&x_full);
+        }
+
+        Option::from(AffinePoint::decompress(
+            &x,
+            u8::from(recovery_id.is_y_odd()).into(),
+        ))
+        .map(|r_point| Self { signature, r_point })
+        .ok_or_else(Error::new)
+    }
+
+    /// Borrow the ECDSA signature.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Borrow the ephemeral point `R`.
+    pub fn r_point(&self) -> &AffinePoint {
+        &self.r_point
+    }
+}
+
+impl From<SignatureWithR> for Signature {
+    fn from(signature: SignatureWithR) -> Signature {
+        signature.signature
+    }
+}
+
+/// Verify a batch of `(verifying key, prehash, signature)` triples.
+///
+/// Prehashes are converted to scalars the same way as in
+/// [`PrehashVerifier::verify_prehash`][ecdsa_core::signature::hazmat::PrehashVerifier::verify_prehash].
+///
+/// Rather than checking each signature individually, this samples random
+/// weights `a_i` from `rng` and checks
+/// `sum(a_i * (z_i * G + r_i * Q_i - s_i * R_i)) == 0` using a single
+/// multi-scalar multiplication. Returns an [`Error`] if any of
+/// the signatures is invalid, without indicating which one.
+///
+/// Like single signature verification, this rejects signatures whose `s`
+/// component is high.
+#[cfg(feature = "alloc")]
+pub fn verify_batch(
+    rng: &mut impl CryptoRngCore,
+    items: &[(VerifyingKey, &[u8], SignatureWithR)],
+) -> Result<(), Error> {
+    let mut terms = Vec::with_capacity(2 * items.len() + 1);
+    let mut g_scalar = Scalar::ZERO;
+
+    for (verifying_key, prehash, signature) in items {
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&bits2field::<Secp256k1>(prehash)?);
+        let (r, s) = signature.signature.split_scalars();
+
+        if s.is_high().into() {
+            return Err(Error::new());
+        }
+
+        let a = *NonZeroScalar::random(&mut *rng);
+
+        g_scalar += a * z;
+        terms.push((ProjectivePoint::from(*verifying_key.as_affine()), a * *r));
+        terms.push((ProjectivePoint::from(signature.r_point), -(a * *s)));
+    }
+
+    terms.push((ProjectivePoint::GENERATOR, g_scalar));
+
+    if lincomb_slice(&terms).is_identity().into() {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
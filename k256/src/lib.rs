@@ -26,6 +26,10 @@
 //!
 //! Please see type-specific documentation for more information.
 
+#[cfg(feature = "alloc")]
+#[allow(unused_extern_crates)]
+extern crate alloc;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
@@ -35,6 +39,9 @@ pub mod ecdh;
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
+#[cfg(feature = "musig2")]
+pub mod musig2;
+
 #[cfg(feature = "schnorr")]
 pub mod schnorr;
 
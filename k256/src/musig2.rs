@@ -0,0 +1,88 @@
+//! MuSig2 multi-signatures as defined in [BIP327].
+//!
+//! # About
+//!
+//! MuSig2 allows a group of signers to jointly produce a single [BIP340]
+//! Schnorr signature under an aggregate public key, which is
+//! indistinguishable from a regular BIP340 signature and verifies with
+//! [`schnorr::VerifyingKey`]. It is an `n`-of-`n` scheme: all signers need
+//! to participate in every signing session.
+//!
+//! Signing takes two rounds: in the first one, each signer generates a
+//! [`SecretNonce`] and sends the corresponding [`PublicNonce`] to the
+//! others. This round can be performed before the message is known. In the
+//! second round, each signer computes a [`PartialSignature`], and the
+//! partial signatures are aggregated into the final signature.
+//!
+//! # ⚠️ Warning
+//!
+//! Signing twice using the same [`SecretNonce`] leaks the signing key.
+//! To prevent this, [`SessionContext::sign`] consumes the nonce, which is
+//! zeroized afterwards.
+//!
+//! # Usage
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use k256::{
+//!     musig2::{nonce_gen, AggregatedNonce, KeyAggContext, SessionContext},
+//!     schnorr::signature::hazmat::PrehashVerifier,
+//!     SecretKey,
+//! };
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! let secret_keys = [SecretKey::random(&mut OsRng), SecretKey::random(&mut OsRng)];
+//! let public_keys = secret_keys.clone().map(|sk| sk.public_key());
+//! let key_agg_ctx = KeyAggContext::new(&public_keys)?;
+//! let msg = [0x42; 32];
+//!
+//! //
+//! // Round 1: generate and exchange nonces
+//! //
+//! let (secnonce_a, pubnonce_a) = nonce_gen(&mut OsRng, None, &public_keys[0], None, None, None)?;
+//! let (secnonce_b, pubnonce_b) = nonce_gen(&mut OsRng, None, &public_keys[1], None, None, None)?;
+//! let aggnonce = AggregatedNonce::new(&[pubnonce_a, pubnonce_b]);
+//!
+//! //
+//! // Round 2: compute and exchange partial signatures
+//! //
+//! let session_ctx = SessionContext::new(&key_agg_ctx, &aggnonce, &msg);
+//! let psig_a = session_ctx.sign(secnonce_a, &secret_keys[0])?;
+//! let psig_b = session_ctx.sign(secnonce_b, &secret_keys[1])?;
+//! session_ctx.verify_partial(&psig_b, &pubnonce_b, &public_keys[1])?;
+//!
+//! let signature = session_ctx.aggregate(&[psig_a, psig_b])?;
+//! key_agg_ctx.aggregated_key().verify_prehash(&msg, &signature)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [BIP327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//! [`schnorr::VerifyingKey`]: crate::schnorr::VerifyingKey
+
+mod key_agg;
+mod nonce;
+mod session;
+
+pub use self::{
+    key_agg::{key_sort, KeyAggContext},
+    nonce::{nonce_gen, nonce_gen_with_rand, AggregatedNonce, PublicNonce, SecretNonce},
+    session::{PartialSignature, SessionContext},
+};
+pub use signature::Error;
+
+use crate::AffinePoint;
+use elliptic_curve::subtle::Choice;
+
+const KEYAGG_LIST_TAG: &[u8] = b"KeyAgg list";
+const KEYAGG_COEFFICIENT_TAG: &[u8] = b"KeyAgg coefficient";
+const AUX_TAG: &[u8] = b"MuSig/aux";
+const NONCE_TAG: &[u8] = b"MuSig/nonce";
+const NONCECOEF_TAG: &[u8] = b"MuSig/noncecoef";
+
+/// Does the given point have an odd y-coordinate?
+fn has_odd_y(point: &AffinePoint) -> Choice {
+    point.y.normalize().is_odd()
+}
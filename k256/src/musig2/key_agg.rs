@@ -0,0 +1,174 @@
+//! Key aggregation.
+
+use super::{has_odd_y, KEYAGG_COEFFICIENT_TAG, KEYAGG_LIST_TAG};
+use crate::{
+    arithmetic::lincomb_slice,
+    schnorr::{tagged_hash, VerifyingKey},
+    AffinePoint, ProjectivePoint, PublicKey, Scalar,
+};
+use alloc::vec::Vec;
+use elliptic_curve::{
+    bigint::U256,
+    group::GroupEncoding,
+    ops::{MulByGenerator, Reduce},
+    subtle::{Choice, ConditionallySelectable},
+};
+use sha2::{digest::Output, Digest, Sha256};
+use signature::{Error, Result};
+
+/// Key aggregation context: the aggregate public key of a group of signers
+/// (possibly tweaked), along with the data needed to compute their
+/// individual KeyAgg coefficients.
+#[derive(Clone, Debug)]
+pub struct KeyAggContext {
+    /// Individual public keys of the signers, in order.
+    public_keys: Vec<PublicKey>,
+
+    /// Hash of the list of public keys.
+    list_hash: Output<Sha256>,
+
+    /// First public key which differs from the first one in the list.
+    second_key: Option<PublicKey>,
+
+    /// Aggregate public key `Q`.
+    q: PublicKey,
+
+    /// X-only aggregate public key.
+    aggregated_key: VerifyingKey,
+
+    /// Accumulated sign of the tweaks (`1` or `-1`).
+    gacc: Scalar,
+
+    /// Accumulated tweak.
+    tacc: Scalar,
+}
+
+impl KeyAggContext {
+    /// Aggregate the given public keys.
+    ///
+    /// The aggregate key depends on the order of the public keys, so all
+    /// signers need to agree on it, e.g. by sorting them with [`key_sort`].
+    pub fn new(public_keys: &[PublicKey]) -> Result<Self> {
+        let list_hash = public_keys
+            .iter()
+            .fold(tagged_hash(KEYAGG_LIST_TAG), |hash, public_key| {
+                hash.chain_update(public_key.as_affine().to_bytes())
+            })
+            .finalize();
+
+        let second_key = public_keys
+            .iter()
+            .find(|public_key| Some(*public_key) != public_keys.first())
+            .copied();
+
+        let terms = public_keys
+            .iter()
+            .map(|public_key| {
+                (
+                    public_key.to_projective(),
+                    coefficient(&list_hash, second_key.as_ref(), public_key),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let q =
+            PublicKey::from_affine(lincomb_slice(&terms).to_affine()).map_err(|_| Error::new())?;
+
+        Ok(Self {
+            public_keys: public_keys.to_vec(),
+            list_hash,
+            second_key,
+            q,
+            aggregated_key: VerifyingKey::from_bytes(&q.as_affine().x.to_bytes())?,
+            gacc: Scalar::ONE,
+            tacc: Scalar::ZERO,
+        })
+    }
+
+    /// Apply a tweak to the aggregate public key, i.e. compute `Q + t*G`.
+    ///
+    /// If `is_xonly` is true, the tweak is applied to the x-only aggregate
+    /// key instead (as in e.g. [`schnorr::VerifyingKey::tweak_add`]), i.e.
+    /// `Q` is negated first if its y-coordinate is odd.
+    ///
+    /// [`schnorr::VerifyingKey::tweak_add`]: crate::schnorr::VerifyingKey::tweak_add
+    pub fn with_tweak(mut self, tweak: &Scalar, is_xonly: bool) -> Result<Self> {
+        let q = self.q.as_affine();
+        let negate = has_odd_y(q) & Choice::from(u8::from(is_xonly));
+        let q = AffinePoint::conditional_select(q, &-*q, negate);
+        let q = ProjectivePoint::from(q) + ProjectivePoint::mul_by_generator(tweak);
+
+        self.q = PublicKey::from_affine(q.to_affine()).map_err(|_| Error::new())?;
+        self.aggregated_key = VerifyingKey::from_bytes(&self.q.as_affine().x.to_bytes())?;
+        self.gacc.conditional_assign(&-self.gacc, negate);
+        self.tacc.conditional_assign(&-self.tacc, negate);
+        self.tacc += tweak;
+        Ok(self)
+    }
+
+    /// Get the individual public keys of the signers.
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    /// Get the aggregate public key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.q
+    }
+
+    /// Get the x-only aggregate public key, which verifies the final
+    /// signatures.
+    pub fn aggregated_key(&self) -> &VerifyingKey {
+        &self.aggregated_key
+    }
+
+    /// Get the accumulated sign of the tweaks.
+    pub(super) fn gacc(&self) -> &Scalar {
+        &self.gacc
+    }
+
+    /// Get the accumulated tweak.
+    pub(super) fn tacc(&self) -> &Scalar {
+        &self.tacc
+    }
+
+    /// Get the KeyAgg coefficient of a signer's public key, or an error if
+    /// it isn't one of the aggregated keys.
+    pub(super) fn signer_coefficient(&self, public_key: &PublicKey) -> Result<Scalar> {
+        if self.public_keys.contains(public_key) {
+            Ok(coefficient(
+                &self.list_hash,
+                self.second_key.as_ref(),
+                public_key,
+            ))
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Sort public keys lexicographically by their compressed encoding.
+///
+/// This can be used to make the aggregate key independent of the order in
+/// which the public keys were collected.
+pub fn key_sort(public_keys: &mut [PublicKey]) {
+    public_keys.sort_unstable_by_key(|public_key| public_key.as_affine().to_bytes());
+}
+
+/// Compute the KeyAgg coefficient of a public key.
+fn coefficient(
+    list_hash: &Output<Sha256>,
+    second_key: Option<&PublicKey>,
+    public_key: &PublicKey,
+) -> Scalar {
+    if Some(public_key) == second_key {
+        return Scalar::ONE;
+    }
+
+    <Scalar as Reduce<U256>>::reduce_bytes(
+        &tagged_hash(KEYAGG_COEFFICIENT_TAG)
+            .chain_update(list_hash)
+            .chain_update(public_key.as_affine().to_bytes())
+            .finalize(),
+    )
+}
@@ -0,0 +1,289 @@
+//! Nonce generation and aggregation.
+
+use super::{AUX_TAG, NONCE_TAG};
+use crate::{
+    schnorr::{tagged_hash, CryptoRngCore, VerifyingKey},
+    AffinePoint, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey,
+};
+use elliptic_curve::{
+    bigint::U256,
+    group::{prime::PrimeCurveAffine, GroupEncoding},
+    ops::{MulByGenerator, Reduce},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+    PrimeField,
+};
+use sha2::Digest;
+use signature::{Error, Result};
+
+/// Size of a serialized [`SecretNonce`] in bytes.
+const SECRET_NONCE_SIZE: usize = 97;
+
+/// Size of a serialized [`PublicNonce`] or [`AggregatedNonce`] in bytes.
+const PUBLIC_NONCE_SIZE: usize = 66;
+
+/// Secret nonce of a signer, consisting of two nonzero scalars along with
+/// the signer's public key.
+///
+/// # ⚠️ Warning
+///
+/// This value is key material, and must only be used for a single signing
+/// session: see the [module-level documentation](super) for details.
+pub struct SecretNonce {
+    /// Secret nonce scalars.
+    k: [NonZeroScalar; 2],
+
+    /// Public key of the signer.
+    public_key: PublicKey,
+}
+
+impl SecretNonce {
+    /// Parse secret nonce from its 97-byte serialization.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != SECRET_NONCE_SIZE {
+            return Err(Error::new());
+        }
+
+        let k1 = NonZeroScalar::try_from(&bytes[..32]).map_err(|_| Error::new())?;
+        let k2 = NonZeroScalar::try_from(&bytes[32..64]).map_err(|_| Error::new())?;
+        let public_key = PublicKey::from_sec1_bytes(&bytes[64..]).map_err(|_| Error::new())?;
+
+        Ok(Self {
+            k: [k1, k2],
+            public_key,
+        })
+    }
+
+    /// Serialize as bytes.
+    ///
+    /// The result is key material, and must be handled with the same care
+    /// as the secret nonce itself.
+    pub fn to_bytes(&self) -> [u8; SECRET_NONCE_SIZE] {
+        let mut bytes = [0u8; SECRET_NONCE_SIZE];
+        bytes[..32].copy_from_slice(&self.k[0].to_repr());
+        bytes[32..64].copy_from_slice(&self.k[1].to_repr());
+        bytes[64..].copy_from_slice(&self.public_key.as_affine().to_bytes());
+        bytes
+    }
+
+    /// Get the [`PublicNonce`] corresponding to this secret nonce.
+    pub fn public_nonce(&self) -> PublicNonce {
+        PublicNonce(
+            self.k
+                .map(|k| ProjectivePoint::mul_by_generator(&k).to_affine()),
+        )
+    }
+
+    /// Borrow the secret nonce scalars.
+    pub(super) fn k(&self) -> &[NonZeroScalar; 2] {
+        &self.k
+    }
+
+    /// Borrow the public key of the signer.
+    pub(super) fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+impl Drop for SecretNonce {
+    fn drop(&mut self) {
+        self.k.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretNonce {}
+
+/// Public nonce of a signer, which is sent to all other signers in the first
+/// round of a signing session.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PublicNonce([AffinePoint; 2]);
+
+impl PublicNonce {
+    /// Parse public nonce from its 66-byte serialization, i.e. two
+    /// compressed points.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != PUBLIC_NONCE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (r1, r2) = bytes.split_at(PUBLIC_NONCE_SIZE / 2);
+        Ok(Self([decode_point(r1)?, decode_point(r2)?]))
+    }
+
+    /// Serialize as bytes.
+    pub fn to_bytes(&self) -> [u8; PUBLIC_NONCE_SIZE] {
+        encode_points(&self.0)
+    }
+
+    /// Borrow the public nonce points.
+    pub(super) fn r(&self) -> &[AffinePoint; 2] {
+        &self.0
+    }
+}
+
+/// Aggregate of the public nonces of all signers in a signing session.
+///
+/// Computing it can be offloaded to an untrusted coordinator, which then
+/// sends it to all signers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AggregatedNonce([AffinePoint; 2]);
+
+impl AggregatedNonce {
+    /// Aggregate the public nonces of all signers.
+    pub fn new(public_nonces: &[PublicNonce]) -> Self {
+        let mut r = [ProjectivePoint::IDENTITY; 2];
+
+        for public_nonce in public_nonces {
+            for (r, public_nonce) in r.iter_mut().zip(public_nonce.0.iter()) {
+                *r += public_nonce;
+            }
+        }
+
+        Self(r.map(|r| r.to_affine()))
+    }
+
+    /// Parse aggregated nonce from its 66-byte serialization, i.e. two
+    /// compressed points, where 33 zero bytes encode the identity.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != PUBLIC_NONCE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (r1, r2) = bytes.split_at(PUBLIC_NONCE_SIZE / 2);
+        Ok(Self([decode_point_ext(r1)?, decode_point_ext(r2)?]))
+    }
+
+    /// Serialize as bytes.
+    pub fn to_bytes(&self) -> [u8; PUBLIC_NONCE_SIZE] {
+        encode_points(&self.0)
+    }
+
+    /// Borrow the aggregated nonce points.
+    pub(super) fn r(&self) -> &[AffinePoint; 2] {
+        &self.0
+    }
+}
+
+/// Generate a fresh nonce pair for a signing session.
+///
+/// The optional inputs aren't required for security, but passing them
+/// (particularly `secret_key`) provides additional protection in case `rng`
+/// is faulty:
+///
+/// - `secret_key`: secret key of the signer
+/// - `aggregated_key`: x-only aggregate public key
+/// - `msg`: message to be signed
+/// - `extra_in`: any other auxiliary input
+pub fn nonce_gen(
+    rng: &mut impl CryptoRngCore,
+    secret_key: Option<&SecretKey>,
+    public_key: &PublicKey,
+    aggregated_key: Option<&VerifyingKey>,
+    msg: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> Result<(SecretNonce, PublicNonce)> {
+    let mut rand = [0u8; 32];
+    rng.fill_bytes(&mut rand);
+    let ret = nonce_gen_with_rand(&rand, secret_key, public_key, aggregated_key, msg, extra_in);
+    rand.zeroize();
+    ret
+}
+
+/// Generate a nonce pair for a signing session from the given randomness.
+///
+/// # ⚠️ Warning
+///
+/// This is a low-level interface intended only for testing: `rand` must be
+/// uniformly random and never reused. The preferred interface is
+/// [`nonce_gen`].
+pub fn nonce_gen_with_rand(
+    rand: &[u8; 32],
+    secret_key: Option<&SecretKey>,
+    public_key: &PublicKey,
+    aggregated_key: Option<&VerifyingKey>,
+    msg: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> Result<(SecretNonce, PublicNonce)> {
+    let mut rand = *rand;
+
+    if let Some(secret_key) = secret_key {
+        let aux = tagged_hash(AUX_TAG).chain_update(rand).finalize();
+
+        for ((r, a), b) in rand
+            .iter_mut()
+            .zip(secret_key.to_bytes().iter())
+            .zip(aux.iter())
+        {
+            *r = a ^ b;
+        }
+    }
+
+    let public_key_bytes = public_key.as_affine().to_bytes();
+    let aggregated_key_bytes = aggregated_key.map(VerifyingKey::to_bytes);
+    let aggregated_key_bytes = aggregated_key_bytes.as_ref().map_or(&[][..], |b| &b[..]);
+    let extra_in = extra_in.unwrap_or_default();
+
+    let mut hash = tagged_hash(NONCE_TAG)
+        .chain_update(rand)
+        .chain_update([public_key_bytes.len() as u8])
+        .chain_update(public_key_bytes)
+        .chain_update([aggregated_key_bytes.len() as u8])
+        .chain_update(aggregated_key_bytes);
+
+    match msg {
+        Some(msg) => {
+            hash.update([1]);
+            hash.update((msg.len() as u64).to_be_bytes());
+            hash.update(msg);
+        }
+        None => hash.update([0]),
+    }
+
+    hash.update((extra_in.len() as u32).to_be_bytes());
+    hash.update(extra_in);
+    rand.zeroize();
+
+    let k = |i: u8| {
+        let digest = hash.clone().chain_update([i]).finalize();
+        let scalar = <Scalar as Reduce<U256>>::reduce_bytes(&digest);
+        Option::from(NonZeroScalar::new(scalar)).ok_or_else(Error::new)
+    };
+
+    let secret_nonce = SecretNonce {
+        k: [k(0)?, k(1)?],
+        public_key: *public_key,
+    };
+    let public_nonce = secret_nonce.public_nonce();
+    Ok((secret_nonce, public_nonce))
+}
+
+/// Decode a compressed point.
+fn decode_point(bytes: &[u8]) -> Result<AffinePoint> {
+    PublicKey::from_sec1_bytes(bytes)
+        .map(|public_key| *public_key.as_affine())
+        .map_err(|_| Error::new())
+}
+
+/// Decode a compressed point, or 33 zero bytes for the identity.
+fn decode_point_ext(bytes: &[u8]) -> Result<AffinePoint> {
+    if bytes.iter().all(|&b| b == 0) {
+        Ok(AffinePoint::IDENTITY)
+    } else {
+        decode_point(bytes)
+    }
+}
+
+/// Encode two points as compressed points, using 33 zero bytes for the
+/// identity.
+fn encode_points(points: &[AffinePoint; 2]) -> [u8; PUBLIC_NONCE_SIZE] {
+    let mut bytes = [0u8; PUBLIC_NONCE_SIZE];
+
+    for (chunk, point) in bytes.chunks_mut(PUBLIC_NONCE_SIZE / 2).zip(points) {
+        if point.is_identity().into() {
+            continue;
+        }
+
+        chunk.copy_from_slice(&point.to_bytes());
+    }
+
+    bytes
+}
@@ -0,0 +1,179 @@
+//! Signing sessions: partial signing, partial signature verification and
+//! signature aggregation.
+
+use super::{has_odd_y, AggregatedNonce, KeyAggContext, PublicNonce, SecretNonce, NONCECOEF_TAG};
+use crate::{
+    schnorr::{challenge, tagged_hash, Signature},
+    AffinePoint, FieldBytes, ProjectivePoint, PublicKey, Scalar, SecretKey,
+};
+use elliptic_curve::{
+    bigint::U256,
+    group::prime::PrimeCurveAffine,
+    ops::{LinearCombination, MulByGenerator, Reduce},
+    subtle::{Choice, ConditionallySelectable},
+    PrimeField,
+};
+use sha2::Digest;
+use signature::{Error, Result};
+
+/// Size of a serialized [`PartialSignature`] in bytes.
+const PARTIAL_SIGNATURE_SIZE: usize = 32;
+
+/// Partial signature of a signer, which is sent to the aggregator in the
+/// second round of a signing session.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PartialSignature(Scalar);
+
+impl PartialSignature {
+    /// Parse partial signature from big endian-encoded bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != PARTIAL_SIGNATURE_SIZE {
+            return Err(Error::new());
+        }
+
+        Option::from(Scalar::from_repr(*FieldBytes::from_slice(bytes)))
+            .map(Self)
+            .ok_or_else(Error::new)
+    }
+
+    /// Serialize as bytes.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+}
+
+/// Signing session for a particular message, aggregate key and aggregated
+/// nonce.
+#[derive(Clone, Debug)]
+pub struct SessionContext<'a> {
+    /// Key aggregation context of the signers.
+    key_agg_ctx: &'a KeyAggContext,
+
+    /// Nonce coefficient `b`.
+    b: Scalar,
+
+    /// Final nonce `R`.
+    r: AffinePoint,
+
+    /// Challenge `e`.
+    e: Scalar,
+}
+
+impl<'a> SessionContext<'a> {
+    /// Start a signing session for `msg`.
+    pub fn new(key_agg_ctx: &'a KeyAggContext, aggnonce: &AggregatedNonce, msg: &[u8]) -> Self {
+        let aggregated_key = key_agg_ctx.aggregated_key();
+
+        let b = <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(NONCECOEF_TAG)
+                .chain_update(aggnonce.to_bytes())
+                .chain_update(aggregated_key.to_bytes())
+                .chain_update(msg)
+                .finalize(),
+        );
+
+        let [r1, r2] = aggnonce.r();
+        let r = ProjectivePoint::lincomb(
+            &ProjectivePoint::from(*r1),
+            &Scalar::ONE,
+            &ProjectivePoint::from(*r2),
+            &b,
+        )
+        .to_affine();
+
+        let r = AffinePoint::conditional_select(&r, &AffinePoint::GENERATOR, r.is_identity());
+
+        let e = challenge(&r.x.normalize(), aggregated_key, msg);
+
+        Self {
+            key_agg_ctx,
+            b,
+            r,
+            e,
+        }
+    }
+
+    /// Compute the partial signature of a signer, consuming its secret
+    /// nonce.
+    pub fn sign(&self, secnonce: SecretNonce, secret_key: &SecretKey) -> Result<PartialSignature> {
+        let public_key = secret_key.public_key();
+
+        if &public_key != secnonce.public_key() {
+            return Err(Error::new());
+        }
+
+        let a = self.key_agg_ctx.signer_coefficient(&public_key)?;
+
+        let r_odd = has_odd_y(&self.r);
+        let [k1, k2] = secnonce
+            .k()
+            .map(|k| Scalar::conditional_select(&k, &-*k, r_odd));
+
+        let mut d = *secret_key.to_nonzero_scalar() * self.key_agg_ctx.gacc();
+        d.conditional_assign(&-d, self.q_odd());
+
+        let psig = PartialSignature(k1 + self.b * k2 + self.e * a * d);
+
+        #[cfg(debug_assertions)]
+        self.verify_partial(&psig, &secnonce.public_nonce(), &public_key)?;
+
+        Ok(psig)
+    }
+
+    /// Verify the partial signature of a signer, given its public nonce and
+    /// public key.
+    pub fn verify_partial(
+        &self,
+        psig: &PartialSignature,
+        pubnonce: &PublicNonce,
+        public_key: &PublicKey,
+    ) -> Result<()> {
+        let a = self.key_agg_ctx.signer_coefficient(public_key)?;
+
+        let [r1, r2] = pubnonce.r();
+        let re = ProjectivePoint::lincomb(
+            &ProjectivePoint::from(*r1),
+            &Scalar::ONE,
+            &ProjectivePoint::from(*r2),
+            &self.b,
+        );
+        let re = ProjectivePoint::conditional_select(&re, &-re, has_odd_y(&self.r));
+
+        let mut g = *self.key_agg_ctx.gacc();
+        g.conditional_assign(&-g, self.q_odd());
+
+        let lhs = ProjectivePoint::mul_by_generator(&psig.0);
+        let rhs = re + public_key.to_projective() * (self.e * a * g);
+
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Aggregate the partial signatures of all signers into the final
+    /// signature.
+    ///
+    /// The partial signatures are not verified, so if the result is invalid,
+    /// [`SessionContext::verify_partial`] can be used to find the culprits.
+    pub fn aggregate(&self, psigs: &[PartialSignature]) -> Result<Signature> {
+        let mut g = Scalar::ONE;
+        g.conditional_assign(&-g, self.q_odd());
+
+        let s = psigs
+            .iter()
+            .fold(self.e * g * self.key_agg_ctx.tacc(), |s, psig| s + psig.0);
+
+        let mut bytes = [0u8; Signature::BYTE_SIZE];
+        let (r_bytes, s_bytes) = bytes.split_at_mut(Signature::BYTE_SIZE / 2);
+        r_bytes.copy_from_slice(&self.r.x.to_bytes());
+        s_bytes.copy_from_slice(&s.to_bytes());
+        Signature::try_from(&bytes[..])
+    }
+
+    /// Does the aggregate public key have an odd y-coordinate?
+    fn q_odd(&self) -> Choice {
+        has_odd_y(self.key_agg_ctx.public_key().as_affine())
+    }
+}
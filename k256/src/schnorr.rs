@@ -62,21 +62,29 @@
 
 #![allow(non_snake_case, clippy::many_single_char_names)]
 
+mod adaptor;
 mod signing;
 mod verifying;
 
-pub use self::{signing::SigningKey, verifying::VerifyingKey};
+#[cfg(feature = "alloc")]
+mod batch;
+
+pub use self::{adaptor::AdaptorSignature, signing::SigningKey, verifying::VerifyingKey};
 pub use signature::{self, rand_core::CryptoRngCore, Error};
 
-use crate::{arithmetic::FieldElement, NonZeroScalar};
+#[cfg(feature = "alloc")]
+pub use self::batch::{locate_invalid, verify_batch};
+
+use crate::{arithmetic::FieldElement, NonZeroScalar, Scalar};
 use core::fmt;
-use elliptic_curve::subtle::ConstantTimeEq;
+use elliptic_curve::{bigint::U256, ops::Reduce, subtle::ConstantTimeEq};
 use sha2::{Digest, Sha256};
 use signature::Result;
 
 const AUX_TAG: &[u8] = b"BIP0340/aux";
 const NONCE_TAG: &[u8] = b"BIP0340/nonce";
 const CHALLENGE_TAG: &[u8] = b"BIP0340/challenge";
+const TAPTWEAK_TAG: &[u8] = b"TapTweak";
 
 /// Taproot Schnorr signature serialized as bytes.
 pub type SignatureBytes = [u8; Signature::BYTE_SIZE];
@@ -177,7 +185,7 @@ impl signature::PrehashSignature for Signature {
     type Digest = Sha256;
 }
 
-fn tagged_hash(tag: &[u8]) -> Sha256 {
+pub(crate) fn tagged_hash(tag: &[u8]) -> Sha256 {
     let tag_hash = Sha256::digest(tag);
     let mut digest = Sha256::new();
     digest.update(tag_hash);
@@ -185,6 +193,18 @@ fn tagged_hash(tag: &[u8]) -> Sha256 {
     digest
 }
 
+/// Compute the challenge `e` for the nonce x-coordinate `r`, the verifying
+/// key, and the message.
+pub(crate) fn challenge(r: &FieldElement, verifying_key: &VerifyingKey, msg: &[u8]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(
+        &tagged_hash(CHALLENGE_TAG)
+            .chain_update(r.to_bytes())
+            .chain_update(verifying_key.to_bytes())
+            .chain_update(msg)
+            .finalize(),
+    )
+}
+
 // Test vectors from:
 // https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
 #[cfg(test)]
@@ -447,4 +467,217 @@ mod tests {
             );
         }
     }
+
+    /// BIP341 test vectors (from `wallet-test-vectors.json`)
+    mod taproot {
+        use crate::schnorr::{SigningKey, VerifyingKey};
+        use hex_literal::hex;
+
+        #[test]
+        fn output_key_without_scripts() {
+            let internal_key = VerifyingKey::from_bytes(&hex!(
+                "d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d"
+            ))
+            .unwrap();
+            let (output_key, _) = internal_key.taproot_output_key(None).unwrap();
+            assert_eq!(
+                output_key.to_bytes().as_slice(),
+                &hex!("53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343")
+            );
+        }
+
+        #[test]
+        fn output_key_with_scripts() {
+            let internal_key = VerifyingKey::from_bytes(&hex!(
+                "187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27"
+            ))
+            .unwrap();
+            let merkle_root =
+                hex!("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21");
+            let (output_key, _) = internal_key.taproot_output_key(Some(&merkle_root)).unwrap();
+            assert_eq!(
+                output_key.to_bytes().as_slice(),
+                &hex!("147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3")
+            );
+        }
+
+        #[test]
+        fn signing_key_tweak() {
+            let internal_key = SigningKey::from_bytes(&hex!(
+                "6b973d88838f27366ed61c9ad6367663045cb456e28335c109e30717ae0c6baa"
+            ))
+            .unwrap();
+            let (output_key, odd) = internal_key.taproot_tweak(None).unwrap();
+
+            // BIP341 doesn't negate the tweaked key when the output key has
+            // an odd y-coordinate, while `SigningKey` always does
+            let expected_key = SigningKey::from_bytes(&hex!(
+                "2405b971772ad26915c8dcdf10f238753a9b837e5f8e6a86fd7c0cce5b7296d9"
+            ))
+            .unwrap();
+            assert!(odd);
+            assert_eq!(output_key.to_bytes(), expected_key.to_bytes());
+            assert_eq!(
+                output_key.verifying_key(),
+                &internal_key
+                    .verifying_key()
+                    .taproot_output_key(None)
+                    .unwrap()
+                    .0
+            );
+        }
+    }
+
+    mod adaptor {
+        use super::BIP340_SIGN_VECTORS;
+        use crate::{
+            schnorr::{AdaptorSignature, SigningKey, VerifyingKey},
+            NonZeroScalar, PublicKey, Scalar,
+        };
+        use signature::hazmat::PrehashVerifier;
+
+        /// Adaptor secrets to test with, giving final nonces of both parities
+        fn secrets() -> impl Iterator<Item = (NonZeroScalar, PublicKey)> {
+            (1..=4u64).map(|t| {
+                let t = NonZeroScalar::new(Scalar::from(t)).unwrap();
+                (t, PublicKey::from_secret_scalar(&t))
+            })
+        }
+
+        #[test]
+        fn pre_sign_adapt_extract() {
+            for vector in BIP340_SIGN_VECTORS {
+                let sk = SigningKey::from_bytes(&vector.secret_key).unwrap();
+                let vk = VerifyingKey::from_bytes(&vector.public_key).unwrap();
+
+                for (secret, adaptor_point) in secrets() {
+                    let pre_sig = sk
+                        .pre_sign_prehash_with_aux_rand(
+                            &vector.message,
+                            &adaptor_point,
+                            &vector.aux_rand,
+                        )
+                        .unwrap();
+                    vk.pre_verify_prehash(&vector.message, &adaptor_point, &pre_sig)
+                        .unwrap();
+
+                    let sig = pre_sig.adapt(&secret).unwrap();
+                    vk.verify_prehash(&vector.message, &sig).unwrap();
+                    assert_eq!(*pre_sig.extract_secret(&sig).unwrap(), *secret);
+
+                    // Nonces must never coincide with those of regular signatures
+                    let regular_sig = sk
+                        .sign_prehash_with_aux_rand(&vector.message, &vector.aux_rand)
+                        .unwrap();
+                    assert_ne!(regular_sig.r, sig.r);
+                    assert!(pre_sig.extract_secret(&regular_sig).is_err());
+                }
+            }
+        }
+
+        #[test]
+        fn pre_verify_rejects_wrong_inputs() {
+            let vector = &BIP340_SIGN_VECTORS[1];
+            let sk = SigningKey::from_bytes(&vector.secret_key).unwrap();
+            let mut secrets = secrets();
+            let (secret, adaptor_point) = secrets.next().unwrap();
+            let (_, other_point) = secrets.next().unwrap();
+
+            let pre_sig = sk
+                .pre_sign_prehash_with_aux_rand(&vector.message, &adaptor_point, &vector.aux_rand)
+                .unwrap();
+
+            let vk = sk.verifying_key();
+            assert!(vk
+                .pre_verify_prehash(&vector.message, &other_point, &pre_sig)
+                .is_err());
+            assert!(vk
+                .pre_verify_prehash(&[0u8; 32], &adaptor_point, &pre_sig)
+                .is_err());
+
+            let other_vk = VerifyingKey::from_bytes(&BIP340_SIGN_VECTORS[2].public_key).unwrap();
+            assert!(other_vk
+                .pre_verify_prehash(&vector.message, &adaptor_point, &pre_sig)
+                .is_err());
+
+            // Adapting with the wrong secret doesn't produce a valid signature
+            let wrong_secret = NonZeroScalar::new(*secret + Scalar::ONE).unwrap();
+            let sig = pre_sig.adapt(&wrong_secret).unwrap();
+            assert!(vk.verify_prehash(&vector.message, &sig).is_err());
+        }
+
+        #[test]
+        fn serialization_roundtrip() {
+            let vector = &BIP340_SIGN_VECTORS[0];
+            let sk = SigningKey::from_bytes(&vector.secret_key).unwrap();
+            let (_, adaptor_point) = secrets().next().unwrap();
+
+            let pre_sig = sk.pre_sign(b"adaptor", &adaptor_point).unwrap();
+            let bytes = pre_sig.to_bytes();
+            assert_eq!(AdaptorSignature::from_bytes(&bytes).unwrap(), pre_sig);
+            assert!(AdaptorSignature::from_bytes(&bytes[1..]).is_err());
+
+            sk.verifying_key()
+                .pre_verify(b"adaptor", &adaptor_point, &pre_sig)
+                .unwrap();
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod batch {
+        use super::{BIP340_SIGN_VECTORS, BIP340_VERIFY_VECTORS};
+        use crate::schnorr::{locate_invalid, verify_batch, Signature, VerifyingKey};
+        use alloc::vec::Vec;
+        use elliptic_curve::rand_core::OsRng;
+
+        fn valid_items() -> Vec<(VerifyingKey, &'static [u8], Signature)> {
+            BIP340_SIGN_VECTORS
+                .iter()
+                .map(|vector| (&vector.public_key, &vector.message, &vector.signature))
+                .chain(
+                    BIP340_VERIFY_VECTORS
+                        .iter()
+                        .filter(|vector| vector.valid)
+                        .map(|vector| (&vector.public_key, &vector.message, &vector.signature)),
+                )
+                .map(|(public_key, message, signature)| {
+                    (
+                        VerifyingKey::from_bytes(public_key).unwrap(),
+                        message.as_slice(),
+                        Signature::try_from(signature.as_slice()).unwrap(),
+                    )
+                })
+                .collect()
+        }
+
+        #[test]
+        fn bip340_valid_batch() {
+            let items = valid_items();
+            assert!(verify_batch(&mut OsRng, &items).is_ok());
+            assert_eq!(locate_invalid(&items), None);
+        }
+
+        #[test]
+        fn bip340_invalid_batch() {
+            for vector in BIP340_VERIFY_VECTORS.iter().filter(|vector| !vector.valid) {
+                let (pk, sig) = match (
+                    VerifyingKey::from_bytes(&vector.public_key),
+                    Signature::try_from(vector.signature.as_slice()),
+                ) {
+                    (Ok(pk), Ok(sig)) => (pk, sig),
+                    _ => continue,
+                };
+
+                let mut items = valid_items();
+                items.insert(1, (pk, &vector.message, sig));
+
+                assert!(
+                    verify_batch(&mut OsRng, &items).is_err(),
+                    "batch containing index {} verified",
+                    vector.index
+                );
+                assert_eq!(locate_invalid(&items), Some(1));
+            }
+        }
+    }
 }
@@ -0,0 +1,102 @@
+//! Schnorr adaptor signatures.
+//!
+//! An adaptor signature (a.k.a. pre-signature) for an adaptor point `T` is
+//! a signature which only becomes a valid [BIP340] signature once it is
+//! adapted with the discrete logarithm `t` of `T`, and which in turn reveals
+//! `t` to anyone holding the pre-signature once the adapted signature is
+//! published. This is the building block of e.g. atomic swaps and payment
+//! channels.
+//!
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+use super::Signature;
+use crate::{AffinePoint, FieldBytes, NonZeroScalar, PublicKey, Scalar};
+use elliptic_curve::{
+    group::GroupEncoding,
+    subtle::{Choice, ConditionallySelectable},
+    PrimeField,
+};
+use signature::{Error, Result};
+
+/// Size of a serialized [`AdaptorSignature`] in bytes.
+const ADAPTOR_SIGNATURE_SIZE: usize = 65;
+
+/// Schnorr adaptor signature: the final nonce point `R = k*G + T` along
+/// with the pre-signature scalar `s' = k + e*d`.
+///
+/// Unlike in a [`Signature`], the full nonce point is kept, since the
+/// parity of its y-coordinate determines how the adaptor secret is applied.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AdaptorSignature {
+    /// Final nonce point `R`.
+    pub(super) r: PublicKey,
+
+    /// Pre-signature scalar `s'`.
+    pub(super) s: Scalar,
+}
+
+impl AdaptorSignature {
+    /// Parse adaptor signature from its 65-byte serialization, i.e. the
+    /// compressed nonce point followed by the big endian-encoded scalar.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != ADAPTOR_SIGNATURE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (r_bytes, s_bytes) = bytes.split_at(ADAPTOR_SIGNATURE_SIZE - 32);
+        let r = PublicKey::from_sec1_bytes(r_bytes).map_err(|_| Error::new())?;
+        let s = Option::from(Scalar::from_repr(*FieldBytes::from_slice(s_bytes)))
+            .ok_or_else(Error::new)?;
+        Ok(Self { r, s })
+    }
+
+    /// Serialize as bytes.
+    pub fn to_bytes(&self) -> [u8; ADAPTOR_SIGNATURE_SIZE] {
+        let mut bytes = [0u8; ADAPTOR_SIGNATURE_SIZE];
+        let (r_bytes, s_bytes) = bytes.split_at_mut(ADAPTOR_SIGNATURE_SIZE - 32);
+        r_bytes.copy_from_slice(&self.r.as_affine().to_bytes());
+        s_bytes.copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Adapt this pre-signature into a valid [`Signature`] using the adaptor
+    /// secret `t`, i.e. the discrete logarithm of the adaptor point.
+    ///
+    /// The adaptor secret isn't checked against the pre-signature, so the
+    /// result should be verified if `t` comes from an untrusted source.
+    pub fn adapt(&self, secret: &NonZeroScalar) -> Result<Signature> {
+        let t = Scalar::conditional_select(secret, &-**secret, self.r_odd());
+        let s = Option::from(NonZeroScalar::new(self.s + t)).ok_or_else(Error::new)?;
+
+        Ok(Signature {
+            r: self.r.as_affine().x.normalize(),
+            s,
+        })
+    }
+
+    /// Extract the adaptor secret `t` from this pre-signature and the
+    /// corresponding adapted [`Signature`].
+    ///
+    /// Returns an error if the signature doesn't share this pre-signature's
+    /// nonce. Otherwise, the extracted secret is only meaningful if the
+    /// signature is valid, and can be checked against the adaptor point.
+    pub fn extract_secret(&self, signature: &Signature) -> Result<NonZeroScalar> {
+        if signature.r != self.r.as_affine().x.normalize() {
+            return Err(Error::new());
+        }
+
+        let t = **signature.s() - self.s;
+        let t = Scalar::conditional_select(&t, &-t, self.r_odd());
+        Option::from(NonZeroScalar::new(t)).ok_or_else(Error::new)
+    }
+
+    /// Borrow the final nonce point.
+    pub(super) fn r(&self) -> &AffinePoint {
+        self.r.as_affine()
+    }
+
+    /// Does the final nonce point have an odd y-coordinate?
+    pub(super) fn r_odd(&self) -> Choice {
+        self.r.as_affine().y.normalize().is_odd()
+    }
+}
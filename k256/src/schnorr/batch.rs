@@ -0,0 +1,68 @@
+//! Batch verification of Taproot Schnorr signatures.
+
+use super::{challenge, Signature, VerifyingKey};
+use crate::{arithmetic::lincomb_slice, AffinePoint, NonZeroScalar, ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use elliptic_curve::{group::Group, point::DecompactPoint, rand_core::CryptoRngCore};
+use signature::{hazmat::PrehashVerifier, Error, Result};
+
+/// Verify a batch of `(verifying key, message digest, signature)` triples
+/// using the batch verification algorithm from [BIP340].
+///
+/// Message digests are 32 bytes, as in [`PrehashVerifier::verify_prehash`].
+///
+/// The first signature gets weight `1` and all others get random weights
+/// sampled from `rng`, and all of the weighted verification equations are
+/// summed up and checked using a single multi-scalar multiplication. Returns
+/// an [`Error`] if any of the signatures is invalid, without indicating
+/// which one: see [`locate_invalid`] for that.
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki#batch-verification
+pub fn verify_batch(
+    rng: &mut impl CryptoRngCore,
+    items: &[(VerifyingKey, &[u8], Signature)],
+) -> Result<()> {
+    let mut terms = Vec::with_capacity(2 * items.len() + 1);
+    let mut g_scalar = Scalar::ZERO;
+
+    for (i, (verifying_key, msg_digest, signature)) in items.iter().enumerate() {
+        let msg_digest: &[u8; 32] = (*msg_digest).try_into().map_err(|_| Error::new())?;
+        let (r, s) = signature.split();
+
+        let e = challenge(r, verifying_key, msg_digest);
+        let R = Option::<AffinePoint>::from(AffinePoint::decompact(&r.to_bytes()))
+            .ok_or_else(Error::new)?;
+
+        let a = if i == 0 {
+            Scalar::ONE
+        } else {
+            *NonZeroScalar::random(&mut *rng)
+        };
+
+        g_scalar -= a * s.as_ref();
+        terms.push((ProjectivePoint::from(R), a));
+        terms.push((verifying_key.inner.to_projective(), a * e));
+    }
+
+    terms.push((ProjectivePoint::GENERATOR, g_scalar));
+
+    if lincomb_slice(&terms).is_identity().into() {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Find the index of the first invalid signature in a batch of
+/// `(verifying key, message digest, signature)` triples, by verifying them
+/// one at a time.
+///
+/// Intended to be used after [`verify_batch`] has failed. Returns `None` if
+/// all of the signatures are valid.
+pub fn locate_invalid(items: &[(VerifyingKey, &[u8], Signature)]) -> Option<usize> {
+    items
+        .iter()
+        .position(|(verifying_key, msg_digest, signature)| {
+            verifying_key.verify_prehash(msg_digest, signature).is_err()
+        })
+}
@@ -1,14 +1,16 @@
 //! Taproot Schnorr signing key.
 
-use super::{tagged_hash, Signature, VerifyingKey, AUX_TAG, CHALLENGE_TAG, NONCE_TAG};
+use super::{
+    challenge, tagged_hash, AdaptorSignature, Signature, VerifyingKey, AUX_TAG, NONCE_TAG,
+};
 use crate::{
     AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey,
 };
 use elliptic_curve::{
-    bigint::U256,
-    ops::Reduce,
+    group::GroupEncoding,
+    ops::MulByGenerator,
     rand_core::CryptoRngCore,
-    subtle::ConditionallySelectable,
+    subtle::{Choice, ConditionallySelectable},
     zeroize::{Zeroize, ZeroizeOnDrop},
 };
 use sha2::{Digest, Sha256};
@@ -68,6 +70,38 @@ impl SigningKey {
         &self.secret_key
     }
 
+    /// Compute the tweaked signing key `d + t`, i.e. the signing key for
+    /// [`VerifyingKey::tweak_add`].
+    ///
+    /// Returns the tweaked key along with whether the y-coordinate of the
+    /// tweaked public key is odd, in which case the tweaked key is negated
+    /// (like any other [`SigningKey`]).
+    pub fn tweak_add(&self, tweak: &Scalar) -> Result<(Self, bool)> {
+        let (verifying_key, odd) = self.verifying_key.tweak_add(tweak)?;
+
+        let mut secret_key = *self.secret_key + tweak;
+        secret_key.conditional_assign(&-secret_key, Choice::from(u8::from(odd)));
+
+        let secret_key = Option::from(NonZeroScalar::new(secret_key)).ok_or_else(Error::new)?;
+
+        Ok((
+            Self {
+                secret_key,
+                verifying_key,
+            },
+            odd,
+        ))
+    }
+
+    /// Compute the signing key for the Taproot output key of this internal
+    /// key, i.e. the signing key for [`VerifyingKey::taproot_output_key`].
+    ///
+    /// Returns the tweaked key along with the parity of the y-coordinate of
+    /// the output key.
+    pub fn taproot_tweak(&self, merkle_root: Option<&[u8; 32]>) -> Result<(Self, bool)> {
+        self.tweak_add(&self.verifying_key.taproot_tweak(merkle_root)?)
+    }
+
     /// Compute Schnorr signature.
     ///
     /// # ⚠️ Warning
@@ -81,33 +115,13 @@ impl SigningKey {
         msg_digest: &[u8; 32],
         aux_rand: &[u8; 32],
     ) -> Result<Signature> {
-        let mut t = tagged_hash(AUX_TAG).chain_update(aux_rand).finalize();
-
-        for (a, b) in t.iter_mut().zip(self.secret_key.to_bytes().iter()) {
-            *a ^= b
-        }
-
-        let rand = tagged_hash(NONCE_TAG)
-            .chain_update(t)
-            .chain_update(self.verifying_key.as_affine().x.to_bytes())
-            .chain_update(msg_digest)
-            .finalize();
-
-        let k = NonZeroScalar::try_from(&*rand)
-            .map(Self::from)
-            .map_err(|_| Error::new())?;
+        let k = SigningKey::from(self.nonce(msg_digest, aux_rand, None)?);
 
         let secret_key = k.secret_key;
         let verifying_point = AffinePoint::from(k.verifying_key);
         let r = verifying_point.x.normalize();
 
-        let e = <Scalar as Reduce<U256>>::reduce_bytes(
-            &tagged_hash(CHALLENGE_TAG)
-                .chain_update(r.to_bytes())
-                .chain_update(self.verifying_key.to_bytes())
-                .chain_update(msg_digest)
-                .finalize(),
-        );
+        let e = challenge(&r, &self.verifying_key, msg_digest);
 
         let s = *secret_key + e * *self.secret_key;
         let s = Option::from(NonZeroScalar::new(s)).ok_or_else(Error::new)?;
@@ -118,6 +132,92 @@ impl SigningKey {
 
         Ok(sig)
     }
+
+    /// Compute an adaptor signature (a.k.a. pre-signature) for the given
+    /// adaptor point `T`, which becomes a valid signature once adapted with
+    /// the discrete logarithm of `T` using [`AdaptorSignature::adapt`].
+    ///
+    /// The message is hashed with SHA-256, as with the [`Signer`] impl.
+    pub fn pre_sign(&self, msg: &[u8], adaptor_point: &PublicKey) -> Result<AdaptorSignature> {
+        self.pre_sign_prehash_with_aux_rand(
+            &Sha256::digest(msg).into(),
+            adaptor_point,
+            &Default::default(),
+        )
+    }
+
+    /// Compute an adaptor signature for a pre-hashed message.
+    ///
+    /// The nonce is derived as in [`SigningKey::sign_prehash_with_aux_rand`],
+    /// with the adaptor point additionally committed to, so that nonces are
+    /// never shared with regular signatures over the same message.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This is a low-level interface intended only for unusual use cases
+    /// involving signing pre-hashed messages.
+    ///
+    /// The preferred interface is [`SigningKey::pre_sign`].
+    pub fn pre_sign_prehash_with_aux_rand(
+        &self,
+        msg_digest: &[u8; 32],
+        adaptor_point: &PublicKey,
+        aux_rand: &[u8; 32],
+    ) -> Result<AdaptorSignature> {
+        let k = self.nonce(msg_digest, aux_rand, Some(adaptor_point))?;
+
+        let r =
+            (ProjectivePoint::mul_by_generator(&*k) + adaptor_point.to_projective()).to_affine();
+        let r = PublicKey::from_affine(r).map_err(|_| Error::new())?;
+
+        let odd = r.as_affine().y.normalize().is_odd();
+        let k = Scalar::conditional_select(&k, &-*k, odd);
+
+        let e = challenge(
+            &r.as_affine().x.normalize(),
+            &self.verifying_key,
+            msg_digest,
+        );
+
+        let pre_sig = AdaptorSignature {
+            r,
+            s: k + e * *self.secret_key,
+        };
+
+        #[cfg(debug_assertions)]
+        self.verifying_key
+            .pre_verify_prehash(msg_digest, adaptor_point, &pre_sig)?;
+
+        Ok(pre_sig)
+    }
+
+    /// Derive the nonce for signing `msg_digest`, committing to the adaptor
+    /// point if there is one.
+    fn nonce(
+        &self,
+        msg_digest: &[u8; 32],
+        aux_rand: &[u8; 32],
+        adaptor_point: Option<&PublicKey>,
+    ) -> Result<NonZeroScalar> {
+        let mut t = tagged_hash(AUX_TAG).chain_update(aux_rand).finalize();
+
+        for (a, b) in t.iter_mut().zip(self.secret_key.to_bytes().iter()) {
+            *a ^= b
+        }
+
+        let mut hash = tagged_hash(NONCE_TAG).chain_update(t);
+
+        if let Some(adaptor_point) = adaptor_point {
+            hash.update(adaptor_point.as_affine().to_bytes());
+        }
+
+        let rand = hash
+            .chain_update(self.verifying_key.as_affine().x.to_bytes())
+            .chain_update(msg_digest)
+            .finalize();
+
+        NonZeroScalar::try_from(&*rand).map_err(|_| Error::new())
+    }
 }
 
 impl From<NonZeroScalar> for SigningKey {
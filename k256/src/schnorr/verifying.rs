@@ -1,12 +1,13 @@
 //! Taproot Schnorr verifying key.
 
-use super::{tagged_hash, Signature, CHALLENGE_TAG};
+use super::{challenge, tagged_hash, AdaptorSignature, Signature, TAPTWEAK_TAG};
 use crate::{AffinePoint, FieldBytes, ProjectivePoint, PublicKey, Scalar};
 use elliptic_curve::{
-    bigint::U256,
     group::prime::PrimeCurveAffine,
-    ops::{LinearCombination, Reduce},
+    ops::{LinearCombination, MulByGenerator},
     point::DecompactPoint,
+    subtle::ConditionallySelectable,
+    PrimeField,
 };
 use sha2::{
     digest::{consts::U32, FixedOutput},
@@ -43,6 +44,89 @@ impl VerifyingKey {
             .map_err(|_| Error::new())?
             .try_into()
     }
+
+    /// Compute the tweaked key `Q = P + t*G`, where `P` is this key.
+    ///
+    /// Returns the x-only key for `Q` along with whether the y-coordinate of
+    /// `Q` is odd, which is needed e.g. for Taproot control blocks.
+    pub fn tweak_add(&self, tweak: &Scalar) -> Result<(Self, bool)> {
+        let q = (ProjectivePoint::mul_by_generator(tweak) + self.inner.to_projective()).to_affine();
+
+        if q.is_identity().into() {
+            return Err(Error::new());
+        }
+
+        let odd = q.y.normalize().is_odd();
+        let inner = PublicKey::from_affine(AffinePoint::conditional_select(&q, &-q, odd))
+            .map_err(|_| Error::new())?;
+
+        Ok((Self { inner }, odd.into()))
+    }
+
+    /// Compute the Taproot output key for this internal key as described in
+    /// [BIP341], committing to the script tree with the given Merkle root,
+    /// or to no scripts at all if it's `None`.
+    ///
+    /// Returns the output key along with the parity of its y-coordinate.
+    ///
+    /// [BIP341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+    pub fn taproot_output_key(&self, merkle_root: Option<&[u8; 32]>) -> Result<(Self, bool)> {
+        self.tweak_add(&self.taproot_tweak(merkle_root)?)
+    }
+
+    /// Compute the tweak `t = hash_TapTweak(P || merkle_root)` for this key.
+    pub(super) fn taproot_tweak(&self, merkle_root: Option<&[u8; 32]>) -> Result<Scalar> {
+        let mut hash = tagged_hash(TAPTWEAK_TAG).chain_update(self.to_bytes());
+
+        if let Some(merkle_root) = merkle_root {
+            hash.update(merkle_root);
+        }
+
+        Option::from(Scalar::from_repr(hash.finalize())).ok_or_else(Error::new)
+    }
+
+    /// Verify an adaptor signature for the given adaptor point `T`, i.e.
+    /// check that it becomes a valid signature of `msg` once adapted with the
+    /// discrete logarithm of `T`.
+    ///
+    /// The message is hashed with SHA-256, as with the [`Verifier`] impl.
+    pub fn pre_verify(
+        &self,
+        msg: &[u8],
+        adaptor_point: &PublicKey,
+        pre_sig: &AdaptorSignature,
+    ) -> Result<()> {
+        self.pre_verify_prehash(&Sha256::digest(msg), adaptor_point, pre_sig)
+    }
+
+    /// Verify an adaptor signature for a pre-hashed message.
+    pub fn pre_verify_prehash(
+        &self,
+        prehash: &[u8],
+        adaptor_point: &PublicKey,
+        pre_sig: &AdaptorSignature,
+    ) -> Result<()> {
+        let prehash: [u8; 32] = prehash.try_into().map_err(|_| Error::new())?;
+        let r = pre_sig.r();
+
+        let e = challenge(&r.x.normalize(), self, &prehash);
+
+        let R = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &pre_sig.s,
+            &self.inner.to_projective(),
+            &-e,
+        );
+
+        let expected = ProjectivePoint::from(*r) - adaptor_point.to_projective();
+        let expected = ProjectivePoint::conditional_select(&expected, &-expected, pre_sig.r_odd());
+
+        if R == expected {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
 }
 
 //
@@ -67,13 +151,7 @@ impl PrehashVerifier<Signature> for VerifyingKey {
         let prehash: [u8; 32] = prehash.try_into().map_err(|_| Error::new())?;
         let (r, s) = signature.split();
 
-        let e = <Scalar as Reduce<U256>>::reduce_bytes(
-            &tagged_hash(CHALLENGE_TAG)
-                .chain_update(signature.r.to_bytes())
-                .chain_update(self.to_bytes())
-                .chain_update(prehash)
-                .finalize(),
-        );
+        let e = challenge(r, self, &prehash);
 
         let R = ProjectivePoint::lincomb(
             &ProjectivePoint::GENERATOR,
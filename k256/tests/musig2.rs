@@ -0,0 +1,513 @@
+//! MuSig2 tests using the BIP327 test vectors.
+//!
+//! `det_sign_vectors.json` isn't included, since deterministic signing
+//! (`DeterministicSign`) isn't implemented.
+//!
+//! <https://github.com/bitcoin/bips/tree/master/bip-0327/vectors>
+
+#![cfg(feature = "musig2")]
+
+use k256::{
+    elliptic_curve::PrimeField,
+    musig2::{
+        key_sort, nonce_gen, AggregatedNonce, KeyAggContext, PartialSignature, PublicNonce,
+        SecretNonce, SessionContext,
+    },
+    schnorr::signature::hazmat::PrehashVerifier,
+    PublicKey, Scalar, SecretKey,
+};
+use rand_core::OsRng;
+use serde_json::Value;
+
+const KEY_AGG_VECTORS: &str = include_str!("data/musig2/key_agg_vectors.json");
+
+/// Decode a hex string.
+fn hex(s: &str) -> Vec<u8> {
+    assert!(s.len() % 2 == 0);
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Get the elements of a JSON array of hex strings.
+fn hex_array(value: &Value) -> Vec<Vec<u8>> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| hex(s.as_str().unwrap()))
+        .collect()
+}
+
+/// Get the elements of a JSON array of indices.
+fn indices(value: &Value) -> Vec<usize> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| i.as_u64().unwrap() as usize)
+        .collect()
+}
+
+/// Parse the public keys with the given indices, returning the position of
+/// the first invalid one on failure.
+fn public_keys(pubkeys: &[Vec<u8>], key_indices: &[usize]) -> Result<Vec<PublicKey>, usize> {
+    key_indices
+        .iter()
+        .enumerate()
+        .map(|(signer, &i)| PublicKey::from_sec1_bytes(&pubkeys[i]).map_err(|_| signer))
+        .collect()
+}
+
+/// Parse a tweak.
+fn tweak(bytes: &[u8]) -> Option<Scalar> {
+    Option::from(Scalar::from_repr(*k256::FieldBytes::from_slice(bytes)))
+}
+
+/// Apply the tweaks with the given indices, returning `None` if any of them
+/// is invalid.
+fn apply_tweaks(
+    ctx: KeyAggContext,
+    tweaks: &[Vec<u8>],
+    tweak_indices: &[usize],
+    is_xonly: &Value,
+) -> Option<KeyAggContext> {
+    let is_xonly = is_xonly.as_array().unwrap();
+    assert_eq!(tweak_indices.len(), is_xonly.len());
+
+    tweak_indices
+        .iter()
+        .zip(is_xonly)
+        .try_fold(ctx, |ctx, (&i, is_xonly)| {
+            ctx.with_tweak(&tweak(&tweaks[i])?, is_xonly.as_bool().unwrap())
+                .ok()
+        })
+}
+
+const KEY_SORT_VECTORS: &str = include_str!("data/musig2/key_sort_vectors.json");
+
+#[test]
+fn key_sort_vectors() {
+    let vectors: Value = serde_json::from_str(KEY_SORT_VECTORS).unwrap();
+    let mut public_keys = public_keys(
+        &hex_array(&vectors["pubkeys"]),
+        &(0..vectors["pubkeys"].as_array().unwrap().len()).collect::<Vec<_>>(),
+    )
+    .unwrap();
+
+    key_sort(&mut public_keys);
+
+    let sorted = public_keys
+        .iter()
+        .map(|public_key| public_key.to_sec1_bytes().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(sorted, hex_array(&vectors["sorted_pubkeys"]));
+}
+
+#[test]
+fn key_agg_vectors() {
+    let vectors: Value = serde_json::from_str(KEY_AGG_VECTORS).unwrap();
+    let pubkeys = hex_array(&vectors["pubkeys"]);
+    let tweaks = hex_array(&vectors["tweaks"]);
+
+    for case in vectors["valid_test_cases"].as_array().unwrap() {
+        let public_keys = public_keys(&pubkeys, &indices(&case["key_indices"])).unwrap();
+        let ctx = KeyAggContext::new(&public_keys).unwrap();
+        assert_eq!(
+            ctx.aggregated_key().to_bytes().as_slice(),
+            hex(case["expected"].as_str().unwrap())
+        );
+    }
+
+    for case in vectors["error_test_cases"].as_array().unwrap() {
+        let error = &case["error"];
+        let result = public_keys(&pubkeys, &indices(&case["key_indices"]));
+
+        if error["type"] == "invalid_contribution" {
+            assert_eq!(result.unwrap_err() as u64, error["signer"]);
+            continue;
+        }
+
+        let ctx = KeyAggContext::new(&result.unwrap()).unwrap();
+        let ctx = apply_tweaks(
+            ctx,
+            &tweaks,
+            &indices(&case["tweak_indices"]),
+            &case["is_xonly"],
+        );
+        assert!(ctx.is_none(), "{}", case["comment"]);
+    }
+}
+
+const NONCE_GEN_VECTORS: &str = include_str!("data/musig2/nonce_gen_vectors.json");
+
+/// Get an optional hex string.
+fn optional_hex(value: &Value) -> Option<Vec<u8>> {
+    value.as_str().map(hex)
+}
+
+#[test]
+fn nonce_gen_vectors() {
+    let vectors: Value = serde_json::from_str(NONCE_GEN_VECTORS).unwrap();
+
+    for case in vectors["test_cases"].as_array().unwrap() {
+        let rand = hex(case["rand_"].as_str().unwrap());
+        let secret_key = optional_hex(&case["sk"]).map(|sk| SecretKey::from_slice(&sk).unwrap());
+        let public_key = PublicKey::from_sec1_bytes(&hex(case["pk"].as_str().unwrap())).unwrap();
+        let aggregated_key = optional_hex(&case["aggpk"])
+            .map(|aggpk| k256::schnorr::VerifyingKey::from_bytes(&aggpk).unwrap());
+        let msg = optional_hex(&case["msg"]);
+        let extra_in = optional_hex(&case["extra_in"]);
+
+        let (secnonce, pubnonce) = k256::musig2::nonce_gen_with_rand(
+            rand.as_slice().try_into().unwrap(),
+            secret_key.as_ref(),
+            &public_key,
+            aggregated_key.as_ref(),
+            msg.as_deref(),
+            extra_in.as_deref(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            secnonce.to_bytes().as_slice(),
+            hex(case["expected_secnonce"].as_str().unwrap())
+        );
+        assert_eq!(secnonce.public_nonce(), pubnonce);
+        assert_eq!(
+            pubnonce.to_bytes().as_slice(),
+            hex(case["expected_pubnonce"].as_str().unwrap())
+        );
+    }
+}
+
+const NONCE_AGG_VECTORS: &str = include_str!("data/musig2/nonce_agg_vectors.json");
+
+/// Parse the public nonces with the given indices, returning the position of
+/// the first invalid one on failure.
+fn public_nonces(pnonces: &[Vec<u8>], nonce_indices: &[usize]) -> Result<Vec<PublicNonce>, usize> {
+    nonce_indices
+        .iter()
+        .enumerate()
+        .map(|(signer, &i)| PublicNonce::from_bytes(&pnonces[i]).map_err(|_| signer))
+        .collect()
+}
+
+#[test]
+fn nonce_agg_vectors() {
+    let vectors: Value = serde_json::from_str(NONCE_AGG_VECTORS).unwrap();
+    let pnonces = hex_array(&vectors["pnonces"]);
+
+    for case in vectors["valid_test_cases"].as_array().unwrap() {
+        let pubnonces = public_nonces(&pnonces, &indices(&case["pnonce_indices"])).unwrap();
+        let aggnonce = AggregatedNonce::new(&pubnonces);
+        let expected = hex(case["expected"].as_str().unwrap());
+        assert_eq!(aggnonce.to_bytes().as_slice(), expected);
+        assert_eq!(AggregatedNonce::from_bytes(&expected).unwrap(), aggnonce);
+    }
+
+    for case in vectors["error_test_cases"].as_array().unwrap() {
+        let result = public_nonces(&pnonces, &indices(&case["pnonce_indices"]));
+        assert_eq!(result.unwrap_err() as u64, case["error"]["signer"]);
+    }
+}
+
+const SIGN_VERIFY_VECTORS: &str = include_str!("data/musig2/sign_verify_vectors.json");
+
+/// Get a JSON index.
+fn index(value: &Value) -> usize {
+    value.as_u64().unwrap() as usize
+}
+
+#[test]
+fn sign_verify_vectors() {
+    let vectors: Value = serde_json::from_str(SIGN_VERIFY_VECTORS).unwrap();
+    let secret_key = SecretKey::from_slice(&hex(vectors["sk"].as_str().unwrap())).unwrap();
+    let pubkeys = hex_array(&vectors["pubkeys"]);
+    let secnonces = hex_array(&vectors["secnonces"]);
+    let pnonces = hex_array(&vectors["pnonces"]);
+    let aggnonces = hex_array(&vectors["aggnonces"]);
+    let msgs = hex_array(&vectors["msgs"]);
+
+    for case in vectors["valid_test_cases"].as_array().unwrap() {
+        let public_keys = public_keys(&pubkeys, &indices(&case["key_indices"])).unwrap();
+        let key_agg_ctx = KeyAggContext::new(&public_keys).unwrap();
+        let pubnonces = indices(&case["nonce_indices"])
+            .iter()
+            .map(|&i| PublicNonce::from_bytes(&pnonces[i]).unwrap())
+            .collect::<Vec<_>>();
+        let aggnonce =
+            AggregatedNonce::from_bytes(&aggnonces[index(&case["aggnonce_index"])]).unwrap();
+        assert_eq!(AggregatedNonce::new(&pubnonces), aggnonce);
+
+        let msg = &msgs[index(&case["msg_index"])];
+        let signer = index(&case["signer_index"]);
+        let session_ctx = SessionContext::new(&key_agg_ctx, &aggnonce, msg);
+
+        let secnonce = SecretNonce::from_bytes(&secnonces[0]).unwrap();
+        let psig = session_ctx.sign(secnonce, &secret_key).unwrap();
+        assert_eq!(
+            psig.to_bytes().as_slice(),
+            hex(case["expected"].as_str().unwrap())
+        );
+
+        session_ctx
+            .verify_partial(&psig, &pubnonces[signer], &public_keys[signer])
+            .unwrap();
+
+        // The partial signature must not verify for any other signer
+        for (i, public_key) in public_keys.iter().enumerate() {
+            if public_key != &public_keys[signer] {
+                assert!(session_ctx
+                    .verify_partial(&psig, &pubnonces[i], public_key)
+                    .is_err());
+            }
+        }
+
+        // Nor must its negation
+        let negated = PartialSignature::from_bytes(
+            &(-Scalar::from_repr(psig.to_bytes()).unwrap()).to_bytes(),
+        )
+        .unwrap();
+        assert!(session_ctx
+            .verify_partial(&negated, &pubnonces[signer], &public_keys[signer])
+            .is_err());
+    }
+
+    for case in vectors["sign_error_test_cases"].as_array().unwrap() {
+        let error = &case["error"];
+        let public_keys = public_keys(&pubkeys, &indices(&case["key_indices"]));
+
+        if error["contrib"] == "pubkey" {
+            assert_eq!(public_keys.unwrap_err() as u64, error["signer"]);
+            continue;
+        }
+
+        let key_agg_ctx = KeyAggContext::new(&public_keys.unwrap()).unwrap();
+        let aggnonce = AggregatedNonce::from_bytes(&aggnonces[index(&case["aggnonce_index"])]);
+
+        if error["contrib"] == "aggnonce" {
+            assert!(aggnonce.is_err(), "{}", case["comment"]);
+            continue;
+        }
+
+        let secnonce = SecretNonce::from_bytes(&secnonces[index(&case["secnonce_index"])]);
+        let result = secnonce.and_then(|secnonce| {
+            let msg = &msgs[index(&case["msg_index"])];
+            SessionContext::new(&key_agg_ctx, &aggnonce.unwrap(), msg).sign(secnonce, &secret_key)
+        });
+        assert!(result.is_err(), "{}", case["comment"]);
+    }
+
+    for case in vectors["verify_fail_test_cases"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .chain(vectors["verify_error_test_cases"].as_array().unwrap())
+    {
+        let error = &case["error"];
+        let public_keys = public_keys(&pubkeys, &indices(&case["key_indices"]));
+        let pubnonces = public_nonces(&pnonces, &indices(&case["nonce_indices"]));
+
+        match error["contrib"].as_str() {
+            Some("pubkey") => {
+                assert_eq!(public_keys.unwrap_err() as u64, error["signer"]);
+                continue;
+            }
+            Some("pubnonce") => {
+                assert_eq!(pubnonces.unwrap_err() as u64, error["signer"]);
+                continue;
+            }
+            _ => (),
+        }
+
+        let (public_keys, pubnonces) = (public_keys.unwrap(), pubnonces.unwrap());
+        let key_agg_ctx = KeyAggContext::new(&public_keys).unwrap();
+        let aggnonce = AggregatedNonce::new(&pubnonces);
+        let msg = &msgs[index(&case["msg_index"])];
+        let signer = index(&case["signer_index"]);
+        let session_ctx = SessionContext::new(&key_agg_ctx, &aggnonce, msg);
+
+        let result =
+            PartialSignature::from_bytes(&hex(case["sig"].as_str().unwrap())).and_then(|psig| {
+                session_ctx.verify_partial(&psig, &pubnonces[signer], &public_keys[signer])
+            });
+        assert!(result.is_err(), "{}", case["comment"]);
+    }
+}
+
+const TWEAK_VECTORS: &str = include_str!("data/musig2/tweak_vectors.json");
+
+#[test]
+fn tweak_vectors() {
+    let vectors: Value = serde_json::from_str(TWEAK_VECTORS).unwrap();
+    let secret_key = SecretKey::from_slice(&hex(vectors["sk"].as_str().unwrap())).unwrap();
+    let secnonce = hex(vectors["secnonce"].as_str().unwrap());
+    let aggnonce =
+        AggregatedNonce::from_bytes(&hex(vectors["aggnonce"].as_str().unwrap())).unwrap();
+    let msg = hex(vectors["msg"].as_str().unwrap());
+    let pubkeys = hex_array(&vectors["pubkeys"]);
+    let pnonces = hex_array(&vectors["pnonces"]);
+    let tweaks = hex_array(&vectors["tweaks"]);
+
+    for case in vectors["valid_test_cases"].as_array().unwrap() {
+        let public_keys = public_keys(&pubkeys, &indices(&case["key_indices"])).unwrap();
+        let pubnonces = public_nonces(&pnonces, &indices(&case["nonce_indices"])).unwrap();
+        assert_eq!(AggregatedNonce::new(&pubnonces), aggnonce);
+
+        let key_agg_ctx = apply_tweaks(
+            KeyAggContext::new(&public_keys).unwrap(),
+            &tweaks,
+            &indices(&case["tweak_indices"]),
+            &case["is_xonly"],
+        )
+        .unwrap();
+
+        let session_ctx = SessionContext::new(&key_agg_ctx, &aggnonce, &msg);
+        let psig = session_ctx
+            .sign(SecretNonce::from_bytes(&secnonce).unwrap(), &secret_key)
+            .unwrap();
+        assert_eq!(
+            psig.to_bytes().as_slice(),
+            hex(case["expected"].as_str().unwrap()),
+            "{}",
+            case["comment"]
+        );
+
+        let signer = index(&case["signer_index"]);
+        session_ctx
+            .verify_partial(&psig, &pubnonces[signer], &public_keys[signer])
+            .unwrap();
+    }
+
+    for case in vectors["error_test_cases"].as_array().unwrap() {
+        let public_keys = public_keys(&pubkeys, &indices(&case["key_indices"])).unwrap();
+        let key_agg_ctx = apply_tweaks(
+            KeyAggContext::new(&public_keys).unwrap(),
+            &tweaks,
+            &indices(&case["tweak_indices"]),
+            &case["is_xonly"],
+        );
+        assert!(key_agg_ctx.is_none(), "{}", case["comment"]);
+    }
+}
+
+const SIG_AGG_VECTORS: &str = include_str!("data/musig2/sig_agg_vectors.json");
+
+/// Parse the partial signatures with the given indices, returning the
+/// position of the first invalid one on failure.
+fn partial_signatures(
+    psigs: &[Vec<u8>],
+    psig_indices: &[usize],
+) -> Result<Vec<PartialSignature>, usize> {
+    psig_indices
+        .iter()
+        .enumerate()
+        .map(|(signer, &i)| PartialSignature::from_bytes(&psigs[i]).map_err(|_| signer))
+        .collect()
+}
+
+#[test]
+fn sig_agg_vectors() {
+    let vectors: Value = serde_json::from_str(SIG_AGG_VECTORS).unwrap();
+    let pubkeys = hex_array(&vectors["pubkeys"]);
+    let pnonces = hex_array(&vectors["pnonces"]);
+    let tweaks = hex_array(&vectors["tweaks"]);
+    let psigs = hex_array(&vectors["psigs"]);
+    let msg = hex(vectors["msg"].as_str().unwrap());
+
+    for case in vectors["valid_test_cases"].as_array().unwrap() {
+        let public_keys = public_keys(&pubkeys, &indices(&case["key_indices"])).unwrap();
+        let pubnonces = public_nonces(&pnonces, &indices(&case["nonce_indices"])).unwrap();
+        let aggnonce =
+            AggregatedNonce::from_bytes(&hex(case["aggnonce"].as_str().unwrap())).unwrap();
+        assert_eq!(AggregatedNonce::new(&pubnonces), aggnonce);
+
+        let key_agg_ctx = apply_tweaks(
+            KeyAggContext::new(&public_keys).unwrap(),
+            &tweaks,
+            &indices(&case["tweak_indices"]),
+            &case["is_xonly"],
+        )
+        .unwrap();
+
+        let session_ctx = SessionContext::new(&key_agg_ctx, &aggnonce, &msg);
+        let psigs = partial_signatures(&psigs, &indices(&case["psig_indices"])).unwrap();
+        let signature = session_ctx.aggregate(&psigs).unwrap();
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            hex(case["expected"].as_str().unwrap())
+        );
+
+        key_agg_ctx
+            .aggregated_key()
+            .verify_prehash(&msg, &signature)
+            .unwrap();
+    }
+
+    for case in vectors["error_test_cases"].as_array().unwrap() {
+        let result = partial_signatures(&psigs, &indices(&case["psig_indices"]));
+        assert_eq!(result.unwrap_err() as u64, case["error"]["signer"]);
+    }
+}
+
+#[test]
+fn full_session() {
+    let secret_keys = [
+        SecretKey::random(&mut OsRng),
+        SecretKey::random(&mut OsRng),
+        SecretKey::random(&mut OsRng),
+    ];
+    let public_keys = secret_keys.clone().map(|sk| sk.public_key());
+    let tweak = Scalar::from(42u64);
+    let key_agg_ctx = KeyAggContext::new(&public_keys)
+        .unwrap()
+        .with_tweak(&tweak, true)
+        .unwrap();
+    let msg = &[0x42; 32];
+
+    let (secnonces, pubnonces): (Vec<_>, Vec<_>) = public_keys
+        .iter()
+        .zip(&secret_keys)
+        .map(|(public_key, secret_key)| {
+            nonce_gen(
+                &mut OsRng,
+                Some(secret_key),
+                public_key,
+                Some(key_agg_ctx.aggregated_key()),
+                Some(msg),
+                None,
+            )
+            .unwrap()
+        })
+        .unzip();
+
+    let aggnonce = AggregatedNonce::new(&pubnonces);
+    let session_ctx = SessionContext::new(&key_agg_ctx, &aggnonce, msg);
+
+    let psigs = secnonces
+        .into_iter()
+        .zip(&secret_keys)
+        .map(|(secnonce, secret_key)| session_ctx.sign(secnonce, secret_key).unwrap())
+        .collect::<Vec<_>>();
+
+    for ((psig, pubnonce), public_key) in psigs.iter().zip(&pubnonces).zip(&public_keys) {
+        session_ctx
+            .verify_partial(psig, pubnonce, public_key)
+            .unwrap();
+    }
+
+    let signature = session_ctx.aggregate(&psigs).unwrap();
+    key_agg_ctx
+        .aggregated_key()
+        .verify_prehash(msg, &signature)
+        .unwrap();
+
+    // Missing partial signatures result in an invalid signature
+    let signature = session_ctx.aggregate(&psigs[1..]).unwrap();
+    assert!(key_agg_ctx
+        .aggregated_key()
+        .verify_prehash(msg, &signature)
+        .is_err());
+}
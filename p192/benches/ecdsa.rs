@@ -0,0 +1,36 @@
+//! secp192r1 ECDSA benchmarks
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use hex_literal::hex;
+use p192::ecdsa::{
+    signature::hazmat::{PrehashSigner, PrehashVerifier},
+    Signature, SigningKey,
+};
+use sha2::{Digest, Sha256};
+
+fn test_signing_key() -> SigningKey {
+    SigningKey::from_slice(&hex!("6fab034934e4c0fc9ae67f5b5659a9d7d1fefd187ee09fd4")).unwrap()
+}
+
+fn bench_ecdsa(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ecdsa");
+
+    let signing_key = test_signing_key();
+    let verifying_key = signing_key.verifying_key();
+    let prehash = Sha256::digest(b"sample");
+
+    group.bench_function("sign_prehash", |b| {
+        b.iter(|| -> Signature { signing_key.sign_prehash(&prehash).unwrap() })
+    });
+
+    let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+
+    group.bench_function("verify_prehash", |b| {
+        b.iter(|| verifying_key.verify_prehash(&prehash, &signature).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdsa);
+criterion_main!(benches);
@@ -4,7 +4,15 @@
 //!
 //! [FIPS 186-4]: https://csrc.nist.gov/publications/detail/fips/186/4/final
 
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "critical-section", feature = "std"))
+))]
+compile_error!("`precomputed-tables` feature requires either `critical-section` or `std`");
+
 pub(crate) mod field;
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
 pub(crate) mod scalar;
 
 use self::{field::FieldElement, scalar::Scalar};
@@ -12,6 +20,9 @@ use crate::NistP192;
 use elliptic_curve::{CurveArithmetic, PrimeCurveArithmetic};
 use primeorder::{point_arithmetic, PrimeCurveParams};
 
+#[cfg(feature = "precomputed-tables")]
+use once_cell::sync::Lazy;
+
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = primeorder::AffinePoint<NistP192>;
 
@@ -52,4 +63,15 @@ impl PrimeCurveParams for NistP192 {
         FieldElement::from_hex("188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012"),
         FieldElement::from_hex("07192b95ffc8da78631011ed6b24cdd573f977a11e794811"),
     );
+
+    /// Calculates `k * G` using a precomputed basepoint table.
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        BASEPOINT_TABLE.mul(k)
+    }
 }
+
+/// Lazily computed basepoint table.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: Lazy<primeorder::BasepointTable<NistP192, 25>> =
+    Lazy::new(primeorder::BasepointTable::new);
@@ -2,6 +2,10 @@
 //!
 //! Arithmetic implementations have been synthesized using fiat-crypto.
 //!
+//! On 64-bit targets, the `solinas` feature replaces fiat-crypto's Montgomery
+//! multiplication with a hand-written backend which exploits the special form
+//! of the modulus. The formally verified fiat-crypto backend is the default.
+//!
 //! # License
 //!
 //! Copyright (c) 2015-2020 the fiat-crypto authors
@@ -23,6 +27,8 @@
 #[cfg_attr(target_pointer_width = "32", path = "field/p192_32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "field/p192_64.rs")]
 mod field_impl;
+#[cfg(all(any(feature = "solinas", test), target_pointer_width = "64"))]
+mod solinas;
 
 use self::field_impl::*;
 use crate::{FieldBytes, NistP192, U192};
@@ -37,6 +43,19 @@ use elliptic_curve::{
     subtle::{Choice, ConstantTimeEq, CtOption},
 };
 
+#[cfg(not(all(feature = "solinas", target_pointer_width = "64")))]
+use self::field_impl::{
+    fiat_p192_divstep_precomp as p192_divstep_precomp,
+    fiat_p192_from_montgomery as p192_from_montgomery, fiat_p192_mul as p192_mul,
+    fiat_p192_square as p192_square, fiat_p192_to_montgomery as p192_to_montgomery,
+};
+#[cfg(all(feature = "solinas", target_pointer_width = "64"))]
+use self::solinas::{
+    solinas_p192_divstep_precomp as p192_divstep_precomp,
+    solinas_p192_from_montgomery as p192_from_montgomery, solinas_p192_mul as p192_mul,
+    solinas_p192_square as p192_square, solinas_p192_to_montgomery as p192_to_montgomery,
+};
+
 /// Constant representing the modulus serialized as hex.
 /// p = 2^{192} − 2^{64} - 1
 const MODULUS_HEX: &str = "fffffffffffffffffffffffffffffffeffffffffffffffff";
@@ -54,13 +73,13 @@ primeorder::impl_mont_field_element!(
     U192,
     MODULUS,
     fiat_p192_montgomery_domain_field_element,
-    fiat_p192_from_montgomery,
-    fiat_p192_to_montgomery,
+    p192_from_montgomery,
+    p192_to_montgomery,
     fiat_p192_add,
     fiat_p192_sub,
-    fiat_p192_mul,
+    p192_mul,
     fiat_p192_opp,
-    fiat_p192_square
+    p192_square
 );
 
 impl Debug for FieldElement {
@@ -85,10 +104,10 @@ impl FieldElement {
             192,
             U192::LIMBS,
             Limb,
-            fiat_p192_from_montgomery,
-            fiat_p192_mul,
+            p192_from_montgomery,
+            p192_mul,
             fiat_p192_opp,
-            fiat_p192_divstep_precomp,
+            p192_divstep_precomp,
             fiat_p192_divstep,
             fiat_p192_msat,
             fiat_p192_selectznz,
@@ -0,0 +1,225 @@
+//! Field arithmetic modulo p = 2^{192} − 2^{64} - 1 using Solinas reduction.
+//!
+//! Unlike the fiat-crypto backend, field elements are stored in canonical
+//! (non-Montgomery) form as little endian 64-bit limbs. Products are computed
+//! using schoolbook multiplication and then reduced using the special form of
+//! the modulus:
+//!
+//! ```text
+//! 2^192 ≡ 2^64 + 1 (mod p)
+//! ```
+//!
+//! Addition, subtraction, negation, and the Bernstein-Yang `divstep` routines
+//! are independent of the representation, so the fiat-crypto implementations
+//! of those are reused as-is.
+
+use super::field_impl::{fiat_p192_divstep_precomp, fiat_p192_from_montgomery};
+
+/// Number of 64-bit limbs in a field element.
+const LIMBS: usize = 3;
+
+/// Field element as little endian 64-bit limbs.
+type Limbs = [u64; LIMBS];
+
+/// p = 2^{192} − 2^{64} - 1
+const MODULUS: Limbs = [0xffffffffffffffff, 0xfffffffffffffffe, 0xffffffffffffffff];
+
+/// Bernstein-Yang inversion precomputation.
+///
+/// fiat-crypto's precomputed value is in the Montgomery domain, which is
+/// translated out of it here since the result of `divstep` is multiplied by
+/// this value using canonical multiplication.
+const DIVSTEP_PRECOMP: Limbs = fiat_p192_from_montgomery(&fiat_p192_divstep_precomp());
+
+/// Translate a field element into the internal representation.
+///
+/// Elements are stored in canonical form, so this only reduces an input in the
+/// range `[p, 2^192)`.
+pub const fn solinas_p192_to_montgomery(a: &Limbs) -> Limbs {
+    sub_modulus_if_needed(a)
+}
+
+/// Translate a field element out of the internal representation.
+///
+/// Elements are stored in canonical form, so this is the identity map.
+pub const fn solinas_p192_from_montgomery(a: &Limbs) -> Limbs {
+    *a
+}
+
+/// Multiply two field elements.
+pub const fn solinas_p192_mul(a: &Limbs, b: &Limbs) -> Limbs {
+    let w = [0u64; 2 * LIMBS];
+    let w = mac_row(w, a, 0, b, 0);
+    let w = mac_row(w, a, 1, b, 0);
+    let w = mac_row(w, a, 2, b, 0);
+    reduce(&w)
+}
+
+/// Square a field element.
+pub const fn solinas_p192_square(a: &Limbs) -> Limbs {
+    // Off-diagonal products `a[i] * a[j]` where `i < j`
+    let w = [0u64; 2 * LIMBS];
+    let w = mac_row(w, a, 0, a, 1);
+    let mut w = mac_row(w, a, 1, a, 2);
+
+    // Double the off-diagonal products
+    let mut k = 2 * LIMBS - 1;
+    while k > 0 {
+        w[k] = (w[k] << 1) | (w[k - 1] >> 63);
+        k -= 1;
+    }
+    w[0] <<= 1;
+
+    // Add the diagonal products `a[i]^2`
+    let mut carry = 0;
+    let mut i = 0;
+    while i < LIMBS {
+        let sq = (a[i] as u128) * (a[i] as u128);
+        let t = (w[2 * i] as u128) + (sq as u64 as u128) + carry;
+        w[2 * i] = t as u64;
+        let t = (w[2 * i + 1] as u128) + (sq >> 64) + (t >> 64);
+        w[2 * i + 1] = t as u64;
+        carry = t >> 64;
+        i += 1;
+    }
+
+    reduce(&w)
+}
+
+/// Bernstein-Yang inversion precomputation for the canonical representation.
+pub const fn solinas_p192_divstep_precomp() -> Limbs {
+    DIVSTEP_PRECOMP
+}
+
+primeorder::impl_solinas_limb_arithmetic!(LIMBS, MODULUS);
+
+/// Reduce a 384-bit product modulo p.
+#[inline(always)]
+const fn reduce(w: &[u64; 2 * LIMBS]) -> Limbs {
+    let lo = [w[0], w[1], w[2]];
+    let hi = [w[3], w[4], w[5]];
+
+    // w < 2^384 => the result is less than 2^257
+    let (lo, hi) = fold(&lo, &hi);
+
+    // hi < 2^65 => the result is less than 2^192 + 2^130
+    let (lo, hi) = fold(&lo, &hi);
+
+    // hi ∈ {0, 1}, and if hi = 1 then lo < 2^130, so the result is less than
+    // 2^192 < 2p
+    let (lo, _) = fold(&lo, &[hi[0]]);
+    sub_modulus_if_needed(&lo)
+}
+
+/// Compute `lo + hi * (2^64 + 1)`, which is congruent to `lo + hi * 2^192`
+/// modulo p, returning the low 192 bits followed by the remaining high limbs.
+///
+/// `hi` may be narrower than a field element, in which case the computation
+/// of the missing limbs is optimized away.
+#[inline(always)]
+const fn fold<const N: usize>(lo: &Limbs, hi: &[u64; N]) -> (Limbs, [u64; 2]) {
+    let mut out_lo = [0u64; LIMBS];
+    let mut out_hi = [0u64; 2];
+    let mut carry: u128 = 0;
+    let mut k = 0;
+
+    while k < LIMBS + 2 {
+        // lo + hi
+        let mut acc = carry + (limb(lo, k) as u128) + (limb(hi, k) as u128);
+
+        // + hi * 2^64
+        if k >= 1 {
+            acc += limb(hi, k - 1) as u128;
+        }
+
+        if k < LIMBS {
+            out_lo[k] = acc as u64;
+        } else {
+            out_hi[k - LIMBS] = acc as u64;
+        }
+
+        carry = acc >> 64;
+        k += 1;
+    }
+
+    (out_lo, out_hi)
+}
+
+/// Get the `i`-th limb of `a`, or zero if out of range.
+#[inline(always)]
+const fn limb<const N: usize>(a: &[u64; N], i: usize) -> u64 {
+    if i < N {
+        a[i]
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::field_impl::*, solinas_p192_divstep_precomp, solinas_p192_from_montgomery,
+        solinas_p192_mul, solinas_p192_square, solinas_p192_to_montgomery, Limbs, LIMBS, MODULUS,
+    };
+    use primeorder::{impl_bernstein_yang_invert, impl_solinas_tests};
+    use proptest::{num::u64::ANY, prelude::*};
+
+    const ONE: Limbs = [1, 0, 0];
+
+    impl_solinas_tests!(
+        LIMBS,
+        MODULUS,
+        ONE,
+        field_element,
+        fiat_p192_to_montgomery,
+        fiat_p192_from_montgomery,
+        fiat_p192_mul,
+        fiat_p192_square,
+        fiat_p192_sub,
+        solinas_p192_mul,
+        solinas_p192_square,
+    );
+
+    fn invert(a: &Limbs) -> Limbs {
+        impl_bernstein_yang_invert!(
+            a,
+            solinas_p192_to_montgomery(&ONE),
+            192,
+            LIMBS,
+            u64,
+            solinas_p192_from_montgomery,
+            solinas_p192_mul,
+            fiat_p192_opp,
+            solinas_p192_divstep_precomp,
+            fiat_p192_divstep,
+            fiat_p192_msat,
+            fiat_p192_selectznz,
+        )
+    }
+
+    /// Limbs biased towards values which exercise carry propagation.
+    fn limb() -> impl Strategy<Value = u64> {
+        prop_oneof![Just(0), Just(u64::MAX), Just(u64::MAX - 1), Just(1), ANY,]
+    }
+
+    fn field_element() -> impl Strategy<Value = Limbs> {
+        prop::array::uniform3(limb()).prop_filter("unreduced", is_reduced)
+    }
+
+    #[test]
+    fn to_montgomery_reduces() {
+        assert_eq!(solinas_p192_to_montgomery(&MODULUS), [0; LIMBS]);
+        assert_eq!(solinas_p192_to_montgomery(&[u64::MAX; LIMBS]), [0, 1, 0]);
+    }
+
+    proptest! {
+        #[test]
+        fn invert_is_inverse(
+            a in field_element().prop_filter("zero", |a| *a != [0; LIMBS])
+        ) {
+            let a_inv = invert(&a);
+            assert_eq!(solinas_p192_mul(&a, &a_inv), ONE);
+            assert_eq!(fiat_mul(&a, &a_inv), ONE);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Hash-to-curve support for P-192 using the `P192_XMD:SHA-256_SSWU_RO_`
+//! suite, following [RFC 9380].
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use super::{FieldElement, Scalar};
+use crate::NistP192;
+use elliptic_curve::{
+    consts::U36,
+    hash2curve::{OsswuMap, OsswuMapParams},
+};
+use primeorder::PrimeCurveParams;
+
+primeorder::impl_hash2curve!(NistP192, FieldElement, Scalar, U36);
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        c1: &[
+            0xbfff_ffff_ffff_ffff,
+            0xffff_ffff_ffff_ffff,
+            0x3fff_ffff_ffff_ffff,
+        ],
+        c2: FieldElement::from_hex("3325658a84c31561fbaf05d27c4bc806936c08e0174fe9bf"),
+        map_a: NistP192::EQUATION_A,
+        map_b: NistP192::EQUATION_B,
+        z: FieldElement::from_u64(5).neg(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::NistP192;
+    use hex_literal::hex;
+    use sha2::Sha256;
+
+    primeorder::impl_hash2curve_tests!(
+        NistP192,
+        FieldElement,
+        Scalar,
+        Sha256,
+        b"QUUX-V01-CS02-with-P192_XMD:SHA-256_SSWU_RO_",
+        hex!("e9118b4afa4a852046c3e52757d2af8658f7ea8ae6e7cacc"),
+        [
+            {
+                msg: b"",
+                p_x: hex!("3ea6ff790dd25b2ad4841557e0962ee01b77e3994049c0a5"),
+                p_y: hex!("115be07fdb77863af3529e56c077adc340020c3a78e11084"),
+                u_0: hex!("2c535eec3b6b836fdd61f9a050c81df89e476f0dcf5def0c"),
+                u_1: hex!("97586a500c1b9025db98c6f7a1b1dcdc1458dd0949320dfa"),
+                q0_x: hex!("38d5bc9ae2d27eac78f3571ad593f6e9fc337320dede0461"),
+                q0_y: hex!("e6c3fd8ac910064887a21d249ea1126599910914563c4108"),
+                q1_x: hex!("6f99dfc66ac07ab90797c02b7324bf1f2ed5f18bc47a0c4c"),
+                q1_y: hex!("3c572c1743c7fffcd325c49a0f012d18c76191b598a416ae"),
+            },
+            {
+                msg: b"abc",
+                p_x: hex!("15cf29fdee5bc777a8ba5dace23c4584832447703b2f8207"),
+                p_y: hex!("6e19d2c272163eda6f2d50dd78960197ddfe8663b86feede"),
+                u_0: hex!("79920ea6b24aaffb60d338b6bba768addaeb25306d7f9fab"),
+                u_1: hex!("d30940aafde81c31262a4b928f7664b1abcbbb3226dba540"),
+                q0_x: hex!("c16e4d4bf447f19bcbada2c6518eda8c954e294a8157f291"),
+                q0_y: hex!("3db30cf48948b0107821a93ecbe8b6ec2e15f6292490b315"),
+                q1_x: hex!("83f5b9bf59f58af1f3ef69cc8683c1b50ed5ce03cd92754a"),
+                q1_y: hex!("202154f19ba8e8d9e1b634ff26d16a5fcc346328e4309b66"),
+            },
+            {
+                msg: b"abcdef0123456789",
+                p_x: hex!("a2d7e3e2708c0a7477cd0908ceafcce869748d4e88832efc"),
+                p_y: hex!("df919cd148e075ce319edd32299e77ebaceb23afaddd815d"),
+                u_0: hex!("7801d6df92e283040d3b8ebdee3f577bb04de98a20c91299"),
+                u_1: hex!("eaabd3c17e3349e59229e42805a0d2bbe13ca20b7f5de6d4"),
+                q0_x: hex!("1a28b9df8d277b212ad0a9210943c0c79b0b04192e91c721"),
+                q0_y: hex!("648b615737f5d91a9a515f924f058b0667bf15c1e838f3b1"),
+                q1_x: hex!("ba1de20847e8cfdc056abdc39aad719ba4c37a2890e95749"),
+                q1_y: hex!("d357ffcf2e1214e227cee45abca170f5f07e89f877d44606"),
+            },
+            {
+                msg: b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                p_x: hex!("b9b33f549e259e1fbbd50556e40e8770361dc026e2451104"),
+                p_y: hex!("cfa0d48d2cfd2350f010093cba135846491d324e23c054b0"),
+                u_0: hex!("87f781d961da2329d8cec665c4cdd480f117fefd10bf2b79"),
+                u_1: hex!("bb98c40b0d5c795a8a517160c7dd61d369d8c298d879cf17"),
+                q0_x: hex!("412bc609ce2947c9cf4f532839bc4934775770f84c7a0adc"),
+                q0_y: hex!("78495a1a3bf8df00f1bd0f5dc933c9c29a18ddd31e04882b"),
+                q1_x: hex!("f0d3e8a00cd840345f58761829d9104f552492143a6daaa2"),
+                q1_y: hex!("098c955f3c6015f713cb98de8cb109856119193fd5e753a9"),
+            },
+            {
+                msg: b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                p_x: hex!("c8299335e5fa22e535ca104886eb46c634ea32dbfdffcb31"),
+                p_y: hex!("d35c8d997bf711bc00ba5707eeaa5dc5694081aa39095654"),
+                u_0: hex!("29d275c3177f67392dc501b03de3dae7046aeb4afe7df6bb"),
+                u_1: hex!("4174995374b2ca7f7a06f0c620df9584287a9c615d09be72"),
+                q0_x: hex!("b14db2eca19eca9768513e72b20fd4314786a0bf1d8ef1e5"),
+                q0_y: hex!("1a0fbb67d8320c05caef206df19187cfecd41566904b9a71"),
+                q1_x: hex!("ffc25b9faee4e50d8a173c3f2858edb48dfbd1ead4c09925"),
+                q1_y: hex!("add32745be37e8969a03be1642049b335ea66375a7e27866"),
+            },
+        ]
+    );
+}
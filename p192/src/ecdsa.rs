@@ -16,7 +16,11 @@
 //!
 //! Following guidance from [NIST Special Publication 800-131A Revision 2]:
 //! "Transitioning the Use of Cryptographic Algorithms and Key Lengths", this
-//! crate only supports ECDSA verification, not signing.
+//! crate only supports ECDSA verification, not signing, by default.
+//!
+//! Signing is available for legacy interop and testing purposes only, via a
+//! `SigningKey` type gated under the off-by-default `hazmat-legacy` feature.
+//! Do not use it to generate signatures for any new application.
 //!
 //! From Section 3: Digital Signatures:
 //!
@@ -32,8 +36,26 @@
 //! [NIST Special Publication 800-131A Revision 2]: https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-131Ar2.pdf
 
 pub use ecdsa_core::signature::{self, Error};
+#[cfg(feature = "hazmat-legacy")]
+use {
+    crate::Scalar,
+    ecdsa_core::{
+        hazmat::{bits2field, SignPrimitive},
+        signature::{
+            digest::Digest,
+            hazmat::{PrehashSigner, RandomizedPrehashSigner},
+            rand_core::CryptoRngCore,
+            DigestSigner, Keypair, RandomizedDigestSigner, RandomizedSigner, Signer,
+        },
+    },
+    elliptic_curve::NonZeroScalar,
+    sha2::Sha256,
+};
 #[cfg(feature = "ecdsa")]
-use {crate::AffinePoint, ecdsa_core::hazmat::VerifyPrimitive};
+use {
+    crate::{AffinePoint, FieldBytes},
+    ecdsa_core::hazmat::VerifyPrimitive,
+};
 
 use super::NistP192;
 
@@ -47,11 +69,240 @@ pub type DerSignature = ecdsa_core::der::Signature<NistP192>;
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP192>;
 
+/// ECDSA/P-192 signing key.
+///
+/// # ⚠️ Legacy use only
+///
+/// P-192 provides roughly 96 bits of security, which is below the minimum
+/// permitted for generating digital signatures. This type exists solely so
+/// that test harnesses can produce signatures accepted by legacy devices, and
+/// is only available with the off-by-default `hazmat-legacy` feature.
+///
+/// Messages are hashed with SHA-256, with the digest truncated to the size of
+/// the group order as described in [RFC6979 § 2.3.2]. Ephemeral scalars are
+/// computed deterministically as described in [RFC6979 § 3.2].
+///
+/// [RFC6979 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.2
+/// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+#[cfg(feature = "hazmat-legacy")]
+#[derive(Clone, Debug)]
+pub struct SigningKey(ecdsa_core::SigningKey<NistP192>);
+
+#[cfg(feature = "hazmat-legacy")]
+impl SigningKey {
+    /// Generate a cryptographically random [`SigningKey`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self(ecdsa_core::SigningKey::random(rng))
+    }
+
+    /// Initialize signing key from a raw scalar serialized as a byte array.
+    pub fn from_bytes(bytes: &FieldBytes) -> Result<Self, Error> {
+        ecdsa_core::SigningKey::from_bytes(bytes).map(Self)
+    }
+
+    /// Initialize signing key from a raw scalar serialized as a byte slice.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        ecdsa_core::SigningKey::from_slice(bytes).map(Self)
+    }
+
+    /// Serialize this [`SigningKey`] as bytes.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+
+    /// Borrow the secret [`NonZeroScalar`] value for this key.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This value is key material.
+    ///
+    /// Please treat it with the care it deserves!
+    pub fn as_nonzero_scalar(&self) -> &NonZeroScalar<NistP192> {
+        self.0.as_nonzero_scalar()
+    }
+
+    /// Get the [`VerifyingKey`] which corresponds to this [`SigningKey`].
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        self.0.verifying_key()
+    }
+
+    /// Sign the given field-sized digest, using `ad` as additional data for
+    /// the RFC6979 ephemeral scalar derivation.
+    fn sign_field_bytes(&self, z: &FieldBytes, ad: &[u8]) -> Result<Signature, Error> {
+        let x = self.as_nonzero_scalar();
+        Ok(primeorder::ecdsa::sign_prehashed_rfc6979::<NistP192, Sha256>(x, z, ad)?.0)
+    }
+}
+
+/// Sign message prehash using a deterministic ephemeral scalar (`k`)
+/// computed using the algorithm described in [RFC6979 § 3.2].
+///
+/// [RFC6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3
+#[cfg(feature = "hazmat-legacy")]
+impl PrehashSigner<Signature> for SigningKey {
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature, Error> {
+        self.sign_field_bytes(&bits2field::<NistP192>(prehash)?, &[])
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl DigestSigner<Sha256, Signature> for SigningKey {
+    fn try_sign_digest(&self, msg_digest: Sha256) -> Result<Signature, Error> {
+        self.sign_prehash(&msg_digest.finalize())
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.try_sign_digest(Sha256::new_with_prefix(msg))
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl Signer<DerSignature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<DerSignature, Error> {
+        Signer::<Signature>::try_sign(self, msg).map(Into::into)
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl RandomizedPrehashSigner<Signature> for SigningKey {
+    fn sign_prehash_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature, Error> {
+        let z = bits2field::<NistP192>(prehash)?;
+        let mut ad = FieldBytes::default();
+        rng.fill_bytes(&mut ad);
+        self.sign_field_bytes(&z, &ad)
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl RandomizedDigestSigner<Sha256, Signature> for SigningKey {
+    fn try_sign_digest_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg_digest: Sha256,
+    ) -> Result<Signature, Error> {
+        self.sign_prehash_with_rng(rng, &msg_digest.finalize())
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, Error> {
+        self.try_sign_digest_with_rng(rng, Sha256::new_with_prefix(msg))
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl Keypair for SigningKey {
+    type VerifyingKey = VerifyingKey;
+
+    fn verifying_key(&self) -> VerifyingKey {
+        *SigningKey::verifying_key(self)
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl From<NonZeroScalar<NistP192>> for SigningKey {
+    fn from(secret_scalar: NonZeroScalar<NistP192>) -> Self {
+        Self(secret_scalar.into())
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl From<&SigningKey> for VerifyingKey {
+    fn from(signing_key: &SigningKey) -> VerifyingKey {
+        *signing_key.verifying_key()
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_slice(bytes)
+    }
+}
+
+#[cfg(feature = "hazmat-legacy")]
+impl SignPrimitive<NistP192> for Scalar {}
+
 #[cfg(feature = "ecdsa")]
-impl VerifyPrimitive<NistP192> for AffinePoint {}
+impl VerifyPrimitive<NistP192> for AffinePoint {
+    fn verify_prehashed(&self, z: &FieldBytes, sig: &Signature) -> Result<(), Error> {
+        primeorder::ecdsa::verify_prehashed_vartime(self, z, sig)
+    }
+}
 
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
+    #[cfg(feature = "hazmat-legacy")]
+    mod sign {
+        use crate::{
+            ecdsa::{
+                signature::{hazmat::PrehashVerifier, Signer},
+                Signature, SigningKey,
+            },
+            test_vectors::ecdsa::ECDSA_TEST_VECTORS,
+            NistP192,
+        };
+        use hex_literal::hex;
+        use sha2::{Digest, Sha256};
+
+        ecdsa_core::new_signing_test!(NistP192, ECDSA_TEST_VECTORS);
+
+        // Test vector from RFC 6979 Appendix 2.3 (NIST P-192 + SHA-256)
+        // <https://tools.ietf.org/html/rfc6979#appendix-A.2.3>
+        #[test]
+        fn rfc6979() {
+            let x = hex!("6FAB034934E4C0FC9AE67F5B5659A9D7D1FEFD187EE09FD4");
+            let signer = SigningKey::from_slice(&x).expect("invalid signing key");
+            let verifier = signer.verifying_key();
+            assert_eq!(
+                verifier.to_encoded_point(false).as_bytes(),
+                &hex!(
+                    "04
+                     AC2C77F529F91689FEA0EA5EFEC7F210D8EEA0B9E047ED56
+                     3BC723E57670BD4887EBC732C523063D0A7C957BC97C1C43"
+                )
+            );
+
+            let signature: Signature = signer.sign(b"sample");
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "4B0B8CE98A92866A2820E20AA6B75B56382E0F9BFD5ECB55
+                     CCDB006926EA9565CBADC840829D8C384E06DE1F1E381B85"
+                )
+            );
+            assert!(verifier
+                .verify_prehash(&Sha256::digest(b"sample"), &signature)
+                .is_ok());
+
+            let signature: Signature = signer.sign(b"test");
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "3A718BD8B4926C3B52EE6BBE67EF79B18CB6EB62B1AD97AE
+                     5662E6848A4A19B1F1AE2F72ACD4B8BBE50F1EAC65D9124F"
+                )
+            );
+            assert!(verifier
+                .verify_prehash(&Sha256::digest(b"test"), &signature)
+                .is_ok());
+        }
+    }
+
     mod verify {
         use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP192};
         ecdsa_core::new_verification_test!(NistP192, ECDSA_TEST_VECTORS);
@@ -18,6 +18,9 @@
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
@@ -29,6 +32,9 @@ pub use elliptic_curve;
 #[cfg(feature = "arithmetic")]
 pub use arithmetic::{scalar::Scalar, AffinePoint, ProjectivePoint};
 
+#[cfg(feature = "expose-field")]
+pub use arithmetic::field::FieldElement;
+
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
 
@@ -90,6 +96,10 @@ impl FieldBytesEncoding<NistP192> for U192 {
     }
 }
 
+/// NIST P-192 public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<NistP192>;
+
 #[cfg(not(feature = "arithmetic"))]
 impl elliptic_curve::sec1::ValidatePublicKey for NistP192 {}
 
@@ -0,0 +1,39 @@
+//! secp224r1 ECDSA benchmarks
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use hex_literal::hex;
+use p224::ecdsa::{
+    signature::hazmat::{PrehashSigner, PrehashVerifier},
+    Signature, SigningKey,
+};
+use sha2::{Digest, Sha224};
+
+fn test_signing_key() -> SigningKey {
+    SigningKey::from_slice(&hex!(
+        "f220266e1105bfe3083e03ec7a3a654651f45e37167e88600bf257c1"
+    ))
+    .unwrap()
+}
+
+fn bench_ecdsa(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ecdsa");
+
+    let signing_key = test_signing_key();
+    let verifying_key = signing_key.verifying_key();
+    let prehash = Sha224::digest(b"sample");
+
+    group.bench_function("sign_prehash", |b| {
+        b.iter(|| -> Signature { signing_key.sign_prehash(&prehash).unwrap() })
+    });
+
+    let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+
+    group.bench_function("verify_prehash", |b| {
+        b.iter(|| verifying_key.verify_prehash(&prehash, &signature).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdsa);
+criterion_main!(benches);
@@ -2,6 +2,10 @@
 //!
 //! Arithmetic implementations have been synthesized using fiat-crypto.
 //!
+//! On 64-bit targets, the `solinas` feature replaces fiat-crypto's Montgomery
+//! multiplication with a hand-written backend which exploits the special form
+//! of the modulus. The formally verified fiat-crypto backend is the default.
+//!
 //! # License
 //!
 //! Copyright (c) 2015-2020 the fiat-crypto authors
@@ -23,6 +27,8 @@
 #[cfg_attr(target_pointer_width = "32", path = "field/p224_32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "field/p224_64.rs")]
 mod field_impl;
+#[cfg(all(any(feature = "solinas", test), target_pointer_width = "64"))]
+mod solinas;
 
 use self::field_impl::*;
 use crate::{FieldBytes, NistP224, Uint};
@@ -36,6 +42,17 @@ use elliptic_curve::{
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
 };
 
+#[cfg(not(all(feature = "solinas", target_pointer_width = "64")))]
+use self::field_impl::{
+    fiat_p224_from_montgomery as p224_from_montgomery, fiat_p224_mul as p224_mul,
+    fiat_p224_square as p224_square, fiat_p224_to_montgomery as p224_to_montgomery,
+};
+#[cfg(all(feature = "solinas", target_pointer_width = "64"))]
+use self::solinas::{
+    solinas_p224_from_montgomery as p224_from_montgomery, solinas_p224_mul as p224_mul,
+    solinas_p224_square as p224_square, solinas_p224_to_montgomery as p224_to_montgomery,
+};
+
 /// Constant representing the modulus serialized as hex.
 /// p = 2^{224} − 2^{96} + 1
 const MODULUS_HEX: &str = "ffffffffffffffffffffffffffffffff000000000000000000000001";
@@ -58,13 +75,13 @@ primeorder::impl_mont_field_element!(
     Uint,
     MODULUS,
     fiat_p224_montgomery_domain_field_element,
-    fiat_p224_from_montgomery,
-    fiat_p224_to_montgomery,
+    p224_from_montgomery,
+    p224_to_montgomery,
     fiat_p224_add,
     fiat_p224_sub,
-    fiat_p224_mul,
+    p224_mul,
     fiat_p224_opp,
-    fiat_p224_square
+    p224_square
 );
 
 impl FieldElement {
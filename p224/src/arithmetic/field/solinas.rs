@@ -0,0 +1,208 @@
+//! Field arithmetic modulo p = 2^{224} − 2^{96} + 1 using Solinas reduction.
+//!
+//! Unlike the fiat-crypto backend, field elements are stored in canonical
+//! (non-Montgomery) form as little endian 64-bit limbs. Products are computed
+//! using schoolbook multiplication and then reduced using the special form of
+//! the modulus:
+//!
+//! ```text
+//! 2^224 ≡ 2^96 − 1 (mod p)
+//! ```
+//!
+//! Addition, subtraction, and negation are independent of the representation,
+//! so the fiat-crypto implementations of those are reused as-is.
+
+/// Number of 64-bit limbs in a field element.
+const LIMBS: usize = 4;
+
+/// Field element as little endian 64-bit limbs.
+type Limbs = [u64; LIMBS];
+
+/// p = 2^{224} − 2^{96} + 1
+const MODULUS: Limbs = [
+    0x0000000000000001,
+    0xffffffff00000000,
+    0xffffffffffffffff,
+    0x00000000ffffffff,
+];
+
+/// Translate a field element into the internal representation.
+///
+/// Elements are stored in canonical form, so this only reduces an input in the
+/// range `[p, 2^256)`.
+pub const fn solinas_p224_to_montgomery(a: &Limbs) -> Limbs {
+    reduce(&[a[0], a[1], a[2], a[3], 0, 0, 0, 0])
+}
+
+/// Translate a field element out of the internal representation.
+///
+/// Elements are stored in canonical form, so this is the identity map.
+pub const fn solinas_p224_from_montgomery(a: &Limbs) -> Limbs {
+    *a
+}
+
+/// Multiply two field elements.
+pub const fn solinas_p224_mul(a: &Limbs, b: &Limbs) -> Limbs {
+    let w = [0u64; 2 * LIMBS];
+    let w = mac_row(w, a, 0, b, 0);
+    let w = mac_row(w, a, 1, b, 0);
+    let w = mac_row(w, a, 2, b, 0);
+    let w = mac_row(w, a, 3, b, 0);
+    reduce(&w)
+}
+
+/// Square a field element.
+pub const fn solinas_p224_square(a: &Limbs) -> Limbs {
+    // Off-diagonal products `a[i] * a[j]` where `i < j`
+    let w = [0u64; 2 * LIMBS];
+    let w = mac_row(w, a, 0, a, 1);
+    let w = mac_row(w, a, 1, a, 2);
+    let mut w = mac_row(w, a, 2, a, 3);
+
+    // Double the off-diagonal products
+    let mut k = 2 * LIMBS - 1;
+    while k > 0 {
+        w[k] = (w[k] << 1) | (w[k - 1] >> 63);
+        k -= 1;
+    }
+    w[0] <<= 1;
+
+    // Add the diagonal products `a[i]^2`
+    let mut carry = 0;
+    let mut i = 0;
+    while i < LIMBS {
+        let sq = (a[i] as u128) * (a[i] as u128);
+        let t = (w[2 * i] as u128) + (sq as u64 as u128) + carry;
+        w[2 * i] = t as u64;
+        let t = (w[2 * i + 1] as u128) + (sq >> 64) + (t >> 64);
+        w[2 * i + 1] = t as u64;
+        carry = t >> 64;
+        i += 1;
+    }
+
+    reduce(&w)
+}
+
+primeorder::impl_solinas_limb_arithmetic!(LIMBS, MODULUS);
+
+/// Reduce a product less than 2^448 modulo p.
+///
+/// Writing the product as 32-bit words `c13, ..., c0`, this computes
+/// `T + S1 + S2 − D1 − D2` as described in FIPS 186-4 Appendix D.2.2, where:
+///
+/// ```text
+/// T  = ( c6,  c5,  c4,  c3,  c2,  c1,  c0)
+/// S1 = (c10,  c9,  c8,  c7,   0,   0,   0)
+/// S2 = (  0, c13, c12, c11,   0,   0,   0)
+/// D1 = (c13, c12, c11, c10,  c9,  c8,  c7)
+/// D2 = (  0,   0,   0,   0, c13, c12, c11)
+/// ```
+///
+/// `2p` is added to keep the sum non-negative.
+#[inline(always)]
+const fn reduce(w: &[u64; 2 * LIMBS]) -> Limbs {
+    const LO: u64 = 0xffffffff;
+
+    // 64-bit limbs of `S1`, `S2`, `D1`, and `D2`. `T` is the low 224 bits of
+    // `w`, so its limbs are used directly.
+    let s1 = [w[3] & !LO, w[4], w[5] & LO];
+    let s2 = [w[5] & !LO, w[6]];
+    let d1 = [
+        (w[3] >> 32) | (w[4] << 32),
+        (w[4] >> 32) | (w[5] << 32),
+        (w[5] >> 32) | (w[6] << 32),
+        w[6] >> 32,
+    ];
+    let d2 = [(w[5] >> 32) | (w[6] << 32), w[6] >> 32];
+
+    // 0 <= T + S1 + S2 − D1 − D2 + 2p < 5 * 2^224
+    let acc = (w[0] as i128) + 2 * (MODULUS[0] as i128) - (d1[0] as i128) - (d2[0] as i128);
+    let r0 = acc as u64;
+    let acc =
+        (acc >> 64) + (w[1] as i128) + (s1[0] as i128) + (s2[0] as i128) + 2 * (MODULUS[1] as i128)
+            - (d1[1] as i128)
+            - (d2[1] as i128);
+    let r1 = acc as u64;
+    let acc =
+        (acc >> 64) + (w[2] as i128) + (s1[1] as i128) + (s2[1] as i128) + 2 * (MODULUS[2] as i128)
+            - (d1[2] as i128);
+    let r2 = acc as u64;
+    let acc = (acc >> 64) + ((w[3] & LO) as i128) + (s1[2] as i128) + 2 * (MODULUS[3] as i128)
+        - (d1[3] as i128);
+    let r3 = acc as u64;
+
+    // Fold the bits above 2^224 using 2^224 ≡ 2^96 − 1 (mod p). `h < 5`, so
+    // the result is less than 2^224 + 2^99 < 2p
+    let h = r3 >> 32;
+    let acc = (r0 as i128) - (h as i128);
+    let r0 = acc as u64;
+    let acc = (acc >> 64) + (r1 as i128) + ((h << 32) as i128);
+    let r1 = acc as u64;
+    let acc = (acc >> 64) + (r2 as i128);
+    let r2 = acc as u64;
+    let acc = (acc >> 64) + ((r3 & LO) as i128);
+    let r3 = acc as u64;
+
+    sub_modulus_if_needed(&[r0, r1, r2, r3])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::field_impl::*, solinas_p224_from_montgomery, solinas_p224_mul, solinas_p224_square,
+        solinas_p224_to_montgomery, Limbs, LIMBS, MODULUS,
+    };
+    use primeorder::impl_solinas_tests;
+    use proptest::{num::u64::ANY, prelude::*};
+
+    const ONE: Limbs = [1, 0, 0, 0];
+
+    impl_solinas_tests!(
+        LIMBS,
+        MODULUS,
+        ONE,
+        field_element,
+        fiat_p224_to_montgomery,
+        fiat_p224_from_montgomery,
+        fiat_p224_mul,
+        fiat_p224_square,
+        fiat_p224_sub,
+        solinas_p224_mul,
+        solinas_p224_square,
+    );
+
+    /// Limbs biased towards values which exercise carry propagation.
+    fn limb() -> impl Strategy<Value = u64> {
+        prop_oneof![
+            Just(0),
+            Just(u64::MAX),
+            Just(u64::MAX >> 32),
+            Just(u64::MAX << 32),
+            ANY,
+        ]
+    }
+
+    fn field_element() -> impl Strategy<Value = Limbs> {
+        (prop::array::uniform3(limb()), limb())
+            .prop_map(|([a0, a1, a2], a3)| [a0, a1, a2, a3 & 0xffffffff])
+            .prop_filter("unreduced", is_reduced)
+    }
+
+    #[test]
+    fn to_montgomery_reduces() {
+        assert_eq!(solinas_p224_to_montgomery(&MODULUS), [0; LIMBS]);
+        assert_eq!(
+            solinas_p224_to_montgomery(&[u64::MAX; LIMBS]),
+            fiat_p224_from_montgomery(&fiat_p224_to_montgomery(&[u64::MAX; LIMBS]))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn to_montgomery_matches_fiat(a in prop::array::uniform4(limb())) {
+            let c = solinas_p224_from_montgomery(&solinas_p224_to_montgomery(&a));
+            assert!(is_reduced(&c));
+            assert_eq!(c, fiat_p224_from_montgomery(&fiat_p224_to_montgomery(&a)));
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Hash-to-curve support for P-224 using the `P224_XMD:SHA-224_SSWU_RO_`
+//! suite, following [RFC 9380].
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use super::{FieldElement, Scalar};
+use crate::NistP224;
+use elliptic_curve::{
+    consts::U42,
+    hash2curve::{OsswuMap, OsswuMapParams},
+    subtle::Choice,
+};
+use primeorder::PrimeCurveParams;
+
+primeorder::impl_hash2curve!(NistP224, FieldElement, Scalar, U42);
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        // `c1` and `c2` are only used by `sqrt_ratio_3mod4`, which is overridden
+        // below as p = 1 (mod 4)
+        c1: &[],
+        c2: FieldElement::ZERO,
+        map_a: NistP224::EQUATION_A,
+        map_b: NistP224::EQUATION_B,
+        z: FieldElement::from_u64(31),
+    };
+
+    fn sqrt_ratio_3mod4(u: Self, v: Self) -> (Choice, Self) {
+        primeorder::hash2curve::sqrt_ratio(u, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::NistP224;
+    use hex_literal::hex;
+    use sha2::Sha224;
+
+    primeorder::impl_hash2curve_tests!(
+        NistP224,
+        FieldElement,
+        Scalar,
+        Sha224,
+        b"QUUX-V01-CS02-with-P224_XMD:SHA-224_SSWU_RO_",
+        hex!("82e3d1db6dbaed53bbcc3bafeab8353f6fcd84986fdc0e814e7ed6ee"),
+        [
+            {
+                msg: b"",
+                p_x: hex!("ad8827c31ef8b75aaa1ae5d3c5a4f3a1ffdf0267751e88d6100b3f36"),
+                p_y: hex!("c80ad5f744a624e9eca132b2f42b86f8a7a135f0920d9254cae8c37d"),
+                u_0: hex!("d688c6043ccac3a596ca6043cb0e1fdb67dc4d899759e93183932fd6"),
+                u_1: hex!("eabe2daa4351754aefd612a68087b7e15239fdc7026943fc1725e0a8"),
+                q0_x: hex!("bd2d86937524d1b43be278c589b76677f966464cc4e8d51b925d02ba"),
+                q0_y: hex!("4841a88bbd1d8c75f8dc019baff6b7c06d687f5a9c273902a5f834e8"),
+                q1_x: hex!("a3d0714920d5128bcfbd6c45cb87222ea39b8af1fbbc678c50876a21"),
+                q1_y: hex!("2e1d21b9748cd9fd38e272c49b26ebdb67d3966f3b5ad9b482e0e272"),
+            },
+            {
+                msg: b"abc",
+                p_x: hex!("34d315434efa516ce858de807f3ab5a704ca1512cac71626fb5c5a03"),
+                p_y: hex!("1efd3df8f43ca1211c2011ab7a0b171429d10820f533b921cecb2124"),
+                u_0: hex!("f24a52d96edebbe932609cafb6452644004594d2add57775cb89384d"),
+                u_1: hex!("9e51d6c008e0617cb25fc87acfdf2e5323edd29a7745ba9815424883"),
+                q0_x: hex!("20644c1662997526a48e1ed95849fa1e10153fa57e13c5fc7988163f"),
+                q0_y: hex!("4f7206c2d2cb7400d8514cd6c1fcba9a6ef337b2334e39e6bfc016c9"),
+                q1_x: hex!("30192504bec08d0935320465e17800b7ee4c5e3a22575b5db99286d9"),
+                q1_y: hex!("294556b17985dc2b9c24583741d9d4b56ba89e777e53a3b95190385d"),
+            },
+            {
+                msg: b"abcdef0123456789",
+                p_x: hex!("69d5c4a85deef803631f55276bb5d26b4c2acceb348deccbd288409c"),
+                p_y: hex!("a3e5262de4458a2e3474005308b68d319eb257a4eb6636d2f72c967f"),
+                u_0: hex!("30296706c3788de6c06897315ef7d3e8ccbbadfb199f84edf2210fcd"),
+                u_1: hex!("c399d6ad9bf1bc863324b46e7ed9e2c254e0973b1f76eb83edc65d4f"),
+                q0_x: hex!("145444553b91b63b2c3e0e1279aab84cc48a6dd27ccceee3d09ae1a5"),
+                q0_y: hex!("4253491d5c9fc0789e3d3c69e6e3d2d6c981a331f1dd9da6a7968681"),
+                q1_x: hex!("88a2b042a037a285337ccfd502830d05157822d020d627ccf85daab0"),
+                q1_y: hex!("3b9a71e4682f86899516283182cd00efb2aba275b39a85eed826291b"),
+            },
+            {
+                msg: b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                p_x: hex!("94ee75e599069f5dc56032ee9a2daef17d475277505d371a0924e6e1"),
+                p_y: hex!("089cb44d22df5f98dd8b5767c3dfaca296b19bb41e950dabd17de8c5"),
+                u_0: hex!("3bf768b92105c46966be421d9d3dec3371edd51a72415adad33f0de2"),
+                u_1: hex!("cd933327bba2d30bb5704ac8912a76f0ff7abbe1b6a596d1561407b1"),
+                q0_x: hex!("005e1397049a9f1d87337df7f1c2022d444d7400de60e3a8dfb9fad7"),
+                q0_y: hex!("2090c0b06973aa04e6c73846ea9b9aa29ae8f9fd553ed36acf0b6fa2"),
+                q1_x: hex!("022c19e8beda005560b049a0bb3c0556600b3cfed56a92546b32f153"),
+                q1_y: hex!("179fb7ffa312a07c83491ceaaf8e53d8d2fed2899a756a4a0266b3f5"),
+            },
+            {
+                msg: b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                p_x: hex!("766656ae0b05f48772479f763198d8294d79ff56a377c9554ebebc83"),
+                p_y: hex!("2260b8db1dcbee7c7ac592b5a5cd7753e4e01117ecd6a3bd74893d19"),
+                u_0: hex!("88e5e2d98412fd762a56a0ca0d273fa424b3886b2a0f1364e9ad30d0"),
+                u_1: hex!("21dc85edd67bf2e735189ede16f963d47d0c21fb306620035abfef02"),
+                q0_x: hex!("f64310e56dc14262d2c2050e0dbf7f63576389c9e13c842bba0f85f1"),
+                q0_y: hex!("41e2c078a515f9cde30ff028301512057a1d11edecb269b5a9989cd6"),
+                q1_x: hex!("7cf9ff494144ba3464d763bbd72d2f7de0bf9bb254fb0fa34503a97a"),
+                q1_y: hex!("cb39320e6b029e8f99290183933ae2bcae794ff24f939b9e2f54a2b0"),
+            },
+        ]
+    );
+}
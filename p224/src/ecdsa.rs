@@ -40,7 +40,7 @@
 pub use ecdsa_core::signature::{self, Error};
 #[cfg(feature = "ecdsa")]
 use {
-    crate::{AffinePoint, Scalar},
+    crate::{AffinePoint, FieldBytes, Scalar},
     ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
 };
 
@@ -69,7 +69,11 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP224 {
 impl SignPrimitive<NistP224> for Scalar {}
 
 #[cfg(feature = "ecdsa")]
-impl VerifyPrimitive<NistP224> for AffinePoint {}
+impl VerifyPrimitive<NistP224> for AffinePoint {
+    fn verify_prehashed(&self, z: &FieldBytes, sig: &Signature) -> Result<(), Error> {
+        primeorder::ecdsa::verify_prehashed_vartime(self, z, sig)
+    }
+}
 
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
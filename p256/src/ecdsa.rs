@@ -38,14 +38,63 @@
 //! assert!(verifying_key.verify(message, &signature).is_ok());
 //! # }
 //! ```
+//!
+//! ## Recovering [`VerifyingKey`] from [`Signature`]
+//!
+//! ECDSA makes it possible to recover the public key used to verify a
+//! signature with the assistance of 2-bits of additional information, which
+//! is represented by [`RecoveryId`].
+//!
+//! This is helpful when there is already a trust relationship for a particular
+//! key, and it's desirable to omit the full public key used to sign a
+//! particular message.
+//!
+//! - Signing with a [`RecoveryId`] is provided by the `sign_*_recoverable`
+//!   methods of [`SigningKey`], e.g. [`SigningKey::sign_digest_recoverable`].
+//! - Signature recovery is implemented as methods of the [`VerifyingKey`]
+//!   type (i.e. `::recover_from_*`).
+//! - Trial recovery is defined on the [`RecoveryId`] type
+//!   (i.e. `::trial_recovery_from_*`).
+//!
+//! The [`RecoveryId`] also records whether the x-coordinate of the ephemeral
+//! point `R` was reduced modulo the group order when computing `r`. This
+//! happens with negligible probability for P-256, but is handled when both
+//! signing and recovering.
+//!
+//! This example requires the `ecdsa` Cargo feature is enabled:
+//!
+//! ```
+//! # #[cfg(feature = "ecdsa")]
+//! # {
+//! use p256::ecdsa::{RecoveryId, SigningKey, VerifyingKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! let signing_key = SigningKey::random(&mut OsRng);
+//! let message = b"ECDSA proves knowledge of a secret number in the context of a single message";
+//! let (signature, recovery_id) = signing_key.sign_recoverable(message).unwrap();
+//!
+//! let recovered_key = VerifyingKey::recover_from_msg(message, &signature, recovery_id).unwrap();
+//! assert_eq!(&recovered_key, signing_key.verifying_key());
+//!
+//! let trial_recovery_id =
+//!     RecoveryId::trial_recovery_from_msg(&recovered_key, message, &signature).unwrap();
+//! assert_eq!(trial_recovery_id, recovery_id);
+//! # }
+//! ```
+
+pub use ecdsa_core::{
+    signature::{self, Error},
+    RecoveryId,
+};
 
-pub use ecdsa_core::signature::{self, Error};
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+pub use primeorder::ecdsa::verify_batch;
 
 use super::NistP256;
 
 #[cfg(feature = "ecdsa")]
 use {
-    crate::{AffinePoint, Scalar},
+    crate::{AffinePoint, FieldBytes, Scalar},
     ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
 };
 
@@ -63,6 +112,10 @@ pub type SigningKey = ecdsa_core::SigningKey<NistP256>;
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP256>;
 
+/// ECDSA/P-256 signature along with its ephemeral point `R` (a.k.a. ECDSA*).
+#[cfg(feature = "ecdsa")]
+pub type SignatureWithR = primeorder::ecdsa::SignatureWithR<NistP256>;
+
 #[cfg(feature = "sha256")]
 impl ecdsa_core::hazmat::DigestPrimitive for NistP256 {
     type Digest = sha2::Sha256;
@@ -72,7 +125,11 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP256 {
 impl SignPrimitive<NistP256> for Scalar {}
 
 #[cfg(feature = "ecdsa")]
-impl VerifyPrimitive<NistP256> for AffinePoint {}
+impl VerifyPrimitive<NistP256> for AffinePoint {
+    fn verify_prehashed(&self, z: &FieldBytes, sig: &Signature) -> Result<(), Error> {
+        primeorder::ecdsa::verify_prehashed_vartime(self, z, sig)
+    }
+}
 
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
@@ -195,4 +252,95 @@ mod tests {
         use crate::NistP256;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP256);
     }
+
+    mod recovery {
+        use crate::{
+            ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey},
+            EncodedPoint,
+        };
+        use hex_literal::hex;
+        use sha2::{Digest, Sha256};
+
+        // Test vector from RFC 6979 Appendix 2.5
+        // <https://tools.ietf.org/html/rfc6979#appendix-A.2.5>
+        #[test]
+        fn rfc6979_recoverable() {
+            let x = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+            let signer = SigningKey::from_bytes(&x.into()).expect("valid key");
+            let digest = Sha256::new_with_prefix(b"sample");
+
+            let (signature, recid) = signer
+                .sign_digest_recoverable(digest.clone())
+                .expect("signing failed");
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716
+                     f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+                )
+            );
+            assert_eq!(recid, RecoveryId::new(false, false));
+
+            let recovered_key =
+                VerifyingKey::recover_from_digest(digest.clone(), &signature, recid)
+                    .expect("recovery failed");
+            assert_eq!(&recovered_key, signer.verifying_key());
+
+            let trial_recid =
+                RecoveryId::trial_recovery_from_digest(&recovered_key, digest, &signature)
+                    .expect("trial recovery failed");
+            assert_eq!(trial_recid, recid);
+        }
+
+        /// Signature whose ephemeral point `R` has an x-coordinate larger than
+        /// the group order, i.e. where `r = R.x - n`.
+        ///
+        /// This is astronomically unlikely to occur when signing, so the
+        /// signature was constructed from `R = (n + 3, y)` with even `y`.
+        #[test]
+        fn x_reduced_recovery() {
+            let prehash = Sha256::digest(b"x-coordinate overflow");
+            let signature = Signature::from_scalars(
+                hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+                hex!("6a5222e9c93648977671d659f87a97d1fca1039c7e68d662363393b2cfd5aa86"),
+            )
+            .expect("valid signature");
+            let expected_key =
+                VerifyingKey::from_encoded_point(&EncodedPoint::from_affine_coordinates(
+                    &hex!("c5f952888639a1130da4cd6a2365c83d7da16218b41bc48373c825252ec10b2c")
+                        .into(),
+                    &hex!("96f0882843f3e8aadbefb98c9ad230a0c62ca599a435bddf3bd9d39c297dbc35")
+                        .into(),
+                    false,
+                ))
+                .expect("valid point");
+
+            let recid = RecoveryId::new(false, true);
+            let recovered_key = VerifyingKey::recover_from_prehash(&prehash, &signature, recid)
+                .expect("recovery failed");
+            assert_eq!(recovered_key, expected_key);
+
+            let trial_recid =
+                RecoveryId::trial_recovery_from_prehash(&expected_key, &prehash, &signature)
+                    .expect("trial recovery failed");
+            assert_eq!(trial_recid, recid);
+
+            // Without the `is_x_reduced` flag the wrong `R` is used
+            let wrong_recid = RecoveryId::new(false, false);
+            assert_ne!(
+                VerifyingKey::recover_from_prehash(&prehash, &signature, wrong_recid).ok(),
+                Some(expected_key)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod batch {
+        use crate::{
+            ecdsa::{verify_batch, SignatureWithR, SigningKey, VerifyingKey},
+            NistP256,
+        };
+
+        primeorder::impl_ecdsa_batch_tests!(NistP256);
+    }
 }
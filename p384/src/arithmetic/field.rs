@@ -2,6 +2,10 @@
 //!
 //! Arithmetic implementations have been synthesized using fiat-crypto.
 //!
+//! On 64-bit targets, the `solinas` feature replaces fiat-crypto's Montgomery
+//! multiplication with a hand-written backend which exploits the special form
+//! of the modulus. The formally verified fiat-crypto backend is the default.
+//!
 //! # License
 //!
 //! Copyright (c) 2015-2020 the fiat-crypto authors
@@ -22,6 +26,8 @@
 #[cfg_attr(target_pointer_width = "32", path = "field/p384_32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "field/p384_64.rs")]
 mod field_impl;
+#[cfg(all(any(feature = "solinas", test), target_pointer_width = "64"))]
+mod solinas;
 
 use self::field_impl::*;
 use crate::{FieldBytes, NistP384};
@@ -37,6 +43,19 @@ use elliptic_curve::{
 };
 use primeorder::impl_bernstein_yang_invert;
 
+#[cfg(not(all(feature = "solinas", target_pointer_width = "64")))]
+use self::field_impl::{
+    fiat_p384_divstep_precomp as p384_divstep_precomp,
+    fiat_p384_from_montgomery as p384_from_montgomery, fiat_p384_mul as p384_mul,
+    fiat_p384_square as p384_square, fiat_p384_to_montgomery as p384_to_montgomery,
+};
+#[cfg(all(feature = "solinas", target_pointer_width = "64"))]
+use self::solinas::{
+    solinas_p384_divstep_precomp as p384_divstep_precomp,
+    solinas_p384_from_montgomery as p384_from_montgomery, solinas_p384_mul as p384_mul,
+    solinas_p384_square as p384_square, solinas_p384_to_montgomery as p384_to_montgomery,
+};
+
 /// Constant representing the modulus
 /// p = 2^{384} − 2^{128} − 2^{96} + 2^{32} − 1
 pub(crate) const MODULUS: U384 = U384::from_be_hex(FieldElement::MODULUS);
@@ -52,13 +71,13 @@ primeorder::impl_mont_field_element!(
     U384,
     MODULUS,
     fiat_p384_montgomery_domain_field_element,
-    fiat_p384_from_montgomery,
-    fiat_p384_to_montgomery,
+    p384_from_montgomery,
+    p384_to_montgomery,
     fiat_p384_add,
     fiat_p384_sub,
-    fiat_p384_mul,
+    p384_mul,
     fiat_p384_opp,
-    fiat_p384_square
+    p384_square
 );
 
 impl FieldElement {
@@ -77,10 +96,10 @@ impl FieldElement {
             384,
             U384::LIMBS,
             Limb,
-            fiat_p384_from_montgomery,
-            fiat_p384_mul,
+            p384_from_montgomery,
+            p384_mul,
             fiat_p384_opp,
-            fiat_p384_divstep_precomp,
+            p384_divstep_precomp,
             fiat_p384_divstep,
             fiat_p384_msat,
             fiat_p384_selectznz,
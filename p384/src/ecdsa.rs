@@ -36,11 +36,61 @@
 //! assert!(verifying_key.verify(message, &signature).is_ok());
 //! # }
 //! ```
+//!
+//! ## Recovering [`VerifyingKey`] from [`Signature`]
+//!
+//! ECDSA makes it possible to recover the public key used to verify a
+//! signature with the assistance of 2-bits of additional information, which
+//! is represented by [`RecoveryId`].
+//!
+//! This is helpful when there is already a trust relationship for a particular
+//! key, and it's desirable to omit the full public key used to sign a
+//! particular message.
+//!
+//! - Signing with a [`RecoveryId`] is provided by the `sign_*_recoverable`
+//!   methods of [`SigningKey`], e.g. [`SigningKey::sign_digest_recoverable`].
+//! - Signature recovery is implemented as methods of the [`VerifyingKey`]
+//!   type (i.e. `::recover_from_*`).
+//! - Trial recovery is defined on the [`RecoveryId`] type
+//!   (i.e. `::trial_recovery_from_*`).
+//!
+//! The [`RecoveryId`] also records whether the x-coordinate of the ephemeral
+//! point `R` was reduced modulo the group order when computing `r`. This
+//! happens with negligible probability for P-384, but is handled when both
+//! signing and recovering.
+//!
+//! This example requires the `ecdsa` Cargo feature is enabled:
+//!
+//! ```
+//! # #[cfg(feature = "ecdsa")]
+//! # {
+//! use p384::ecdsa::{RecoveryId, SigningKey, VerifyingKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! let signing_key = SigningKey::random(&mut OsRng);
+//! let message = b"ECDSA proves knowledge of a secret number in the context of a single message";
+//! let (signature, recovery_id) = signing_key.sign_recoverable(message).unwrap();
+//!
+//! let recovered_key = VerifyingKey::recover_from_msg(message, &signature, recovery_id).unwrap();
+//! assert_eq!(&recovered_key, signing_key.verifying_key());
+//!
+//! let trial_recovery_id =
+//!     RecoveryId::trial_recovery_from_msg(&recovered_key, message, &signature).unwrap();
+//! assert_eq!(trial_recovery_id, recovery_id);
+//! # }
+//! ```
+
+pub use ecdsa_core::{
+    signature::{self, Error},
+    RecoveryId,
+};
+
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+pub use primeorder::ecdsa::verify_batch;
 
-pub use ecdsa_core::signature::{self, Error};
 #[cfg(feature = "ecdsa")]
 use {
-    crate::{AffinePoint, Scalar},
+    crate::{AffinePoint, FieldBytes, Scalar},
     ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
 };
 
@@ -60,6 +110,10 @@ pub type SigningKey = ecdsa_core::SigningKey<NistP384>;
 #[cfg(feature = "ecdsa")]
 pub type VerifyingKey = ecdsa_core::VerifyingKey<NistP384>;
 
+/// ECDSA/P-384 signature along with its ephemeral point `R` (a.k.a. ECDSA*).
+#[cfg(feature = "ecdsa")]
+pub type SignatureWithR = primeorder::ecdsa::SignatureWithR<NistP384>;
+
 #[cfg(feature = "sha384")]
 impl ecdsa_core::hazmat::DigestPrimitive for NistP384 {
     type Digest = sha2::Sha384;
@@ -69,7 +123,11 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP384 {
 impl SignPrimitive<NistP384> for Scalar {}
 
 #[cfg(feature = "ecdsa")]
-impl VerifyPrimitive<NistP384> for AffinePoint {}
+impl VerifyPrimitive<NistP384> for AffinePoint {
+    fn verify_prehashed(&self, z: &FieldBytes, sig: &Signature) -> Result<(), Error> {
+        primeorder::ecdsa::verify_prehashed_vartime(self, z, sig)
+    }
+}
 
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
@@ -182,4 +240,90 @@ mod tests {
         use crate::NistP384;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP384);
     }
+
+    mod recovery {
+        use crate::{
+            ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey},
+            EncodedPoint,
+        };
+        use hex_literal::hex;
+        use sha2::{Digest, Sha384};
+
+        // Test vector from RFC 6979 Appendix 2.6
+        // <https://tools.ietf.org/html/rfc6979#appendix-A.2.6>
+        #[test]
+        fn rfc6979_recoverable() {
+            let x = hex!("6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5");
+            let signer = SigningKey::from_bytes(&x.into()).unwrap();
+            let digest = Sha384::new_with_prefix(b"sample");
+
+            let (signature, recid) = signer.sign_digest_recoverable(digest.clone()).unwrap();
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe46
+                     99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8"
+                )
+            );
+            assert_eq!(recid, RecoveryId::new(true, false));
+
+            let recovered_key =
+                VerifyingKey::recover_from_digest(digest.clone(), &signature, recid).unwrap();
+            assert_eq!(&recovered_key, signer.verifying_key());
+
+            let trial_recid =
+                RecoveryId::trial_recovery_from_digest(&recovered_key, digest, &signature).unwrap();
+            assert_eq!(trial_recid, recid);
+        }
+
+        /// Signature whose ephemeral point `R` has an x-coordinate larger than
+        /// the group order, i.e. where `r = R.x - n`.
+        ///
+        /// This is astronomically unlikely to occur when signing, so the
+        /// signature was constructed from `R = (n + 2, y)` with even `y`.
+        #[test]
+        fn x_reduced_recovery() {
+            let prehash = Sha384::digest(b"x-coordinate overflow");
+            let signature = Signature::from_scalars(
+                hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"),
+                hex!("4fab928da7d9275d4ed721f68fde276fcc92d21c05511279fa0b5b22f90841334f2104081f35f5961dd059b495fff6f5"),
+            )
+            .unwrap();
+            let expected_key = VerifyingKey::from_encoded_point(
+                &EncodedPoint::from_affine_coordinates(
+                    &hex!("70dc8e7d1780b38861f1f9c61e17f3639de4970a1bd04ecc4bd488ae77888c1029906c0cebc2a0c26b2b72e92b76e142").into(),
+                    &hex!("0de199e560eee8d3fc609d99ae2b80aee12d0e5d51a773b2e5efc2980d17665cfad6816d4a0d28b0f4cb14968e848661").into(),
+                    false,
+                ),
+            )
+            .unwrap();
+
+            let recid = RecoveryId::new(false, true);
+            let recovered_key =
+                VerifyingKey::recover_from_prehash(&prehash, &signature, recid).unwrap();
+            assert_eq!(recovered_key, expected_key);
+
+            let trial_recid =
+                RecoveryId::trial_recovery_from_prehash(&expected_key, &prehash, &signature)
+                    .unwrap();
+            assert_eq!(trial_recid, recid);
+
+            // Without the `is_x_reduced` flag the wrong `R` is used
+            let wrong_recid = RecoveryId::new(false, false);
+            assert_ne!(
+                VerifyingKey::recover_from_prehash(&prehash, &signature, wrong_recid).ok(),
+                Some(expected_key)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod batch {
+        use crate::{
+            ecdsa::{verify_batch, SignatureWithR, SigningKey, VerifyingKey},
+            NistP384,
+        };
+
+        primeorder::impl_ecdsa_batch_tests!(NistP384);
+    }
 }
@@ -0,0 +1,576 @@
+//! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! To use it, you will need to enable one of the two following Cargo features:
+//!
+//! - `ecdsa-core`: provides only the [`Signature`] type (which represents an
+//!   ECDSA/P-521 signature). Does not require the `arithmetic` feature. This is
+//!   useful for 3rd-party crates which wish to use the `Signature` type for
+//!   interoperability purposes (particularly in conjunction with the
+//!   [`signature::Signer`] trait. Example use cases for this include other
+//!   software implementations of ECDSA/P-521 and wrappers for cloud KMS
+//!   services or hardware devices (HSM or crypto hardware wallet).
+//! - `ecdsa`: provides `ecdsa-core` features plus the [`SigningKey`] and
+//!   [`VerifyingKey`] types which natively implement ECDSA/P-521 signing and
+//!   verification.
+//!
+//! Messages are hashed with SHA-512. Unlike the other curves in this
+//! repository, [`SigningKey`] and [`VerifyingKey`] are newtypes rather than
+//! aliases for the generic `ecdsa` types: the serialized size of a P-521 scalar
+//! (66 bytes) does not match the SHA-512 output size, and the 521-bit group
+//! order requires bit-level digest truncation as described in
+//! [RFC6979 § 2.3.2].
+//!
+//! ## Signing/Verification Example
+//!
+//! This example requires the `ecdsa` Cargo feature is enabled:
+//!
+//! ```
+//! # #[cfg(feature = "ecdsa")]
+//! # {
+//! use p521::ecdsa::{signature::Signer, Signature, SigningKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Signing
+//! let signing_key = SigningKey::random(&mut OsRng); // Serialize with `::to_bytes()`
+//! let message = b"ECDSA proves knowledge of a secret number in the context of a single message";
+//! let signature: Signature = signing_key.sign(message);
+//!
+//! // Verification
+//! use p521::ecdsa::{signature::Verifier, VerifyingKey};
+//!
+//! let verifying_key = VerifyingKey::from(&signing_key); // Serialize with `::to_encoded_point()`
+//! assert!(verifying_key.verify(message, &signature).is_ok());
+//! # }
+//! ```
+//!
+//! [RFC6979 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.2
+
+pub use ecdsa_core::signature::{self, Error};
+#[cfg(feature = "ecdsa")]
+use {
+    crate::{AffinePoint, EncodedPoint, FieldBytes, PublicKey, Scalar, SecretKey},
+    ecdsa_core::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::{
+            digest::Digest,
+            hazmat::{PrehashSigner, PrehashVerifier, RandomizedPrehashSigner},
+            rand_core::CryptoRngCore,
+            DigestSigner, DigestVerifier, Keypair, RandomizedDigestSigner, RandomizedSigner,
+            Signer, Verifier,
+        },
+    },
+    elliptic_curve::{
+        bigint::U576, generic_array::typenum::Unsigned, FieldBytesEncoding, FieldBytesSize,
+        NonZeroScalar,
+    },
+    sha2::Sha512,
+};
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+use crate::pkcs8;
+
+use super::NistP521;
+
+/// ECDSA/P-521 signature (fixed-size)
+pub type Signature = ecdsa_core::Signature<NistP521>;
+
+/// ECDSA/P-521 signature (ASN.1 DER encoded)
+pub type DerSignature = ecdsa_core::der::Signature<NistP521>;
+
+/// ECDSA/P-521 signing key.
+///
+/// Computes signatures over SHA-512 message digests using deterministic
+/// ephemeral scalars as described in [RFC6979].
+///
+/// [RFC6979]: https://datatracker.ietf.org/doc/html/rfc6979
+#[cfg(feature = "ecdsa")]
+#[derive(Clone, Debug)]
+pub struct SigningKey(ecdsa_core::SigningKey<NistP521>);
+
+#[cfg(feature = "ecdsa")]
+impl SigningKey {
+    /// Generate a cryptographically random [`SigningKey`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        ecdsa_core::SigningKey::random(rng).into()
+    }
+
+    /// Initialize signing key from a raw scalar serialized as a byte array.
+    pub fn from_bytes(bytes: &FieldBytes) -> Result<Self, Error> {
+        ecdsa_core::SigningKey::from_bytes(bytes).map(Self)
+    }
+
+    /// Initialize signing key from a raw scalar serialized as a byte slice.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        ecdsa_core::SigningKey::from_slice(bytes).map(Self)
+    }
+
+    /// Serialize this [`SigningKey`] as bytes.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+
+    /// Borrow the secret [`NonZeroScalar`] value for this key.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This value is key material.
+    ///
+    /// Please treat it with the care it deserves!
+    pub fn as_nonzero_scalar(&self) -> &NonZeroScalar<NistP521> {
+        self.0.as_nonzero_scalar()
+    }
+
+    /// Get the [`VerifyingKey`] which corresponds to this [`SigningKey`].
+    pub fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey(*self.0.verifying_key())
+    }
+
+    /// Sign the given field-sized digest, using `ad` as additional data for
+    /// the RFC6979 ephemeral scalar derivation.
+    fn sign_field_bytes(&self, z: &FieldBytes, ad: &[u8]) -> Result<Signature, Error> {
+        let x = self.as_nonzero_scalar();
+        Ok(primeorder::ecdsa::sign_prehashed_rfc6979::<NistP521, Sha512>(x, z, ad)?.0)
+    }
+}
+
+/// Sign message prehash using a deterministic ephemeral scalar (`k`)
+/// computed using the algorithm described in [RFC6979 § 3.2].
+///
+/// [RFC6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3
+#[cfg(feature = "ecdsa")]
+impl PrehashSigner<Signature> for SigningKey {
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature, Error> {
+        self.sign_field_bytes(&bits2field(prehash)?, &[])
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl DigestSigner<Sha512, Signature> for SigningKey {
+    fn try_sign_digest(&self, msg_digest: Sha512) -> Result<Signature, Error> {
+        self.sign_prehash(&msg_digest.finalize())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.try_sign_digest(Sha512::new_with_prefix(msg))
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Signer<DerSignature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<DerSignature, Error> {
+        Signer::<Signature>::try_sign(self, msg).map(Into::into)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl RandomizedPrehashSigner<Signature> for SigningKey {
+    fn sign_prehash_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature, Error> {
+        let z = bits2field(prehash)?;
+        let mut ad = FieldBytes::default();
+        rng.fill_bytes(&mut ad);
+        self.sign_field_bytes(&z, &ad)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl RandomizedDigestSigner<Sha512, Signature> for SigningKey {
+    fn try_sign_digest_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg_digest: Sha512,
+    ) -> Result<Signature, Error> {
+        self.sign_prehash_with_rng(rng, &msg_digest.finalize())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<Signature, Error> {
+        self.try_sign_digest_with_rng(rng, Sha512::new_with_prefix(msg))
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Keypair for SigningKey {
+    type VerifyingKey = VerifyingKey;
+
+    fn verifying_key(&self) -> VerifyingKey {
+        SigningKey::verifying_key(self)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<NonZeroScalar<NistP521>> for SigningKey {
+    fn from(secret_scalar: NonZeroScalar<NistP521>) -> Self {
+        Self(secret_scalar.into())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<ecdsa_core::SigningKey<NistP521>> for SigningKey {
+    fn from(signing_key: ecdsa_core::SigningKey<NistP521>) -> Self {
+        Self(signing_key)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<SecretKey> for SigningKey {
+    fn from(secret_key: SecretKey) -> Self {
+        Self::from(&secret_key)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&SecretKey> for SigningKey {
+    fn from(secret_key: &SecretKey) -> Self {
+        Self(secret_key.into())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<SigningKey> for SecretKey {
+    fn from(signing_key: SigningKey) -> Self {
+        signing_key.0.into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_slice(bytes)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for SigningKey {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        ecdsa_core::SigningKey::try_from(private_key_info).map(Self)
+    }
+}
+
+/// ECDSA/P-521 verification key (i.e. public key).
+///
+/// Verifies signatures over SHA-512 message digests.
+#[cfg(feature = "ecdsa")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyingKey(ecdsa_core::VerifyingKey<NistP521>);
+
+#[cfg(feature = "ecdsa")]
+impl VerifyingKey {
+    /// Initialize [`VerifyingKey`] from a SEC1-encoded public key.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        ecdsa_core::VerifyingKey::from_sec1_bytes(bytes).map(Self)
+    }
+
+    /// Initialize [`VerifyingKey`] from an affine point.
+    ///
+    /// Returns an [`Error`] if the given affine point is the additive identity
+    /// (a.k.a. point at infinity).
+    pub fn from_affine(affine: AffinePoint) -> Result<Self, Error> {
+        ecdsa_core::VerifyingKey::from_affine(affine).map(Self)
+    }
+
+    /// Initialize [`VerifyingKey`] from an [`EncodedPoint`].
+    pub fn from_encoded_point(public_key: &EncodedPoint) -> Result<Self, Error> {
+        ecdsa_core::VerifyingKey::from_encoded_point(public_key).map(Self)
+    }
+
+    /// Serialize this [`VerifyingKey`] as a SEC1 [`EncodedPoint`], optionally
+    /// applying point compression.
+    pub fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        self.0.to_encoded_point(compress)
+    }
+
+    /// Borrow the inner [`AffinePoint`] for this public key.
+    pub fn as_affine(&self) -> &AffinePoint {
+        self.0.as_affine()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl PrehashVerifier<Signature> for VerifyingKey {
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.as_affine()
+            .verify_prehashed(&bits2field(prehash)?, signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl DigestVerifier<Sha512, Signature> for VerifyingKey {
+    fn verify_digest(&self, msg_digest: Sha512, signature: &Signature) -> Result<(), Error> {
+        self.verify_prehash(&msg_digest.finalize(), signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Verifier<Signature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.verify_digest(Sha512::new_with_prefix(msg), signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Verifier<DerSignature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &DerSignature) -> Result<(), Error> {
+        let signature = Signature::try_from(signature.clone())?;
+        Verifier::<Signature>::verify(self, msg, &signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&SigningKey> for VerifyingKey {
+    fn from(signing_key: &SigningKey) -> VerifyingKey {
+        signing_key.verifying_key()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<SigningKey> for VerifyingKey {
+    fn from(signing_key: SigningKey) -> VerifyingKey {
+        signing_key.verifying_key()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<ecdsa_core::VerifyingKey<NistP521>> for VerifyingKey {
+    fn from(verifying_key: ecdsa_core::VerifyingKey<NistP521>) -> VerifyingKey {
+        Self(verifying_key)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<PublicKey> for VerifyingKey {
+    fn from(public_key: PublicKey) -> VerifyingKey {
+        Self(public_key.into())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<VerifyingKey> for PublicKey {
+    fn from(verifying_key: VerifyingKey) -> PublicKey {
+        verifying_key.0.into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<VerifyingKey> for EncodedPoint {
+    fn from(verifying_key: VerifyingKey) -> EncodedPoint {
+        verifying_key.to_encoded_point(false)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_sec1_bytes(bytes)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+impl TryFrom<pkcs8::SubjectPublicKeyInfoRef<'_>> for VerifyingKey {
+    type Error = pkcs8::spki::Error;
+
+    fn try_from(spki: pkcs8::SubjectPublicKeyInfoRef<'_>) -> pkcs8::spki::Result<Self> {
+        ecdsa_core::VerifyingKey::try_from(spki).map(Self)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl SignPrimitive<NistP521> for Scalar {}
+
+/// Verification only involves public inputs, so it's performed using
+/// variable-time scalar multiplication.
+#[cfg(feature = "ecdsa")]
+impl VerifyPrimitive<NistP521> for AffinePoint {
+    fn verify_prehashed(&self, z: &FieldBytes, sig: &Signature) -> Result<(), Error> {
+        primeorder::ecdsa::verify_prehashed_vartime(self, z, sig)
+    }
+}
+
+/// Number of bits in the order of the P-521 group.
+#[cfg(feature = "ecdsa")]
+const ORDER_BITS: usize = 521;
+
+/// Convert a message prehash into a serialized (unreduced) field element
+/// using the `bits2int` function described in [RFC6979 § 2.3.2].
+///
+/// P-521's order is not byte-aligned, so prehashes longer than 521 bits are
+/// truncated to their leftmost 521 bits, which requires a right shift of the
+/// leading 66 bytes. Shorter prehashes are left-padded with zeroes. Prehashes
+/// shorter than half the field size are rejected.
+///
+/// [RFC6979 § 2.3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.2
+#[cfg(feature = "ecdsa")]
+fn bits2field(bits: &[u8]) -> Result<FieldBytes, Error> {
+    let field_size = FieldBytesSize::<NistP521>::USIZE;
+
+    if bits.len() < field_size / 2 {
+        return Err(Error::new());
+    }
+
+    let mut field_bytes = FieldBytes::default();
+
+    if bits.len() < field_size {
+        field_bytes[(field_size - bits.len())..].copy_from_slice(bits);
+    } else {
+        field_bytes.copy_from_slice(&bits[..field_size]);
+        field_bytes = bits2int(&field_bytes).encode_field_bytes();
+    }
+
+    Ok(field_bytes)
+}
+
+/// Interpret a 66-byte (528-bit) string as an integer by taking its leftmost
+/// 521 bits.
+#[cfg(feature = "ecdsa")]
+fn bits2int(bytes: &FieldBytes) -> U576 {
+    let shift = FieldBytesSize::<NistP521>::USIZE * 8 - ORDER_BITS;
+    U576::decode_field_bytes(bytes).shr_vartime(shift)
+}
+
+#[cfg(all(test, feature = "ecdsa"))]
+mod tests {
+    use crate::ecdsa::{
+        signature::{
+            hazmat::{PrehashSigner, PrehashVerifier},
+            Signer, Verifier,
+        },
+        Signature, SigningKey, VerifyingKey,
+    };
+    use hex_literal::hex;
+
+    // Test vector from RFC 6979 Appendix 2.7 (NIST P-521 + SHA-512)
+    // <https://tools.ietf.org/html/rfc6979#appendix-A.2.7>
+    const RFC6979_KEY: [u8; 66] = hex!(
+        "00FAD06DAA62BA3B25D2FB40133DA757205DE67F5BB0018FEE8C86E1B68C7E75CA
+         A896EB32F1F47C70855836A6D16FCC1466F6D8FBEC67DB89EC0C08B0E996B83538"
+    );
+
+    #[test]
+    fn rfc6979() {
+        let signer = SigningKey::from_slice(&RFC6979_KEY).expect("invalid signing key");
+        let verifier = signer.verifying_key();
+        assert_eq!(
+            verifier.to_encoded_point(false).as_bytes(),
+            &hex!(
+                "04
+                 01894550D0785932E00EAA23B694F213F8C3121F86DC97A04E5A7167DB4E5BCD37
+                 1123D46E45DB6B5D5370A7F20FB633155D38FFA16D2BD761DCAC474B9A2F5023A4
+                 00493101C962CD4D2FDDF782285E64584139C2F91B47F87FF82354D6630F746A28
+                 A0DB25741B5B34A828008B22ACC23F924FAAFBD4D33F81EA66956DFEAA2BFDFCF5"
+            )
+        );
+
+        let signature: Signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "00C328FAFCBD79DD77850370C46325D987CB525569FB63C5D3BC53950E6D4C5F17
+                 4E25A1EE9017B5D450606ADD152B534931D7D4E8455CC91F9B15BF05EC36E377FA
+                 00617CCE7CF5064806C467F678D3B4080D6F1CC50AF26CA209417308281B68AF28
+                 2623EAA63E5B5C0723D8B8C37FF0777B1A20F8CCB1DCCC43997F1EE0E44DA4A67A"
+            )
+        );
+        assert!(verifier.verify(b"sample", &signature).is_ok());
+
+        let signature: Signature = signer.sign(b"test");
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "013E99020ABF5CEE7525D16B69B229652AB6BDF2AFFCAEF38773B4B7D08725F10C
+                 DB93482FDCC54EDCEE91ECA4166B2A7C6265EF0CE2BD7051B7CEF945BABD47EE6D
+                 01FBD0013C674AA79CB39849527916CE301C66EA7CE8B80682786AD60F98F7E78A
+                 19CA69EFF5C57400E3B3A0AD66CE0978214D13BAF4E9AC60752F7B155E2DE4DCE3"
+            )
+        );
+        assert!(verifier.verify(b"test", &signature).is_ok());
+    }
+
+    /// Prehashes longer than the 521-bit group order must be truncated to
+    /// their leftmost 521 bits rather than their leftmost 66 bytes.
+    #[test]
+    fn long_prehash() {
+        // SHA-512("sample") || SHA-512("test")
+        let prehash = hex!(
+            "39A5E04AAFF7455D9850C605364F514C11324CE64016960D23D5DC57D3FFD8F4
+             9A739468AB8049BF18EEF820CDB1AD6C9015F838556BC7FAD4138B23FDF986C7
+             EE26B0DD4AF7E749AA1A8EE3C10AE9923F618980772E473F8819A5D4940E0DB2
+             7AC185F8A0E1D5F84F88BC887FD67B143732C304CC5FA9AD8E6F57F50028A8FF"
+        );
+
+        let signer = SigningKey::from_slice(&RFC6979_KEY).expect("invalid signing key");
+        let signature: Signature = signer.sign_prehash(&prehash).expect("signing failed");
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "002266A87B6F50AED3E1610B8470C9EFA8F1C03A07FD53D2D7C21A036EEEE4B244
+                 1D7DC7449338EE3F9D4292CB4BC3AE092502D4677D6C1CD197400EF65D941D1D6F
+                 01FC474F364CBD3EA00C4331B20ABD5711AE572D9F2441FD41AEECBE82C22C5C89
+                 56EF6A848ECB3A5FDF27814B91BE3A4A755906D05D925CDAE9D286845BDB56165B"
+            )
+        );
+
+        let verifier = VerifyingKey::from(&signer);
+        assert!(verifier.verify_prehash(&prehash, &signature).is_ok());
+        assert!(verifier.verify_prehash(&prehash[..66], &signature).is_ok());
+        assert!(verifier.verify_prehash(&prehash[..64], &signature).is_err());
+    }
+
+    #[test]
+    fn short_prehash() {
+        let signer = SigningKey::from_slice(&RFC6979_KEY).expect("invalid signing key");
+        let result: Result<Signature, _> = signer.sign_prehash(&[0u8; 32]);
+        assert!(result.is_err());
+    }
+
+    mod sign {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP521};
+        ecdsa_core::new_signing_test!(NistP521, ECDSA_TEST_VECTORS);
+    }
+
+    mod verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP521};
+        ecdsa_core::new_verification_test!(NistP521, ECDSA_TEST_VECTORS);
+    }
+
+    /// Wycheproof `ecdsa_secp521r1_sha512_test.json`, with public key
+    /// coordinates left-padded to 66 bytes.
+    #[test]
+    fn wycheproof() {
+        use blobby::Blob5Iterator;
+
+        let data = include_bytes!("test_vectors/data/wycheproof.blb");
+
+        for (i, row) in Blob5Iterator::new(data)
+            .expect("invalid blobby data")
+            .enumerate()
+        {
+            let [x, y, msg, sig, pass] = row.expect("invalid blobby row");
+            let point = [&[0x04], x, y].concat();
+            let verifying_key = VerifyingKey::from_sec1_bytes(&point).expect("invalid public key");
+            let valid = Signature::from_der(sig)
+                .and_then(|sig| verifying_key.verify(msg, &sig))
+                .is_ok();
+
+            assert_eq!(valid, pass == [1], "Wycheproof test case {}", i + 1);
+        }
+    }
+}
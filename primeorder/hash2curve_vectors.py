@@ -0,0 +1,302 @@
+#!/usr/bin/env python3
+"""Generate hash-to-curve test vectors for suites without published vectors.
+
+This is a direct transcription of the RFC 9380 pseudocode for
+`expand_message_xmd` (§ 5.3.1), `hash_to_field` (§ 5.2), the simplified SWU
+map (§ 6.6.2), `hash_to_curve` (§ 3) and `find_z_sswu` (Appendix H.2), using
+plain affine arithmetic and no optimizations. It first checks itself against
+the P256_XMD:SHA-256_SSWU_RO_ and P384_XMD:SHA-384_SSWU_RO_ vectors of
+RFC 9380 Appendix J, then prints `Z` and the inputs of
+`primeorder::impl_hash2curve_tests!` for each curve.
+
+Requires Python 3.8+. SM3 support depends on the OpenSSL build backing
+`hashlib`.
+
+Usage: python3 hash2curve_vectors.py [CURVE...]
+"""
+
+import hashlib
+import sys
+
+MSGS = [b"", b"abc", b"abcdef0123456789", b"q128_" + b"q" * 128, b"a512_" + b"a" * 512]
+
+
+def expand_message_xmd(msg, dst, length, hash_fn):
+    b_in = hash_fn().block_size
+    b_out = hash_fn().digest_size
+    ell = -(-length // b_out)
+    assert ell <= 255 and len(dst) <= 255
+    dst_prime = dst + bytes([len(dst)])
+    b0 = hash_fn(b"\0" * b_in + msg + length.to_bytes(2, "big") + b"\0" + dst_prime).digest()
+    b = [hash_fn(b0 + b"\1" + dst_prime).digest()]
+    for i in range(2, ell + 1):
+        xored = bytes(x ^ y for x, y in zip(b0, b[-1]))
+        b.append(hash_fn(xored + bytes([i]) + dst_prime).digest())
+    return b"".join(b)[:length]
+
+
+def hash_to_field(msg, count, dst, modulus, length, hash_fn):
+    uniform = expand_message_xmd(msg, dst, count * length, hash_fn)
+    chunks = [uniform[i * length : (i + 1) * length] for i in range(count)]
+    return [int.from_bytes(chunk, "big") % modulus for chunk in chunks]
+
+
+def is_square(x, p):
+    return x % p == 0 or pow(x, (p - 1) // 2, p) == 1
+
+
+def sqrt(x, p):
+    if p % 4 == 3:
+        r = pow(x, (p + 1) // 4, p)
+    else:
+        # Tonelli-Shanks
+        q, s = p - 1, 0
+        while q % 2 == 0:
+            q, s = q // 2, s + 1
+        z = 2
+        while is_square(z, p):
+            z += 1
+        m, c, t, r = s, pow(z, q, p), pow(x, q, p), pow(x, (q + 1) // 2, p)
+        while t != 1:
+            i, tt = 0, t
+            while tt != 1:
+                tt, i = tt * tt % p, i + 1
+            b = pow(c, 1 << (m - i - 1), p)
+            m, c, t, r = i, b * b % p, t * b * b % p, r * b % p
+    assert r * r % p == x % p
+    return r
+
+
+def map_to_curve_simple_swu(u, a, b, z, p):
+    tv1 = pow((z * z * pow(u, 4, p) + z * u * u) % p, p - 2, p)
+    x1 = -b * pow(a, p - 2, p) * (1 + tv1) % p
+    if tv1 == 0:
+        x1 = b * pow(z * a, p - 2, p) % p
+    gx1 = (x1**3 + a * x1 + b) % p
+    x2 = z * u * u * x1 % p
+    gx2 = (x2**3 + a * x2 + b) % p
+    if is_square(gx1, p):
+        x, y = x1, sqrt(gx1, p)
+    else:
+        x, y = x2, sqrt(gx2, p)
+    if u % 2 != y % 2:
+        y = -y % p
+    return x, y
+
+
+def add(p1, p2, a, p):
+    (x1, y1), (x2, y2) = p1, p2
+    if x1 == x2:
+        assert (y1 + y2) % p != 0
+        slope = (3 * x1 * x1 + a) * pow(2 * y1, -1, p) % p
+    else:
+        slope = (y2 - y1) * pow(x2 - x1, -1, p) % p
+    x3 = (slope * slope - x1 - x2) % p
+    return x3, (slope * (x1 - x3) - y1) % p
+
+
+def is_irreducible(a, b, z, p):
+    """Is `g(x) - z` irreducible over GF(p), i.e. does it have no roots?"""
+    f = [(b - z) % p, a % p, 0, 1]
+
+    def mulmod(x, y):
+        r = [0] * (len(x) + len(y) - 1)
+        for i, xi in enumerate(x):
+            for j, yj in enumerate(y):
+                r[i + j] = (r[i + j] + xi * yj) % p
+        while len(r) > 3:
+            c = r.pop()
+            k = len(r) - 3
+            r[k] = (r[k] - c * f[0]) % p
+            r[k + 1] = (r[k + 1] - c * f[1]) % p
+        return r
+
+    def trim(x):
+        while x and x[-1] == 0:
+            x.pop()
+        return x
+
+    # x^p - x mod f
+    res, base, e = [1], [0, 1], p
+    while e:
+        if e & 1:
+            res = mulmod(res, base)
+        base, e = mulmod(base, base), e >> 1
+    res += [0] * (3 - len(res))
+    res[1] = (res[1] - 1) % p
+
+    # gcd(f, x^p - x) is a constant iff f has no roots
+    x, y = trim(f[:]), trim(res)
+    while y:
+        while len(x) >= len(y):
+            c, d = x[-1] * pow(y[-1], -1, p) % p, len(x) - len(y)
+            for i, yi in enumerate(y):
+                x[i + d] = (x[i + d] - c * yi) % p
+            if not trim(x):
+                break
+        x, y = y, x
+    return len(x) == 1
+
+
+def find_z_sswu(a, b, p):
+    def g(x):
+        return (x**3 + a * x + b) % p
+
+    ctr = 1
+    while True:
+        for z in [ctr % p, -ctr % p]:
+            if is_square(z, p) or z == p - 1:
+                continue
+            if not is_irreducible(a, b, z, p):
+                continue
+            if is_square(g(b * pow(z * a, -1, p) % p), p):
+                return z
+        ctr += 1
+
+
+def hash_to_curve(msg, dst, curve):
+    p, a, b, z = curve["p"], curve["a"] % curve["p"], curve["b"], curve["z"]
+    u = hash_to_field(msg, 2, dst, p, curve["L"], curve["H"])
+    q0 = map_to_curve_simple_swu(u[0], a, b, z, p)
+    q1 = map_to_curve_simple_swu(u[1], a, b, z, p)
+    return u, q0, q1, add(q0, q1, a, p)
+
+
+def sm3(data=b""):
+    return hashlib.new("sm3", data)
+
+
+CURVES = {
+    "p256": dict(
+        p=2**256 - 2**224 + 2**192 + 2**96 - 1,
+        a=-3,
+        b=0x5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B,
+        n=0xFFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551,
+        suite="P256_XMD:SHA-256_SSWU_RO_",
+        L=48,
+        H=hashlib.sha256,
+    ),
+    "p384": dict(
+        p=2**384 - 2**128 - 2**96 + 2**32 - 1,
+        a=-3,
+        b=0xB3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875AC656398D8A2ED19D2A85C8EDD3EC2AEF,
+        n=0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973,
+        suite="P384_XMD:SHA-384_SSWU_RO_",
+        L=72,
+        H=hashlib.sha384,
+    ),
+    "p192": dict(
+        p=2**192 - 2**64 - 1,
+        a=-3,
+        b=0x64210519E59C80E70FA7E9AB72243049FEB8DEECC146B9B1,
+        n=0xFFFFFFFFFFFFFFFFFFFFFFFF99DEF836146BC9B1B4D22831,
+        suite="P192_XMD:SHA-256_SSWU_RO_",
+        L=36,
+        H=hashlib.sha256,
+    ),
+    "p224": dict(
+        p=2**224 - 2**96 + 1,
+        a=-3,
+        b=0xB4050A850C04B3ABF54132565044B0B7D7BFD8BA270B39432355FFB4,
+        n=0xFFFFFFFFFFFFFFFFFFFFFFFFFFFF16A2E0B8F03E13DD29455C5C2A3D,
+        suite="P224_XMD:SHA-224_SSWU_RO_",
+        L=42,
+        H=hashlib.sha224,
+    ),
+    "sm2": dict(
+        p=0xFFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFF,
+        a=-3,
+        b=0x28E9FA9E9D9F5E344D5A9E4BCF6509A7F39789F515AB8F92DDBCBD414D940E93,
+        n=0xFFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54123,
+        suite="SM2_XMD:SM3_SSWU_RO_",
+        L=48,
+        H=sm3,
+    ),
+    "bign256": dict(
+        p=2**256 - 189,
+        a=-3,
+        b=0x77CE6C1515F3A8EDD2C13AABE4D8FBBE4CF55069978B9253B22E7D6BD69C03F1,
+        n=0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFD95C8ED60DFB4DFC7E5ABF99263D6607,
+        suite="bign-curve256v1_XMD:SHA-256_SSWU_RO_",
+        L=48,
+        H=hashlib.sha256,
+    ),
+    "bp256": dict(
+        p=0xA9FB57DBA1EEA9BC3E660A909D838D726E3BF623D52620282013481D1F6E5377,
+        a=0x7D5A0975FC2C3057EEF67530417AFFE7FB8055C126DC5C6CE94A4B44F330B5D9,
+        b=0x26DC5C6CE94A4B44F330B5D9BBD77CBF958416295CF7E1CE6BCCDC18FF8C07B6,
+        n=0xA9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A7,
+        suite="brainpoolP256r1_XMD:SHA-256_SSWU_RO_",
+        L=48,
+        H=hashlib.sha256,
+    ),
+    "bp384": dict(
+        p=0x8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B412B1DA197FB71123ACD3A729901D1A71874700133107EC53,
+        a=0x7BC382C63D8C150C3C72080ACE05AFA0C2BEA28E4FB22787139165EFBA91F90F8AA5814A503AD4EB04A8C7DD22CE2826,
+        b=0x04A8C7DD22CE28268B39B55416F0447C2FB77DE107DCD2A62E880EA53EEB62D57CB4390295DBC9943AB78696FA504C11,
+        n=0x8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7CF3AB6AF6B7FC3103B883202E9046565,
+        suite="brainpoolP384r1_XMD:SHA-384_SSWU_RO_",
+        L=72,
+        H=hashlib.sha384,
+    ),
+}
+
+# `P.x` for the first two messages of RFC 9380 Appendices J.1.1 and J.2.1.
+RFC9380_P_X = {
+    "p256": [
+        0x2C15230B26DBC6FC9A37051158C95B79656E17A1A920B11394CA91C44247D3E4,
+        0x0BB8B87485551AA43ED54F009230450B492FEAD5F1CC91658775DAC4A3388A0F,
+    ],
+    "p384": [
+        0xEB9FE1B4F4E14E7140803C1D99D0A93CD823D2B024040F9C067A8ECA1F5A2EEAC9AD604973527A356F3FA3AEFF0E4D83,
+        0xE02FC1A5F44A7519419DD314E29863F30DF55A514DA2D655775A81D413003C4D4E7FD59AF0826DFAAD4200AC6F60ABE1,
+    ],
+}
+
+
+def dst(curve):
+    return ("QUUX-V01-CS02-with-" + curve["suite"]).encode()
+
+
+def main():
+    for name, curve in CURVES.items():
+        curve["z"] = find_z_sswu(curve["a"] % curve["p"], curve["b"], curve["p"])
+
+    for name, expected in RFC9380_P_X.items():
+        curve = CURVES[name]
+        for msg, p_x in zip(MSGS, expected):
+            assert hash_to_curve(msg, dst(curve), curve)[3][0] == p_x, name
+
+    for name in sys.argv[1:] or [n for n in CURVES if n not in RFC9380_P_X]:
+        curve = CURVES[name]
+        p, n = curve["p"], curve["n"]
+        size = (p.bit_length() + 7) // 8
+        z = curve["z"] - p if curve["z"] > p // 2 else curve["z"]
+        hex_of = lambda x: 'hex!("%s")' % x.to_bytes(size, "big").hex()
+
+        print(f"// {name}: Z = {z}")
+        print(f'b"{dst(curve).decode()}",')
+        scalar = hash_to_field(b"abc", 1, dst(curve), n, curve["L"], curve["H"])[0]
+        print(hex_of(scalar) + ",")
+        print("[")
+        for msg in MSGS:
+            u, q0, q1, pt = hash_to_curve(msg, dst(curve), curve)
+            print("    {")
+            print(f'        msg: b"{msg.decode()}",')
+            for key, value in [
+                ("p_x", pt[0]),
+                ("p_y", pt[1]),
+                ("u_0", u[0]),
+                ("u_1", u[1]),
+                ("q0_x", q0[0]),
+                ("q0_y", q0[1]),
+                ("q1_x", q1[0]),
+                ("q1_y", q1[1]),
+            ]:
+                print(f"        {key}: {hex_of(value)},")
+            print("    },")
+        print("]")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,125 @@
+//! Precomputed basepoint tables for fixed-base scalar multiplication.
+
+use crate::{AffinePoint, Double, PrimeCurveParams, ProjectivePoint};
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    Scalar,
+};
+
+/// Precomputed multiples of the generator `G` of a curve, used to compute
+/// `k * G` with a radix-16 signed-digit comb and no doublings (apart from a
+/// final four).
+///
+/// The table consists of `N` rows, where row `i` contains the affine points
+/// `[1, 2, ..., 8] * 256^i * G`. Each row serves two radix-16 digits of the
+/// scalar, which halves the size of the table at the cost of four doublings.
+///
+/// `N` must be chosen such that the bit length of the curve order is at most
+/// `8 * N - 4`, e.g. `N = 33` for a 256-bit curve or `N = 66` for P-521.
+///
+/// Curve crates are expected to compute the table lazily into a `static` and
+/// override [`PrimeCurveParams::mul_by_generator`] to use it.
+#[derive(Clone, Debug)]
+pub struct BasepointTable<C: PrimeCurveParams, const N: usize> {
+    rows: [[AffinePoint<C>; 8]; N],
+}
+
+impl<C, const N: usize> BasepointTable<C, N>
+where
+    C: PrimeCurveParams,
+{
+    /// Compute the table for the curve's generator.
+    pub fn new() -> Self {
+        let mut rows = [[AffinePoint::IDENTITY; 8]; N];
+        let mut base = ProjectivePoint::<C>::GENERATOR;
+
+        for row in rows.iter_mut() {
+            let mut point = base;
+
+            for entry in row.iter_mut() {
+                *entry = point.to_affine();
+                point += &base;
+            }
+
+            // Rows are spaced by two radix-16 steps
+            for _ in 0..8 {
+                base = base.double();
+            }
+        }
+
+        Self { rows }
+    }
+
+    /// Compute `k * G` in constant time.
+    pub fn mul(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
+        let k = Into::<C::Uint>::into(*k).to_le_byte_array();
+        debug_assert!(k.iter().skip(N).all(|&byte| byte == 0));
+
+        // Accumulators for the even and odd radix-16 digits respectively
+        let mut even = ProjectivePoint::IDENTITY;
+        let mut odd = ProjectivePoint::IDENTITY;
+        let mut carry = 0i8;
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let byte = k.get(i).copied().unwrap_or_default();
+            let (lo, c) = recenter((byte & 0xf) as i8 + carry);
+            let (hi, c) = recenter((byte >> 4) as i8 + c);
+            carry = c;
+
+            even += select(row, lo);
+            odd += select(row, hi);
+        }
+
+        debug_assert_eq!(carry, 0);
+
+        for _ in 0..4 {
+            odd = odd.double();
+        }
+
+        even + odd
+    }
+}
+
+impl<C, const N: usize> Default for BasepointTable<C, N>
+where
+    C: PrimeCurveParams,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Recenter a radix-16 digit `0 <= x <= 16` into `-8 <= digit < 8`, returning
+/// the digit and the carry into the next position.
+#[inline(always)]
+fn recenter(x: i8) -> (i8, i8) {
+    let carry = (x + 8) >> 4;
+    (x - (carry << 4), carry)
+}
+
+/// Given `-8 <= x <= 8`, returns `x * P` in constant time, where `row`
+/// contains `[P, 2P, ..., 8P]`.
+#[inline(always)]
+fn select<C: PrimeCurveParams>(row: &[AffinePoint<C>; 8], x: i8) -> AffinePoint<C> {
+    debug_assert!(x >= -8);
+    debug_assert!(x <= 8);
+
+    // Compute xabs = |x|
+    let xmask = x >> 7;
+    let xabs = (x + xmask) ^ xmask;
+
+    // Get an array element in constant time
+    let mut t = AffinePoint::IDENTITY;
+    for (j, entry) in (1u8..).zip(row.iter()) {
+        let c = (xabs as u8).ct_eq(&j);
+        t.conditional_assign(entry, c);
+    }
+    // Now t == |x| * P.
+
+    let neg_mask = Choice::from((xmask & 1) as u8);
+    t.conditional_assign(&-t, neg_mask);
+    // Now t == x * P.
+
+    t
+}
@@ -2,6 +2,36 @@
 
 // TODO(tarcieri): move all development-related macros into this module
 
+use elliptic_curve::rand_core::{self, CryptoRng, RngCore};
+
+/// RNG which always outputs the same bytes, for reproducing test vectors
+/// with fixed random inputs, such as an ephemeral scalar `k`.
+///
+/// Every request for random bytes must be exactly as long as the given
+/// bytes.
+pub struct FixedRng<'a>(pub &'a [u8]);
+
+impl RngCore for FixedRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.copy_from_slice(self.0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for FixedRng<'_> {}
+
 /// Implement projective arithmetic tests.
 #[macro_export]
 macro_rules! impl_projective_arithmetic_tests {
@@ -153,5 +183,256 @@ macro_rules! impl_projective_arithmetic_tests {
                 assert_point_eq!(p, coords);
             }
         }
+
+        #[test]
+        fn test_vector_mul_by_generator() {
+            use $crate::elliptic_curve::ops::MulByGenerator;
+
+            for (k, coords) in $add_vectors
+                .iter()
+                .enumerate()
+                .map(|(k, coords)| (<$scalar>::from(k as u64 + 1), *coords))
+                .chain($mul_vectors.iter().cloned().map(|(k, x, y)| {
+                    (
+                        <$scalar>::from_repr(
+                            $crate::generic_array::GenericArray::clone_from_slice(&k),
+                        )
+                        .unwrap(),
+                        (x, y),
+                    )
+                }))
+            {
+                let p = $projective::mul_by_generator(&k);
+                assert_point_eq!(p, coords);
+            }
+
+            let minus_one = -<$scalar as $crate::Field>::ONE;
+            assert_eq!(
+                $projective::mul_by_generator(&minus_one),
+                -$projective::GENERATOR
+            );
+        }
+
+        #[test]
+        fn test_vector_lincomb() {
+            use $crate::elliptic_curve::ops::LinearCombination;
+
+            let x = $projective::GENERATOR;
+            let y = x.double() + &x;
+            let scalars = $mul_vectors
+                .iter()
+                .map(|(k, _, _)| {
+                    <$scalar>::from_repr($crate::generic_array::GenericArray::clone_from_slice(k))
+                        .unwrap()
+                })
+                .chain([<$scalar>::from(0u64), -<$scalar>::from(1u64)]);
+
+            for (k, l) in scalars.clone().zip(scalars.skip(1)) {
+                let expected = x * &k + y * &l;
+                assert_eq!($projective::lincomb(&x, &k, &y, &l), expected);
+                assert_eq!($projective::lincomb_vartime(&x, &k, &y, &l), expected);
+                assert_eq!(x.mul_vartime(&k), x * &k);
+            }
+
+            let zero = <$scalar>::from(0u64);
+            assert_eq!(
+                $projective::lincomb_vartime(&x, &zero, &y, &zero),
+                $projective::IDENTITY
+            );
+        }
+    };
+}
+
+/// Implement ECDSA batch verification tests.
+///
+/// Expects `verify_batch`, `SignatureWithR`, `SigningKey` and `VerifyingKey`
+/// for the given curve to be in scope.
+#[macro_export]
+macro_rules! impl_ecdsa_batch_tests {
+    ($curve:path) => {
+        extern crate alloc;
+
+        use alloc::vec::Vec;
+        use ecdsa_core::{
+            hazmat::DigestPrimitive,
+            signature::digest::{Digest, Output},
+        };
+        use elliptic_curve::rand_core::OsRng;
+
+        type D = <$curve as DigestPrimitive>::Digest;
+
+        fn prehashes() -> [Output<D>; 3] {
+            [b"first", b"other", b"third"].map(D::digest)
+        }
+
+        fn sign_batch<'a>(
+            prehashes: &'a [impl AsRef<[u8]>],
+        ) -> Vec<(VerifyingKey, &'a [u8], SignatureWithR)> {
+            prehashes
+                .iter()
+                .map(|prehash| {
+                    let signing_key = SigningKey::random(&mut OsRng);
+                    let (signature, recovery_id) = signing_key
+                        .sign_prehash_recoverable(prehash.as_ref())
+                        .unwrap();
+                    let signature = SignatureWithR::new(signature, recovery_id).unwrap();
+                    (*signing_key.verifying_key(), prehash.as_ref(), signature)
+                })
+                .collect()
+        }
+
+        #[test]
+        fn valid() {
+            let prehashes = prehashes();
+            let items = sign_batch(&prehashes);
+            assert!(verify_batch(&mut OsRng, &items).is_ok());
+            assert!(verify_batch(&mut OsRng, &items[..1]).is_ok());
+            assert!(verify_batch::<$curve>(&mut OsRng, &[]).is_ok());
+        }
+
+        #[test]
+        fn wrong_prehash() {
+            let prehashes = prehashes();
+            let mut items = sign_batch(&prehashes);
+            items[1].1 = &prehashes[0];
+            assert!(verify_batch(&mut OsRng, &items).is_err());
+        }
+
+        #[test]
+        fn wrong_verifying_key() {
+            let prehashes = prehashes();
+            let mut items = sign_batch(&prehashes);
+            items[2].0 = items[0].0;
+            assert!(verify_batch(&mut OsRng, &items).is_err());
+        }
+    };
+}
+
+/// Implement hash-to-curve tests.
+///
+/// Each test vector is checked at every step of `hash_to_curve` as laid out
+/// in the RFC 9380 test vectors: the field elements `u`, the mapped points
+/// `Q0` and `Q1`, and the final point `P`. `hash_to_scalar` is checked
+/// against the expected scalar for the message `abc`.
+///
+/// For suites without published test vectors, the vectors can be generated
+/// with `hash2curve_vectors.py` in this crate's source directory. It is a
+/// direct transcription of the RFC 9380 pseudocode, including `find_z_sswu`
+/// for choosing `Z`, and checks itself against the P-256 and P-384 vectors of
+/// RFC 9380 Appendix J before printing anything.
+#[macro_export]
+macro_rules! impl_hash2curve_tests {
+    (
+        $curve:ty,
+        $field_element:ty,
+        $scalar:ty,
+        $hash:ty,
+        $dst:expr,
+        $hash_to_scalar:expr,
+        [$({
+            msg: $msg:expr,
+            p_x: $p_x:expr,
+            p_y: $p_y:expr,
+            u_0: $u_0:expr,
+            u_1: $u_1:expr,
+            q0_x: $q0_x:expr,
+            q0_y: $q0_y:expr,
+            q1_x: $q1_x:expr,
+            q1_y: $q1_y:expr $(,)?
+        }),+ $(,)?]
+    ) => {
+        const DST: &[u8] = $dst;
+
+        #[test]
+        fn hash_to_curve() {
+            use elliptic_curve::{
+                group::cofactor::CofactorGroup,
+                hash2curve::{self, ExpandMsgXmd, GroupDigest, MapToCurve},
+                sec1::{self, ToEncodedPoint},
+                PrimeField,
+            };
+
+            struct TestVector {
+                msg: &'static [u8],
+                p_x: &'static [u8],
+                p_y: &'static [u8],
+                u_0: &'static [u8],
+                u_1: &'static [u8],
+                q0_x: &'static [u8],
+                q0_y: &'static [u8],
+                q1_x: &'static [u8],
+                q1_y: &'static [u8],
+            }
+
+            const TEST_VECTORS: &[TestVector] = &[$(
+                TestVector {
+                    msg: $msg,
+                    p_x: &$p_x,
+                    p_y: &$p_y,
+                    u_0: &$u_0,
+                    u_1: &$u_1,
+                    q0_x: &$q0_x,
+                    q0_y: &$q0_y,
+                    q1_x: &$q1_x,
+                    q1_y: &$q1_y,
+                },
+            )+];
+
+            /// Assert that the provided projective point matches the given test vector.
+            // TODO(tarcieri): use coordinate APIs. See zkcrypto/group#30
+            macro_rules! assert_point_eq {
+                ($actual:expr, $expected_x:expr, $expected_y:expr) => {
+                    let point = $actual.to_affine().to_encoded_point(false);
+                    let (actual_x, actual_y) = match point.coordinates() {
+                        sec1::Coordinates::Uncompressed { x, y } => (x, y),
+                        _ => unreachable!(),
+                    };
+
+                    assert_eq!($expected_x, actual_x.as_slice());
+                    assert_eq!($expected_y, actual_y.as_slice());
+                };
+            }
+
+            for test_vector in TEST_VECTORS {
+                // in parts
+                let mut u = [<$field_element>::default(), <$field_element>::default()];
+                hash2curve::hash_to_field::<ExpandMsgXmd<$hash>, $field_element>(
+                    &[test_vector.msg],
+                    &[DST],
+                    &mut u,
+                )
+                .expect("hash_to_field failed");
+
+                assert_eq!(u[0].to_repr().as_slice(), test_vector.u_0);
+                assert_eq!(u[1].to_repr().as_slice(), test_vector.u_1);
+
+                let q0 = u[0].map_to_curve();
+                assert_point_eq!(q0, test_vector.q0_x, test_vector.q0_y);
+
+                let q1 = u[1].map_to_curve();
+                assert_point_eq!(q1, test_vector.q1_x, test_vector.q1_y);
+
+                let p = q0.clear_cofactor() + q1.clear_cofactor();
+                assert_point_eq!(p, test_vector.p_x, test_vector.p_y);
+
+                // complete run
+                let pt = <$curve>::hash_from_bytes::<ExpandMsgXmd<$hash>>(&[test_vector.msg], &[DST])
+                    .expect("hash_from_bytes failed");
+                assert_point_eq!(pt, test_vector.p_x, test_vector.p_y);
+            }
+        }
+
+        #[test]
+        fn hash_to_scalar() {
+            use elliptic_curve::{
+                hash2curve::{ExpandMsgXmd, GroupDigest},
+                PrimeField,
+            };
+
+            let scalar = <$curve>::hash_to_scalar::<ExpandMsgXmd<$hash>>(&[b"abc"], &[DST])
+                .expect("hash_to_scalar failed");
+            let expected: <$scalar as PrimeField>::Repr = $hash_to_scalar.into();
+            assert_eq!(scalar, <$scalar>::from_repr(expected).unwrap());
+        }
     };
 }
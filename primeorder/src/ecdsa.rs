@@ -0,0 +1,335 @@
+//! ECDSA support shared by curves implemented using this crate.
+
+use crate::{PrimeCurveParams, ProjectivePoint};
+use ecdsa_core::{Error, RecoveryId, Result, Signature, SignatureSize};
+use elliptic_curve::{
+    bigint::CheckedAdd,
+    generic_array::ArrayLength,
+    ops::{Invert, Reduce},
+    point::{AffineCoordinates, DecompressPoint},
+    AffinePoint, FieldBytes, FieldBytesEncoding, PrimeField, Scalar,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::vec::Vec,
+    ecdsa_core::{hazmat::bits2field, VerifyingKey},
+    elliptic_curve::{group::Group, rand_core::CryptoRngCore, Field, PublicKey},
+};
+
+#[cfg(any(feature = "alloc", feature = "rfc6979"))]
+use elliptic_curve::NonZeroScalar;
+
+#[cfg(feature = "rfc6979")]
+use {
+    ecdsa_core::{
+        hazmat::SignPrimitive,
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutputReset},
+    },
+    elliptic_curve::generic_array::typenum::Unsigned,
+    rfc6979::HmacDrbg,
+};
+
+/// Verify the prehashed message digest `z` against the public key `q` using
+/// variable-time scalar multiplication.
+///
+/// Verification only involves public inputs, so this is a drop-in body for
+/// [`VerifyPrimitive::verify_prehashed`][ecdsa_core::hazmat::VerifyPrimitive].
+pub fn verify_prehashed_vartime<C>(
+    q: &AffinePoint<C>,
+    z: &FieldBytes<C>,
+    sig: &Signature<C>,
+) -> Result<()>
+where
+    C: PrimeCurveParams,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let z = Scalar::<C>::reduce_bytes(z);
+    let (r, s) = sig.split_scalars();
+    let s_inv = *s.invert_vartime();
+    let u1 = z * s_inv;
+    let u2 = *r * s_inv;
+    let x = ProjectivePoint::lincomb_vartime(
+        &ProjectivePoint::<C>::GENERATOR,
+        &u1,
+        &ProjectivePoint::from(*q),
+        &u2,
+    )
+    .to_affine()
+    .x();
+
+    if *r == Scalar::<C>::reduce_bytes(&x) {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Sign the prehashed message digest `z` with the secret scalar `x`, using an
+/// ephemeral scalar `k` computed deterministically with HMAC-`D` as described
+/// in [RFC6979 § 3.2].
+///
+/// Unlike `SignPrimitive::try_sign_prehashed_rfc6979`, the output size of
+/// `D` needn't match the field size, and each `HMAC_DRBG` candidate is
+/// truncated to the bit length of the curve order (`bits2int`), so this also
+/// handles curves whose order isn't a whole number of bytes, like P-521.
+///
+/// `z` must already be truncated to the field size, e.g. using
+/// [`hazmat::bits2field`][ecdsa_core::hazmat::bits2field]. `ad` is optional
+/// additional data, e.g. output from a CSRNG for hedged signatures.
+///
+/// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+#[cfg(feature = "rfc6979")]
+pub fn sign_prehashed_rfc6979<C, D>(
+    x: &Scalar<C>,
+    z: &FieldBytes<C>,
+    ad: &[u8],
+) -> Result<(Signature<C>, Option<RecoveryId>)>
+where
+    C: PrimeCurveParams,
+    D: Digest + BlockSizeUser + FixedOutputReset,
+    Scalar<C>: SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let shift = C::FieldBytesSize::USIZE * 8 - Scalar::<C>::NUM_BITS as usize;
+    let h = Scalar::<C>::reduce_bytes(z).to_repr();
+    let mut hmac_drbg = HmacDrbg::<D>::new(&x.to_repr(), &h, ad);
+
+    loop {
+        let mut bytes = FieldBytes::<C>::default();
+        hmac_drbg.fill_bytes(&mut bytes);
+        let k = C::Uint::decode_field_bytes(&bytes) >> shift;
+
+        if let Some(k) = Option::<NonZeroScalar<C>>::from(NonZeroScalar::from_uint(k)) {
+            return x.try_sign_prehashed(*k, z);
+        }
+    }
+}
+
+/// ECDSA signature along with its ephemeral point `R` (a.k.a. ECDSA*).
+///
+/// ECDSA signatures only contain the x-coordinate of `R` reduced modulo the
+/// curve order, which isn't enough to check several signatures at once.
+/// `R` can be reconstructed from `r` using the [`RecoveryId`] returned when
+/// signing with e.g. `SigningKey::sign_prehash_recoverable`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignatureWithR<C>
+where
+    C: PrimeCurveParams,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    signature: Signature<C>,
+    r_point: AffinePoint<C>,
+}
+
+impl<C> SignatureWithR<C>
+where
+    C: PrimeCurveParams,
+    FieldBytes<C>: Copy,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Reconstruct the point `R` of `signature` using `recovery_id`.
+    pub fn new(signature: Signature<C>, recovery_id: RecoveryId) -> Result<Self> {
+        let mut x = signature.r().to_repr();
+
+        if recovery_id.is_x_reduced() {
+            let x_reduced = C::Uint::decode_field_bytes(&x);
+            let x_full =
+                Option::<C::Uint>::from(x_reduced.checked_add(&C::ORDER)).ok_or_else(Error::new)?;
+            x = x_full.encode_field_bytes();
+        }
+
+        Option::from(AffinePoint::<C>::decompress(
+            &x,
+            u8::from(recovery_id.is_y_odd()).into(),
+        ))
+        .map(|r_point| Self { signature, r_point })
+        .ok_or_else(Error::new)
+    }
+
+    /// Borrow the ECDSA signature.
+    pub fn signature(&self) -> &Signature<C> {
+        &self.signature
+    }
+
+    /// Borrow the ephemeral point `R`.
+    pub fn r_point(&self) -> &AffinePoint<C> {
+        &self.r_point
+    }
+}
+
+impl<C> Copy for SignatureWithR<C>
+where
+    C: PrimeCurveParams,
+    Signature<C>: Copy,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+}
+
+impl<C> From<SignatureWithR<C>> for Signature<C>
+where
+    C: PrimeCurveParams,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: SignatureWithR<C>) -> Signature<C> {
+        signature.signature
+    }
+}
+
+/// Verify a batch of `(verifying key, prehash, signature)` triples.
+///
+/// Prehashes are converted to scalars the same way as in
+/// [`PrehashVerifier::verify_prehash`][ecdsa_core::signature::hazmat::PrehashVerifier::verify_prehash].
+///
+/// Rather than checking each signature individually, this samples random
+/// weights `a_i` from `rng` and checks
+/// `sum(a_i * (z_i * G + r_i * Q_i - s_i * R_i)) == 0` using a single
+/// variable-time multi-scalar multiplication. Returns an [`Error`] if any of
+/// the signatures is invalid, without indicating which one.
+#[cfg(feature = "alloc")]
+pub fn verify_batch<C>(
+    rng: &mut impl CryptoRngCore,
+    items: &[(VerifyingKey<C>, &[u8], SignatureWithR<C>)],
+) -> Result<()>
+where
+    C: PrimeCurveParams,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let mut terms = Vec::with_capacity(2 * items.len() + 1);
+    let mut g_scalar = Scalar::<C>::ZERO;
+
+    for (verifying_key, prehash, signature) in items {
+        let z = Scalar::<C>::reduce_bytes(&bits2field::<C>(prehash)?);
+        let (r, s) = signature.signature.split_scalars();
+        let a = *NonZeroScalar::<C>::random(&mut *rng);
+
+        g_scalar += a * z;
+        terms.push((PublicKey::from(verifying_key).to_projective(), a * *r));
+        terms.push((ProjectivePoint::from(signature.r_point), -(a * *s)));
+    }
+
+    terms.push((ProjectivePoint::GENERATOR, g_scalar));
+
+    if ProjectivePoint::multiscalar_mul_vartime(&terms)
+        .is_identity()
+        .into()
+    {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Implement ECDSA for a curve using this crate's arithmetic, whose scalar
+/// field is `$scalar`.
+///
+/// This defines the `SigningKey` and `VerifyingKey` types and implements:
+///
+/// - [`SignPrimitive`][ecdsa_core::hazmat::SignPrimitive] using
+///   [`sign_prehashed_rfc6979`], which requires the `rfc6979` feature.
+/// - [`VerifyPrimitive`][ecdsa_core::hazmat::VerifyPrimitive] using
+///   [`verify_prehashed_vartime`].
+///
+/// It also generates signing tests, and tests against the Wycheproof vectors
+/// in the given blobby file if a path is provided.
+///
+/// Expects `ecdsa_core` to be in scope, along with the curve's
+/// `Signature` type and `DigestPrimitive` impl, plus `blobby` and
+/// `rand_core` in tests.
+#[macro_export]
+macro_rules! impl_ecdsa {
+    ($curve:path, $scalar:ty $(, wycheproof: $wycheproof:literal)?) => {
+        #[doc = concat!("ECDSA/", stringify!($curve), " signing key")]
+        pub type SigningKey = ecdsa_core::SigningKey<$curve>;
+
+        #[doc = concat!("ECDSA/", stringify!($curve), " verification key (i.e. public key)")]
+        pub type VerifyingKey = ecdsa_core::VerifyingKey<$curve>;
+
+        impl ecdsa_core::hazmat::SignPrimitive<$curve> for $scalar {
+            /// Sign using a deterministic ephemeral scalar (`k`) computed as
+            /// described in RFC 6979.
+            ///
+            /// Unlike the provided method, this reduces `z` modulo the curve
+            /// order before deriving `k` as RFC 6979's `bits2octets` requires.
+            fn try_sign_prehashed_rfc6979<D>(
+                &self,
+                z: &$crate::elliptic_curve::FieldBytes<$curve>,
+                ad: &[u8],
+            ) -> ecdsa_core::Result<(
+                ecdsa_core::Signature<$curve>,
+                Option<ecdsa_core::RecoveryId>,
+            )>
+            where
+                Self: From<$crate::elliptic_curve::ScalarPrimitive<$curve>>
+                    + $crate::elliptic_curve::ops::Invert<
+                        Output = $crate::elliptic_curve::subtle::CtOption<Self>,
+                    >,
+                D: ecdsa_core::signature::digest::Digest
+                    + ecdsa_core::signature::digest::core_api::BlockSizeUser
+                    + ecdsa_core::signature::digest::FixedOutput<
+                        OutputSize = $crate::elliptic_curve::FieldBytesSize<$curve>,
+                    > + ecdsa_core::signature::digest::FixedOutputReset,
+            {
+                $crate::ecdsa::sign_prehashed_rfc6979::<$curve, D>(self, z, ad)
+            }
+        }
+
+        /// Verification only involves public inputs, so it's performed using
+        /// variable-time scalar multiplication.
+        impl ecdsa_core::hazmat::VerifyPrimitive<$curve> for $crate::AffinePoint<$curve> {
+            fn verify_prehashed(
+                &self,
+                z: &$crate::elliptic_curve::FieldBytes<$curve>,
+                sig: &ecdsa_core::Signature<$curve>,
+            ) -> ecdsa_core::Result<()> {
+                $crate::ecdsa::verify_prehashed_vartime(self, z, sig)
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::{Signature, SigningKey, VerifyingKey};
+            use ecdsa_core::signature::{Signer, Verifier};
+            use rand_core::OsRng;
+
+            const MSG: &[u8] = b"ECDSA proves knowledge of a secret number";
+
+            #[test]
+            fn sign_and_verify() {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let verifying_key = VerifyingKey::from(&signing_key);
+                let signature: Signature = signing_key.sign(MSG);
+
+                assert!(verifying_key.verify(MSG, &signature).is_ok());
+                assert!(verifying_key.verify(&MSG[1..], &signature).is_err());
+            }
+
+            #[test]
+            fn deterministic_signature() {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let signature: Signature = signing_key.sign(MSG);
+                assert_eq!(signature, signing_key.sign(MSG));
+            }
+
+            $(
+                #[test]
+                fn wycheproof() {
+                    use blobby::Blob5Iterator;
+
+                    let data = include_bytes!($wycheproof);
+
+                    for (i, row) in Blob5Iterator::new(data).unwrap().enumerate() {
+                        let [x, y, msg, sig, pass] = row.unwrap();
+                        let point = [&[0x04], x, y].concat();
+                        let verifying_key = VerifyingKey::from_sec1_bytes(&point).unwrap();
+                        let valid = Signature::from_der(sig)
+                            .and_then(|sig| verifying_key.verify(msg, &sig))
+                            .is_ok();
+
+                        assert_eq!(valid, pass == [1], "Wycheproof test case {}", i + 1);
+                    }
+                }
+            )?
+        }
+    };
+}
@@ -558,6 +558,145 @@ macro_rules! impl_bernstein_yang_invert {
     }};
 }
 
+/// Implement the limb arithmetic shared by Solinas reduction field backends.
+///
+/// Field elements are little endian arrays of `$nlimbs` 64-bit limbs in
+/// canonical form. `$modulus` is the field modulus in the same representation.
+///
+/// # Functions
+/// - `const fn mac_row`: accumulate one row of a schoolbook product
+/// - `const fn sub_modulus_if_needed`: constant-time final subtraction
+#[macro_export]
+macro_rules! impl_solinas_limb_arithmetic {
+    ($nlimbs:expr, $modulus:expr) => {
+        /// Accumulate the products `a[i] * b[j]` into `w[i + j]` for `j >= j_start`.
+        ///
+        /// Callers invoke this once per row rather than looping so that the whole
+        /// product is compiled to straight-line code.
+        #[inline(always)]
+        const fn mac_row(
+            mut w: [u64; 2 * $nlimbs],
+            a: &[u64; $nlimbs],
+            i: usize,
+            b: &[u64; $nlimbs],
+            j_start: usize,
+        ) -> [u64; 2 * $nlimbs] {
+            let mut carry = 0;
+            let mut j = j_start;
+
+            while j < $nlimbs {
+                let t = (a[i] as u128) * (b[j] as u128) + (w[i + j] as u128) + carry;
+                w[i + j] = t as u64;
+                carry = t >> 64;
+                j += 1;
+            }
+
+            w[i + $nlimbs] = carry as u64;
+            w
+        }
+
+        /// Subtract the modulus from `a` if `a >= p` in constant time.
+        ///
+        /// `a` must be less than `2p`.
+        #[inline(always)]
+        const fn sub_modulus_if_needed(a: &[u64; $nlimbs]) -> [u64; $nlimbs] {
+            let mut diff = [0u64; $nlimbs];
+            let mut borrow = 0;
+            let mut i = 0;
+
+            while i < $nlimbs {
+                let (d, b1) = a[i].overflowing_sub($modulus[i]);
+                let (d, b2) = d.overflowing_sub(borrow);
+                diff[i] = d;
+                borrow = (b1 | b2) as u64;
+                i += 1;
+            }
+
+            // `mask` is all ones if `a < p`
+            let mask = borrow.wrapping_neg();
+            let mut ret = [0u64; $nlimbs];
+            i = 0;
+
+            while i < $nlimbs {
+                ret[i] = (a[i] & mask) | (diff[i] & !mask);
+                i += 1;
+            }
+
+            ret
+        }
+    };
+}
+
+/// Implement tests checking a Solinas reduction field backend against the
+/// fiat-crypto one it replaces.
+///
+/// `$field_element` is a `proptest` strategy for reduced field elements, and
+/// requires `proptest` to be available to the calling crate.
+///
+/// # Functions
+/// - `fn fiat_mul`
+/// - `fn fiat_square`
+/// - `fn is_reduced`
+#[macro_export]
+macro_rules! impl_solinas_tests {
+    (
+        $nlimbs:expr,
+        $modulus:expr,
+        $one:expr,
+        $field_element:ident,
+        $fiat_to_mont:ident,
+        $fiat_from_mont:ident,
+        $fiat_mul:ident,
+        $fiat_square:ident,
+        $fiat_sub:ident,
+        $mul:ident,
+        $square:ident,
+    ) => {
+        fn fiat_mul(a: &[u64; $nlimbs], b: &[u64; $nlimbs]) -> [u64; $nlimbs] {
+            $fiat_from_mont(&$fiat_mul(&$fiat_to_mont(a), &$fiat_to_mont(b)))
+        }
+
+        fn fiat_square(a: &[u64; $nlimbs]) -> [u64; $nlimbs] {
+            $fiat_from_mont(&$fiat_square(&$fiat_to_mont(a)))
+        }
+
+        /// Is `a` less than the modulus?
+        fn is_reduced(a: &[u64; $nlimbs]) -> bool {
+            a.iter().rev().cmp($modulus.iter().rev()).is_lt()
+        }
+
+        #[test]
+        fn mul_edge_cases() {
+            let p_minus_one = $fiat_sub(&[0; $nlimbs], &$one);
+            let p_minus_two = $fiat_sub(&p_minus_one, &$one);
+
+            for a in [[0; $nlimbs], $one, p_minus_one, p_minus_two] {
+                for b in [[0; $nlimbs], $one, p_minus_one, p_minus_two] {
+                    assert_eq!($mul(&a, &b), fiat_mul(&a, &b));
+                }
+
+                assert_eq!($square(&a), fiat_square(&a));
+            }
+        }
+
+        proptest::proptest! {
+            #[test]
+            fn mul_matches_fiat(a in $field_element(), b in $field_element()) {
+                let c = $mul(&a, &b);
+                assert!(is_reduced(&c));
+                assert_eq!(c, fiat_mul(&a, &b));
+            }
+
+            #[test]
+            fn square_matches_fiat(a in $field_element()) {
+                let c = $square(&a);
+                assert!(is_reduced(&c));
+                assert_eq!(c, fiat_square(&a));
+            }
+        }
+    };
+}
+
 /// Implement field element identity tests.
 #[macro_export]
 macro_rules! impl_field_identity_tests {
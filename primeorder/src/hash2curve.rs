@@ -0,0 +1,138 @@
+//! Hash-to-curve support using the simplified Shallue-van de Woestijne-Ulas
+//! (SSWU) map described in [RFC 9380].
+//!
+//! The functions in this module are generic over [`PrimeCurveParams`] and are
+//! intended to be wired up to the [`elliptic_curve::hash2curve`] traits using
+//! the [`impl_hash2curve!`](crate::impl_hash2curve) macro. Curve crates only
+//! need to supply an [`OsswuMap`] impl containing the constants for their
+//! curve and chosen `Z` parameter.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use crate::{AffinePoint, PrimeCurveParams, ProjectivePoint};
+use elliptic_curve::{
+    hash2curve::OsswuMap,
+    subtle::{Choice, ConditionallySelectable},
+    Field,
+};
+
+/// Interpret a big endian byte string as an integer and reduce it modulo the
+/// field's characteristic, as required by `hash_to_field` in
+/// [RFC 9380 § 5.2].
+///
+/// This is intended for implementing [`FromOkm`] for both base field elements
+/// and scalars, and only relies on field arithmetic, so it works regardless of
+/// the internal representation or serialization byte order of `F`.
+///
+/// [RFC 9380 § 5.2]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2
+/// [`FromOkm`]: elliptic_curve::hash2curve::FromOkm
+pub fn from_okm<F>(data: &[u8]) -> F
+where
+    F: Field + From<u64>,
+{
+    let two_64 = F::from(u64::MAX) + F::ONE;
+    let (head, tail) = data.split_at(data.len() % 8);
+
+    let mut word = [0u8; 8];
+    word[(8 - head.len())..].copy_from_slice(head);
+    let mut acc = F::from(u64::from_be_bytes(word));
+
+    for chunk in tail.chunks_exact(8) {
+        word.copy_from_slice(chunk);
+        acc = acc * two_64 + F::from(u64::from_be_bytes(word));
+    }
+
+    acc
+}
+
+/// Map a field element to a curve point using the simplified SWU map
+/// described in [RFC 9380 § 6.6.2].
+///
+/// Only applicable to curves whose `a` and `b` coefficients are both non-zero,
+/// where [`OsswuMap`] yields a point on the curve itself rather than on an
+/// isogenous curve.
+///
+/// [RFC 9380 § 6.6.2]: https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2
+pub fn map_to_curve<C>(u: &C::FieldElement) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+    C::FieldElement: OsswuMap,
+{
+    let (x, y) = u.osswu();
+    AffinePoint { x, y, infinity: 0 }.into()
+}
+
+/// `sqrt_ratio` for fields of any characteristic, as described in
+/// [RFC 9380 § F.2.1].
+///
+/// Returns `(true, sqrt(u / v))` if `u / v` is square, or
+/// `(false, sqrt(Z * u / v))` otherwise.
+///
+/// [`OsswuMap::sqrt_ratio_3mod4`] only supports fields where `q = 3 (mod 4)`.
+/// Fields where this doesn't hold can override it with this function, which is
+/// built on [`Field::sqrt_ratio`] and ignores the `c1` and `c2` parameters.
+///
+/// [RFC 9380 § F.2.1]: https://www.rfc-editor.org/rfc/rfc9380.html#appendix-F.2.1
+pub fn sqrt_ratio<F>(u: F, v: F) -> (Choice, F)
+where
+    F: OsswuMap + ConditionallySelectable,
+{
+    let (is_square, y1) = F::sqrt_ratio(&u, &v);
+    let (_, y2) = F::sqrt_ratio(&(F::PARAMS.z * u), &v);
+    (is_square, F::conditional_select(&y2, &y1, is_square))
+}
+
+/// Implement the [`elliptic_curve::hash2curve`] traits for a curve whose base
+/// field element type implements [`OsswuMap`].
+///
+/// Arguments:
+/// - `$curve`: curve type, which must impl [`PrimeCurveParams`]
+/// - `$fe`: base field element type
+/// - `$scalar`: scalar type
+/// - `$len`: `typenum` length `L` of the uniform bytes used to derive each
+///   field element or scalar, i.e. `ceil((ceil(log2(p)) + k) / 8)` where `k`
+///   is the target security level.
+///
+/// # Trait impls
+/// - `GroupDigest` for `$curve`
+/// - `FromOkm` for `$fe` and `$scalar`
+/// - `MapToCurve` for `$fe`
+/// - `Sgn0` for `$fe`
+#[macro_export]
+macro_rules! impl_hash2curve {
+    ($curve:ty, $fe:ty, $scalar:ty, $len:ty) => {
+        impl $crate::elliptic_curve::hash2curve::GroupDigest for $curve {
+            type FieldElement = $fe;
+        }
+
+        impl $crate::elliptic_curve::hash2curve::FromOkm for $fe {
+            type Length = $len;
+
+            fn from_okm(data: &$crate::generic_array::GenericArray<u8, Self::Length>) -> Self {
+                $crate::hash2curve::from_okm(data)
+            }
+        }
+
+        impl $crate::elliptic_curve::hash2curve::FromOkm for $scalar {
+            type Length = $len;
+
+            fn from_okm(data: &$crate::generic_array::GenericArray<u8, Self::Length>) -> Self {
+                $crate::hash2curve::from_okm(data)
+            }
+        }
+
+        impl $crate::elliptic_curve::hash2curve::Sgn0 for $fe {
+            fn sgn0(&self) -> $crate::elliptic_curve::subtle::Choice {
+                <$fe as $crate::PrimeField>::is_odd(self)
+            }
+        }
+
+        impl $crate::elliptic_curve::hash2curve::MapToCurve for $fe {
+            type Output = $crate::ProjectivePoint<$curve>;
+
+            fn map_to_curve(&self) -> Self::Output {
+                $crate::hash2curve::map_to_curve::<$curve>(self)
+            }
+        }
+    };
+}
@@ -8,20 +8,32 @@
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "hash2curve")]
+pub mod hash2curve;
+pub mod ops;
 pub mod point_arithmetic;
 
 mod affine;
+mod basepoint_table;
 #[cfg(feature = "dev")]
-mod dev;
+pub mod dev;
 mod field;
+mod lincomb;
 mod projective;
 
-pub use crate::{affine::AffinePoint, projective::ProjectivePoint};
+pub use crate::{
+    affine::AffinePoint, basepoint_table::BasepointTable, projective::ProjectivePoint,
+};
 pub use elliptic_curve::{
     self, generic_array, point::Double, Field, FieldBytes, PrimeCurve, PrimeField,
 };
 
-use elliptic_curve::CurveArithmetic;
+use elliptic_curve::{CurveArithmetic, Scalar};
 
 /// Parameters for elliptic curves of prime order which can be described by the
 /// short Weierstrass equation.
@@ -45,4 +57,12 @@ pub trait PrimeCurveParams:
 
     /// Generator point's affine coordinates: (x, y).
     const GENERATOR: (Self::FieldElement, Self::FieldElement);
+
+    /// Compute `k * G`, where `G` is the generator.
+    ///
+    /// The default implementation uses variable-base scalar multiplication.
+    /// Curves can override it to use a precomputed [`BasepointTable`].
+    fn mul_by_generator(k: &Scalar<Self>) -> ProjectivePoint<Self> {
+        ProjectivePoint::GENERATOR * k
+    }
 }
@@ -0,0 +1,250 @@
+//! Linear combinations of points, a.k.a. multi-scalar multiplication.
+//!
+//! Uses Straus' method (a.k.a. Shamir's trick): every point gets its own
+//! window table, and all scalars are processed together so the doublings are
+//! shared between them. The constant-time implementation uses fixed 4-bit
+//! windows, while the variable-time one uses signed digits in width-5 NAF.
+
+use crate::{Double, PrimeCurveParams, ProjectivePoint};
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use elliptic_curve::{
+    bigint::{ArrayEncoding, Integer, U576},
+    subtle::{ConditionallySelectable, ConstantTimeEq},
+    Scalar,
+};
+
+/// Lookup table containing precomputed values `[0, p, 2p, ..., 15p]`.
+#[derive(Clone, Copy)]
+struct LookupTable<C: PrimeCurveParams>([ProjectivePoint<C>; 16]);
+
+impl<C> From<&ProjectivePoint<C>> for LookupTable<C>
+where
+    C: PrimeCurveParams,
+{
+    fn from(p: &ProjectivePoint<C>) -> Self {
+        let mut points = [ProjectivePoint::IDENTITY; 16];
+        points[1] = *p;
+
+        for i in 2..16 {
+            points[i] = if i % 2 == 0 {
+                Double::double(&points[i / 2])
+            } else {
+                points[i - 1].add(p)
+            };
+        }
+
+        Self(points)
+    }
+}
+
+impl<C> LookupTable<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Given `0 <= x < 16`, returns `x * p` in constant time.
+    fn select(&self, x: u8) -> ProjectivePoint<C> {
+        debug_assert!(x < 16);
+
+        let mut t = ProjectivePoint::IDENTITY;
+        for (j, point) in (1u8..).zip(self.0.iter().skip(1)) {
+            t.conditional_assign(point, x.ct_eq(&j));
+        }
+        t
+    }
+}
+
+/// Calculates the linear combination `sum(xs[i] * ks[i])`, `i = 0..N`, in
+/// constant time.
+pub(crate) fn lincomb<C, const N: usize>(
+    xs: &[ProjectivePoint<C>; N],
+    ks: &[Scalar<C>; N],
+) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    let tables = xs.map(|x| LookupTable::from(&x));
+    let ks = ks.map(|k| Into::<C::Uint>::into(k).to_le_byte_array());
+
+    let mut q = ProjectivePoint::IDENTITY;
+    let mut pos = C::Uint::BITS - 4;
+
+    loop {
+        for (table, k) in tables.iter().zip(ks.iter()) {
+            q += table.select(nibble(k, pos));
+        }
+
+        if pos == 0 {
+            break;
+        }
+
+        q = q.double().double().double().double();
+        pos -= 4;
+    }
+
+    q
+}
+
+/// Calculates the linear combination `sum(xs[i] * ks[i])`, `i = 0..N`, in
+/// variable time.
+///
+/// Scalars are recoded into width-5 NAFs and table entries are accessed
+/// directly, so this must only be used with public inputs.
+pub(crate) fn lincomb_vartime<C, const N: usize>(
+    xs: &[ProjectivePoint<C>; N],
+    ks: &[Scalar<C>; N],
+) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    let tables = xs.map(|x| OddMultiples::from(&x));
+    let nafs = ks.map(|k| wnaf::<C>(&k));
+    wnaf_sum(&tables, &nafs)
+}
+
+/// Calculates the linear combination `sum(x * k)` over all `(x, k)` terms in
+/// variable time.
+///
+/// Like [`lincomb_vartime`], but for an arbitrary number of terms.
+#[cfg(feature = "alloc")]
+pub(crate) fn lincomb_slice_vartime<C>(
+    terms: &[(ProjectivePoint<C>, Scalar<C>)],
+) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    let tables = terms
+        .iter()
+        .map(|(x, _)| OddMultiples::from(x))
+        .collect::<Vec<_>>();
+    let nafs = terms.iter().map(|(_, k)| wnaf::<C>(k)).collect::<Vec<_>>();
+    wnaf_sum(&tables, &nafs)
+}
+
+/// Evaluates `sum(nafs[i] * p[i])`, where `tables[i]` contains the odd
+/// multiples of `p[i]`.
+fn wnaf_sum<C>(tables: &[OddMultiples<C>], nafs: &[[i8; WNAF_MAX_LEN]]) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    let top = match nafs
+        .iter()
+        .filter_map(|naf| naf.iter().rposition(|&d| d != 0))
+        .max()
+    {
+        Some(top) => top,
+        None => return ProjectivePoint::IDENTITY,
+    };
+
+    let mut q = ProjectivePoint::IDENTITY;
+
+    for i in (0..=top).rev() {
+        q = q.double();
+
+        for (table, naf) in tables.iter().zip(nafs.iter()) {
+            match naf[i].cmp(&0) {
+                Ordering::Greater => q += table.get(naf[i]),
+                Ordering::Less => q -= table.get(-naf[i]),
+                Ordering::Equal => (),
+            }
+        }
+    }
+
+    q
+}
+
+/// Width of the windows used by the non-adjacent form of scalars.
+const WNAF_WIDTH: usize = 5;
+
+/// Maximum length of a width-[`WNAF_WIDTH`] NAF.
+///
+/// This is large enough for scalars of any curve whose `Uint` is at most
+/// 576 bits wide, e.g. P-521.
+const WNAF_MAX_LEN: usize = U576::BITS + WNAF_WIDTH;
+
+/// Lookup table containing precomputed odd multiples `[p, 3p, 5p, ..., 15p]`.
+struct OddMultiples<C: PrimeCurveParams>([ProjectivePoint<C>; 1 << (WNAF_WIDTH - 2)]);
+
+impl<C> From<&ProjectivePoint<C>> for OddMultiples<C>
+where
+    C: PrimeCurveParams,
+{
+    fn from(p: &ProjectivePoint<C>) -> Self {
+        let p2 = Double::double(p);
+        let mut points = [*p; 1 << (WNAF_WIDTH - 2)];
+
+        for i in 1..points.len() {
+            points[i] = points[i - 1].add(&p2);
+        }
+
+        Self(points)
+    }
+}
+
+impl<C> OddMultiples<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Given an odd `0 < x < 2^(w-1)`, returns `x * p` in variable time.
+    fn get(&self, x: i8) -> &ProjectivePoint<C> {
+        debug_assert!(x > 0 && x & 1 == 1);
+        &self.0[(x / 2) as usize]
+    }
+}
+
+/// Compute the width-[`WNAF_WIDTH`] non-adjacent form of `k`.
+///
+/// Every nonzero digit is odd and lies in `(-2^(w-1), 2^(w-1))`, and any two
+/// nonzero digits are separated by at least `w - 1` zeros.
+fn wnaf<C: PrimeCurveParams>(k: &Scalar<C>) -> [i8; WNAF_MAX_LEN] {
+    let k = Into::<C::Uint>::into(*k).to_le_byte_array();
+    let bits = C::Uint::BITS;
+    let width = 1i16 << WNAF_WIDTH;
+    let mut naf = [0i8; WNAF_MAX_LEN];
+    let mut carry = 0i16;
+    let mut pos = 0;
+
+    while pos < bits || carry != 0 {
+        let window = carry + i16::from(bit_window(&k, pos, WNAF_WIDTH));
+
+        if window & 1 == 0 {
+            pos += 1;
+            continue;
+        }
+
+        if window < width / 2 {
+            carry = 0;
+            naf[pos] = window as i8;
+        } else {
+            carry = 1;
+            naf[pos] = (window - width) as i8;
+        }
+
+        pos += WNAF_WIDTH;
+    }
+
+    naf
+}
+
+/// Get the `width`-bit window of the little endian byte encoding `k`
+/// starting at bit position `pos`, treating bits past the end as zero.
+fn bit_window(k: &[u8], pos: usize, width: usize) -> u8 {
+    let mut window = 0;
+
+    for (j, p) in (pos..pos + width).enumerate() {
+        if let Some(byte) = k.get(p >> 3) {
+            window |= ((byte >> (p & 7)) & 1) << j;
+        }
+    }
+
+    window
+}
+
+/// Get the 4-bit window of the little endian byte encoding `k` starting at
+/// bit position `pos`.
+#[inline(always)]
+fn nibble(k: &[u8], pos: usize) -> u8 {
+    (k[pos >> 3] >> (pos & 7)) & 0xf
+}
@@ -0,0 +1,23 @@
+//! Traits for arithmetic operations on elliptic curve points.
+
+use elliptic_curve::group::Group;
+
+/// Variable-time scalar multiplication.
+///
+/// Faster than the constant-time [`Mul`][core::ops::Mul] impl, but it leaks
+/// information about the scalar through timing, so it must only be used when
+/// both the point and the scalar are public, e.g. when verifying signatures.
+pub trait MulVartime: Group {
+    /// Calculates `self * k` in variable time.
+    fn mul_vartime(&self, k: &Self::Scalar) -> Self;
+}
+
+/// Variable-time linear combination of two points.
+///
+/// Variable-time counterpart of
+/// [`LinearCombination`][elliptic_curve::ops::LinearCombination], subject to
+/// the same caveats as [`MulVartime`].
+pub trait LinearCombinationVartime: Group {
+    /// Calculates `x * k + y * l` in variable time.
+    fn lincomb_vartime(x: &Self, k: &Self::Scalar, y: &Self, l: &Self::Scalar) -> Self;
+}
@@ -2,14 +2,18 @@
 
 #![allow(clippy::needless_range_loop, clippy::op_ref)]
 
-use crate::{point_arithmetic::PointArithmetic, AffinePoint, Field, PrimeCurveParams};
+use crate::{
+    lincomb,
+    ops::{LinearCombinationVartime, MulVartime},
+    point_arithmetic::PointArithmetic,
+    AffinePoint, Field, PrimeCurveParams,
+};
 use core::{
     borrow::Borrow,
     iter::Sum,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{ArrayEncoding, Integer},
     generic_array::ArrayLength,
     group::{
         self,
@@ -101,46 +105,34 @@ where
     where
         Self: Double,
     {
-        let k = Into::<C::Uint>::into(*k).to_le_byte_array();
-
-        let mut pc = [Self::default(); 16];
-        pc[0] = Self::IDENTITY;
-        pc[1] = *self;
-
-        for i in 2..16 {
-            pc[i] = if i % 2 == 0 {
-                Double::double(&pc[i / 2])
-            } else {
-                pc[i - 1].add(self)
-            };
-        }
-
-        let mut q = Self::IDENTITY;
-        let mut pos = C::Uint::BITS - 4;
-
-        loop {
-            let slot = (k[pos >> 3] >> (pos & 7)) & 0xf;
-
-            let mut t = ProjectivePoint::IDENTITY;
-
-            for i in 1..16 {
-                t.conditional_assign(
-                    &pc[i],
-                    Choice::from(((slot as usize ^ i).wrapping_sub(1) >> 8) as u8 & 1),
-                );
-            }
-
-            q = q.add(&t);
+        lincomb::lincomb(&[*self], &[*k])
+    }
 
-            if pos == 0 {
-                break;
-            }
+    /// Returns `[k] self`, computed in variable time.
+    ///
+    /// This is faster than the constant-time [`Mul`] impl, but it must only be
+    /// used when both the point and the scalar are public.
+    pub fn mul_vartime(&self, k: &Scalar<C>) -> Self {
+        lincomb::lincomb_vartime(&[*self], &[*k])
+    }
 
-            q = Double::double(&Double::double(&Double::double(&Double::double(&q))));
-            pos -= 4;
-        }
+    /// Calculates `x * k + y * l` in variable time.
+    ///
+    /// This is faster than [`LinearCombination::lincomb`], but it must only
+    /// be used when all of the inputs are public, e.g. when verifying
+    /// signatures.
+    pub fn lincomb_vartime(x: &Self, k: &Scalar<C>, y: &Self, l: &Scalar<C>) -> Self {
+        lincomb::lincomb_vartime(&[*x, *y], &[*k, *l])
+    }
 
-        q
+    /// Calculates the linear combination `sum(x * k)` over all `(x, k)` pairs
+    /// in `terms` in variable time, a.k.a. multi-scalar multiplication.
+    ///
+    /// Like [`ProjectivePoint::lincomb_vartime`], this must only be used when
+    /// all of the inputs are public.
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_mul_vartime(terms: &[(Self, Scalar<C>)]) -> Self {
+        lincomb::lincomb_slice_vartime(terms)
     }
 }
 
@@ -333,6 +325,19 @@ where
     Self: Double,
     C: PrimeCurveParams,
 {
+    fn lincomb(x: &Self, k: &Self::Scalar, y: &Self, l: &Self::Scalar) -> Self {
+        lincomb::lincomb(&[*x, *y], &[*k, *l])
+    }
+}
+
+impl<C> LinearCombinationVartime for ProjectivePoint<C>
+where
+    Self: Double,
+    C: PrimeCurveParams,
+{
+    fn lincomb_vartime(x: &Self, k: &Self::Scalar, y: &Self, l: &Self::Scalar) -> Self {
+        ProjectivePoint::lincomb_vartime(x, k, y, l)
+    }
 }
 
 impl<C> MulByGenerator for ProjectivePoint<C>
@@ -341,8 +346,17 @@ where
     C: PrimeCurveParams,
 {
     fn mul_by_generator(scalar: &Self::Scalar) -> Self {
-        // TODO(tarcieri): precomputed basepoint tables
-        Self::generator() * scalar
+        C::mul_by_generator(scalar)
+    }
+}
+
+impl<C> MulVartime for ProjectivePoint<C>
+where
+    Self: Double,
+    C: PrimeCurveParams,
+{
+    fn mul_vartime(&self, k: &Self::Scalar) -> Self {
+        ProjectivePoint::mul_vartime(self, k)
     }
 }
 
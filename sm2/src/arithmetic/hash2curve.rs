@@ -0,0 +1,103 @@
+//! Hash-to-curve support for SM2 using the `SM2_XMD:SM3_SSWU_RO_`
+//! suite, following [RFC 9380].
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use super::{FieldElement, Scalar};
+use crate::Sm2;
+use elliptic_curve::{
+    consts::U48,
+    hash2curve::{OsswuMap, OsswuMapParams},
+};
+use primeorder::PrimeCurveParams;
+
+primeorder::impl_hash2curve!(Sm2, FieldElement, Scalar, U48);
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        c1: &[
+            0x3fff_ffff_ffff_ffff,
+            0xffff_ffff_c000_0000,
+            0xffff_ffff_ffff_ffff,
+            0x3fff_ffff_bfff_ffff,
+        ],
+        c2: FieldElement::from_u64(3),
+        map_a: Sm2::EQUATION_A,
+        map_b: Sm2::EQUATION_B,
+        z: FieldElement::from_u64(9).neg(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, Scalar};
+    use crate::Sm2;
+    use hex_literal::hex;
+    use sm3::Sm3;
+
+    primeorder::impl_hash2curve_tests!(
+        Sm2,
+        FieldElement,
+        Scalar,
+        Sm3,
+        b"QUUX-V01-CS02-with-SM2_XMD:SM3_SSWU_RO_",
+        hex!("1f1a75a88ee216ee268a3e8964711c6c05ee99e0dd8ee2608dc2b53953836fb3"),
+        [
+            {
+                msg: b"",
+                p_x: hex!("80048bf6454de460598966bc3bc9a3213e8776668817d85cf447eda370991a41"),
+                p_y: hex!("cf41fd9fa681d1416ddb5129e570bef4d74c4e0c1a5be8009717eb1c02e8e9e9"),
+                u_0: hex!("e3a0077d70dc77e0e2d9ecf81723c2faa0b4db94a3ad5daab62e503b9f40f1b9"),
+                u_1: hex!("912e9c547ba989938905b91ec9035f95699a4402586255c4d2d21287637c72a9"),
+                q0_x: hex!("b844819a027c73a6fee5e080d9ff186c4a265f3e5621592583984fa3066bd5d3"),
+                q0_y: hex!("afa9cf89b56da454bf050ad24a96b03097d45dc0754a05d59c21f51abccb518f"),
+                q1_x: hex!("c85088b311868784c628f45b66cde744ad0786981f88b3373e4266ecb525e2b5"),
+                q1_y: hex!("4fa1b5503f186026adb6527dd4cd19d3e88611eaea87552aba106a8c77b74495"),
+            },
+            {
+                msg: b"abc",
+                p_x: hex!("7cf8871dffcb584997d9b27cbc1b12308eec4544f38688f7b8c53531afb9fdcd"),
+                p_y: hex!("e803123cc855d859d58857cbea53c0cf0187b160e3a4996a9260879a1b059203"),
+                u_0: hex!("8355d61dd83760ef45f02ede22b81f81f03280de19017d1913bf1498ee44465c"),
+                u_1: hex!("9bfd2b47ee3dedade769b309dd5d9edebc182eaef68639e0dd7b2221ba50dcd6"),
+                q0_x: hex!("379b0fa2cbb6bca68beb1c258c50d684116f79533d73a2e93f7f63f0460144f9"),
+                q0_y: hex!("c32d50d59166b8417e91f3ba3fd9cc7669394a120992b41f19f2467055064a94"),
+                q1_x: hex!("46d5849cdabc5fa55e347a23a0496872001cf34da2b3f5b67a733a256e93f398"),
+                q1_y: hex!("7355e1f04bd28301299af1bcfb7c854a3d8d3e5019fd2f4ec0177c47da57b6c6"),
+            },
+            {
+                msg: b"abcdef0123456789",
+                p_x: hex!("9fbfac2f80e2492165c664f1329a2e8391d39ec33e6c7a57c0e582d17e533c0e"),
+                p_y: hex!("733e1148256a3fcb971b89789755fd8e8c292b7e82a67ab38c46a827b6cacc0b"),
+                u_0: hex!("495417ebeb10ceaec666bbe8c08baa01fe8d334af5a1542e3a77bf5271732d1e"),
+                u_1: hex!("9d085a1c7ebf33f241fdbaa7fcde77ef8721db40cb28bf779a5eb940adfd1be6"),
+                q0_x: hex!("4d9162cd2b0948b2a5bbd06068c5ead5f75fcd651cc476e627582d1af2066f35"),
+                q0_y: hex!("260ebe313f65cd9abd36f5c07b0e0bb39cfbef7562b313774226885844829e58"),
+                q1_x: hex!("2958a893cde20994ed37cd051adfeade9703301350f7ec159bea43254ee2d197"),
+                q1_y: hex!("ce9318d1a7262b264d298b27d240416658e5ddfb2684e50272e5c8700ae366d4"),
+            },
+            {
+                msg: b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+                p_x: hex!("7eccdb5a62d795ff497c6f24ba10049945a384df187717667deddeea465cd927"),
+                p_y: hex!("b869d3c923de3afccad7e09b27fda5b3f4732a1bc44eee62532b6f90ba524ea5"),
+                u_0: hex!("5598f2776f20cad386d46395ea47adbddf255bbea09c65ffd29de1bad05fefb9"),
+                u_1: hex!("8826689c4a546b2d0456b960cd81b2dea15684f4119586be4f447bb5ab27c2d9"),
+                q0_x: hex!("7493e27e38423c435d26ec0d467c4509e1a375213c129ca1ae39c5f9358418b9"),
+                q0_y: hex!("20b80dd700543bed0e82b1296a476b37f758841f6341b04e13b3c24f9a59fe53"),
+                q1_x: hex!("dfd33df6e982825e972960407924543a2927a15a15909b6e2882e08f8d89c7c9"),
+                q1_y: hex!("36b6bdfc79795d3e1f5595e8374b6bbb0a69c434364f72abde9ffc4f8b9eef9f"),
+            },
+            {
+                msg: b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                p_x: hex!("ac24c8657b4e116c8b5a92136d41947839e5a61fdab3ac1529d2fbd9b9959691"),
+                p_y: hex!("db6c91d5b977aecaec71956df70547369a63af795066d6c223c836bb5b70389a"),
+                u_0: hex!("434e61e6b72e7bc5cfdf539a2ffb6a9c2825da30198340d2262d7eae2226ed5c"),
+                u_1: hex!("f8f58966cee6bda313b77fa3d2f20375847175c3ceab278118ce3209563abe5e"),
+                q0_x: hex!("3133f40e4063f3af38b137651152374d5e3675191bb66ad6ed2f612fff8fd1c9"),
+                q0_y: hex!("691965470ed2674316776fc640c7cd32f20bcac3d398551dad7313936f4c0092"),
+                q1_x: hex!("47305bdbe32bb16b3a6ba4c9a5f2fc87e28ce84d05a13fe043356af5d0ba8b63"),
+                q1_y: hex!("181a862f37b925c4b9e77a769956cfd7369dd26a8052250936cea63a8336b5be"),
+            },
+        ]
+    );
+}
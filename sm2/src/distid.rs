@@ -1,4 +1,4 @@
-//! Distinguished identifier support.
+//! Distinguishing identifier support.
 
 use crate::{AffinePoint, Hash, Sm2};
 use elliptic_curve::{
@@ -9,7 +9,14 @@ use primeorder::PrimeCurveParams;
 use sm3::{Digest, Sm3};
 
 /// Type which represents distinguishing identifiers.
-pub(crate) type DistId = str;
+///
+/// Distinguishing identifiers are arbitrary byte strings of at most 8191 bytes,
+/// i.e. their length in bits must fit in the 16-bit `ENTLA` field.
+pub type DistId = [u8];
+
+/// Default distinguishing identifier `1234567812345678`, as specified in
+/// GM/T 0009-2012 for use when no other identifier has been agreed upon.
+pub const DEFAULT_DISTID: &DistId = b"1234567812345678";
 
 /// Compute user information hash `Z` according to [draft-shen-sm2-ecdsa § 5.1.4.4].
 ///
@@ -17,8 +24,16 @@ pub(crate) type DistId = str;
 /// ZA=H256(ENTLA || IDA || a || b || xG || yG || xA || yA)
 /// ```
 ///
+/// This is the value which is prepended to a message before hashing it, and
+/// can be used to compute a message digest incrementally, e.g. for use with
+/// `RandomizedDigestSigner`.
+///
+/// Returns an error if the distinguishing identifier is longer than 8191
+/// bytes.
+///
 /// [draft-shen-sm2-ecdsa § 5.1.4.4]: https://datatracker.ietf.org/doc/html/draft-shen-sm2-ecdsa-02#section-5.1.4.4
-pub(crate) fn hash_z(distid: &DistId, public_key: &impl AsRef<AffinePoint>) -> Result<Hash> {
+pub fn hash_z(distid: impl AsRef<DistId>, public_key: &impl AsRef<AffinePoint>) -> Result<Hash> {
+    let distid = distid.as_ref();
     let entla: u16 = distid
         .len()
         .checked_mul(8)
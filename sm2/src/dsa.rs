@@ -31,13 +31,38 @@
 //! # }
 //! ```
 //!
+//! ## Distinguishing identifiers
+//!
+//! Keys are bound to a distinguishing identifier, which can be any byte
+//! string of at most 8191 bytes, such as a `&str`, a certificate serial number
+//! or a device identifier. When no identifier has been agreed upon,
+//! [`DEFAULT_DISTID`](crate::DEFAULT_DISTID) should be used.
+//!
+//! The user information hash `Z` derived from the identifier and public key
+//! can be computed with [`hash_z`](crate::hash_z).
+//!
+//! ## Signature encodings and nonces
+//!
+//! [`Signature`] is the fixed-size `r || s` encoding. The ASN.1 DER encoding
+//! specified in GM/T 0009-2012 and used in X.509 certificates is provided by
+//! [`DerSignature`], which can be converted to and from [`Signature`].
+//!
+//! [`SigningKey`] derives the ephemeral scalar `k` deterministically using
+//! RFC6979 by default. Where a random `k` as described in GB/T 32918.2 is
+//! required, use the [`signature::RandomizedSigner`] impls instead.
+//!
 //! [draft-shen-sm2-ecdsa § 5]: https://datatracker.ietf.org/doc/html/draft-shen-sm2-ecdsa-02#section-5
 
+#[cfg(feature = "arithmetic")]
+pub mod cosign;
+
+mod der;
 #[cfg(feature = "arithmetic")]
 mod signing;
 #[cfg(feature = "arithmetic")]
 mod verifying;
 
+pub use self::der::DerSignature;
 pub use signature;
 
 #[cfg(feature = "arithmetic")]
@@ -0,0 +1,406 @@
+//! Two-party cooperative SM2DSA signing.
+//!
+//! The secret key `d` is split multiplicatively between a client and a
+//! server holding the key shares `d1` and `d2`, such that
+//! `(1+d)^-1 = d1*d2 modn`. Neither party ever learns `d`, but together they
+//! produce ordinary SM2DSA signatures which verify under the public key
+//! `P = [(d1*d2)^-1]G - G` using [`VerifyingKey`].
+//!
+//! Key generation:
+//!
+//! 1. Each party generates its key share ([`ClientKeyShare::random`],
+//!    [`ServerKeyShare::random`]) and sends its public share `[d1^-1]G` or
+//!    `[d2^-1]G` to the other party.
+//! 2. Each party derives the joint public key `P` from its key share and the
+//!    peer's public share ([`ClientKeyShare::public_key`],
+//!    [`ServerKeyShare::public_key`]).
+//!
+//! Signing:
+//!
+//! 1. The client hashes the message, and sends the message hash `e` along
+//!    with its commitment `Q1=[k1]G` to the server ([`ClientKeyShare::sign`]).
+//! 2. The server computes `r` and its partial signatures `s2` and `s3`, and
+//!    sends them to the client ([`ServerKeyShare::respond`]).
+//! 3. The client assembles and verifies the final signature
+//!    ([`ClientSigner::finish`]).
+//!
+//! ## Usage
+//!
+//! NOTE: requires the `dsa` crate feature enabled, and `rand_core` dependency
+//! with `getrandom` feature enabled.
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use rand_core::OsRng; // requires 'getrandom` feature
+//! use sm2::dsa::{
+//!     cosign::{ClientKeyShare, ServerKeyShare},
+//!     signature::Verifier,
+//!     VerifyingKey,
+//! };
+//!
+//! // Key generation
+//! let client_share = ClientKeyShare::random(&mut OsRng);
+//! let server_share = ServerKeyShare::random(&mut OsRng);
+//! let public_key = client_share.public_key(server_share.public_share())?;
+//! assert_eq!(public_key, server_share.public_key(client_share.public_share())?);
+//!
+//! let distid = "example@rustcrypto.org"; // distinguishing identifier
+//! let verifying_key = VerifyingKey::new(distid, public_key)?;
+//!
+//! // Client sends `e` and `Q1` to the server
+//! let message = b"test message";
+//! let client = client_share.sign(&mut OsRng, &verifying_key, message);
+//!
+//! // Server sends `r`, `s2` and `s3` to the client
+//! let response = server_share.respond(&mut OsRng, client.prehash(), client.commitment())?;
+//!
+//! // Client assembles the signature
+//! let signature = client.finish(&response)?;
+//! verifying_key.verify(message, &signature)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{signing::prehash_to_scalar, Signature, VerifyingKey};
+use crate::{FieldBytes, Hash, NonZeroScalar, ProjectivePoint, PublicKey, Scalar};
+use core::fmt::{self, Debug};
+use elliptic_curve::{
+    ops::{Invert, LinearCombination, Reduce},
+    point::AffineCoordinates,
+    rand_core::CryptoRngCore,
+    zeroize::{Zeroize, ZeroizeOnDrop},
+    Group, PrimeField,
+};
+use signature::{hazmat::PrehashVerifier, Error, Result};
+
+/// Client's share `d1` of a secret key split between two parties.
+///
+/// The client initiates signing, and assembles the final signature.
+#[derive(Clone)]
+pub struct ClientKeyShare {
+    /// Secret key share `d1`.
+    secret_scalar: NonZeroScalar,
+
+    /// Public share `[d1^-1]G`.
+    public_share: PublicKey,
+}
+
+impl ClientKeyShare {
+    /// Generate a random client key share.
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::from_nonzero_scalar(NonZeroScalar::random(rng))
+    }
+
+    /// Create a client key share from the non-zero scalar `d1`.
+    pub fn from_nonzero_scalar(secret_scalar: NonZeroScalar) -> Self {
+        Self {
+            secret_scalar,
+            public_share: public_share(&secret_scalar),
+        }
+    }
+
+    /// Get the public share `[d1^-1]G`, which is sent to the server.
+    pub fn public_share(&self) -> &PublicKey {
+        &self.public_share
+    }
+
+    /// Derive the joint public key `P = [d1^-1]([d2^-1]G) - G` from the
+    /// server's public share.
+    pub fn public_key(&self, peer_public_share: &PublicKey) -> Result<PublicKey> {
+        public_key(&self.secret_scalar, peer_public_share)
+    }
+
+    /// Start signing the given message, which is hashed along with the
+    /// user information hash `Z` of the given joint verifying key.
+    ///
+    /// The message hash `e` and commitment `Q1` of the returned
+    /// [`ClientSigner`] are sent to the server.
+    pub fn sign(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        verifying_key: &VerifyingKey,
+        msg: &[u8],
+    ) -> ClientSigner {
+        // C1: calculate e=Hv(ZA || M)
+        let prehash = verifying_key.hash_msg(msg);
+
+        // C2: pick a random number k1 in [1, n-1] and calculate Q1=[k1]G
+        let ephemeral_scalar = NonZeroScalar::random(rng);
+        let commitment = PublicKey::from_secret_scalar(&ephemeral_scalar);
+
+        ClientSigner {
+            secret_scalar: self.secret_scalar,
+            ephemeral_scalar,
+            commitment,
+            prehash,
+            verifying_key: verifying_key.clone(),
+        }
+    }
+}
+
+impl Debug for ClientKeyShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientKeyShare")
+            .field("public_share", &self.public_share)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ClientKeyShare {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ClientKeyShare {}
+
+/// Client side of a single cooperative signing operation.
+///
+/// ## Algorithm
+///
+/// ```text
+/// C1: calculate e=Hv(ZA || M)
+/// C2: pick a random number k1 in [1, n-1] and calculate Q1=[k1]G
+/// C3: send e and Q1 to the server
+/// C4: calculate s=(d1*k1*s2 + d1*s3 - r) modn, error if s=0 or s+r=0
+/// C5: verify that (r, s) is a valid signature of M
+/// ```
+pub struct ClientSigner {
+    /// Secret key share `d1`.
+    secret_scalar: NonZeroScalar,
+
+    /// Ephemeral secret `k1`.
+    ephemeral_scalar: NonZeroScalar,
+
+    /// Commitment `Q1=[k1]G`.
+    commitment: PublicKey,
+
+    /// Message hash `e`.
+    prehash: Hash,
+
+    /// Joint verifying key.
+    verifying_key: VerifyingKey,
+}
+
+impl ClientSigner {
+    /// Get the message hash `e`, which is sent to the server.
+    pub fn prehash(&self) -> &Hash {
+        &self.prehash
+    }
+
+    /// Get the commitment `Q1=[k1]G`, which is sent to the server.
+    pub fn commitment(&self) -> &PublicKey {
+        &self.commitment
+    }
+
+    /// Assemble the final signature from the server's response, checking
+    /// that it is valid under the joint verifying key.
+    pub fn finish(self, response: &ServerResponse) -> Result<Signature> {
+        // C4: calculate s=(d1*k1*s2 + d1*s3 - r) modn
+        let d1 = *self.secret_scalar;
+        let s = d1 * *self.ephemeral_scalar * response.s2 + d1 * response.s3 - response.r;
+
+        // s+r=0 implies r+k=n for the joint nonce k=k1*k3+k2
+        if bool::from(s.is_zero() | (s + response.r).is_zero()) {
+            return Err(Error::new());
+        }
+
+        // C5: verify that (r, s) is a valid signature of M
+        let signature = Signature::from_scalars(response.r, s)?;
+        self.verifying_key
+            .verify_prehash(&self.prehash, &signature)?;
+
+        Ok(signature)
+    }
+}
+
+impl Debug for ClientSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientSigner")
+            .field("commitment", &self.commitment)
+            .field("prehash", &self.prehash)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ClientSigner {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+        self.ephemeral_scalar.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ClientSigner {}
+
+/// Server's share `d2` of a secret key split between two parties.
+///
+/// ## Algorithm
+///
+/// ```text
+/// S1: verify that Q1 is a point on the curve
+/// S2: pick random numbers k2, k3 in [1, n-1] and calculate Q2=[k2]G
+/// S3: calculate the elliptic curve point (x1, y1)=[k3]Q1+Q2
+/// S4: calculate r=(e+x1) modn, return to S2 if r=0
+/// S5: calculate s2=(d2*k3) modn and s3=(d2*(r+k2)) modn
+/// S6: send r, s2 and s3 to the client
+/// ```
+#[derive(Clone)]
+pub struct ServerKeyShare {
+    /// Secret key share `d2`.
+    secret_scalar: NonZeroScalar,
+
+    /// Public share `[d2^-1]G`.
+    public_share: PublicKey,
+}
+
+impl ServerKeyShare {
+    /// Generate a random server key share.
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::from_nonzero_scalar(NonZeroScalar::random(rng))
+    }
+
+    /// Create a server key share from the non-zero scalar `d2`.
+    pub fn from_nonzero_scalar(secret_scalar: NonZeroScalar) -> Self {
+        Self {
+            secret_scalar,
+            public_share: public_share(&secret_scalar),
+        }
+    }
+
+    /// Get the public share `[d2^-1]G`, which is sent to the client.
+    pub fn public_share(&self) -> &PublicKey {
+        &self.public_share
+    }
+
+    /// Derive the joint public key `P = [d2^-1]([d1^-1]G) - G` from the
+    /// client's public share.
+    pub fn public_key(&self, peer_public_share: &PublicKey) -> Result<PublicKey> {
+        public_key(&self.secret_scalar, peer_public_share)
+    }
+
+    /// Respond to the client's signing request consisting of the message
+    /// hash `e` and commitment `Q1`.
+    pub fn respond(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+        commitment: &PublicKey,
+    ) -> Result<ServerResponse> {
+        let e = prehash_to_scalar(prehash)?;
+
+        // S1: `PublicKey` is guaranteed to be a valid point other than the
+        // point at infinity
+        let commitment = commitment.to_projective();
+
+        loop {
+            // S2: pick random numbers k2, k3 in [1, n-1] and calculate Q2=[k2]G
+            let mut k2 = NonZeroScalar::random(&mut *rng);
+            let mut k3 = NonZeroScalar::random(&mut *rng);
+
+            // S3: calculate the elliptic curve point (x1, y1)=[k3]Q1+Q2
+            let x1 = ProjectivePoint::lincomb(&commitment, &k3, &ProjectivePoint::generator(), &k2)
+                .to_affine()
+                .x();
+
+            // S4: calculate r=(e+x1) modn, return to S2 if r=0
+            let r = e + Scalar::reduce_bytes(&x1);
+
+            // S5: calculate s2=(d2*k3) modn and s3=(d2*(r+k2)) modn
+            let response = ServerResponse {
+                r,
+                s2: *self.secret_scalar * *k3,
+                s3: *self.secret_scalar * (r + *k2),
+            };
+
+            k2.zeroize();
+            k3.zeroize();
+
+            if !bool::from(r.is_zero()) {
+                return Ok(response);
+            }
+        }
+    }
+}
+
+impl Debug for ServerKeyShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServerKeyShare")
+            .field("public_share", &self.public_share)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ServerKeyShare {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ServerKeyShare {}
+
+/// Server's response to a signing request, consisting of the signature
+/// component `r` and the partial signatures `s2` and `s3`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ServerResponse {
+    /// Signature component `r`.
+    r: Scalar,
+
+    /// Partial signature `s2`.
+    s2: Scalar,
+
+    /// Partial signature `s3`.
+    s3: Scalar,
+}
+
+impl ServerResponse {
+    /// Size of an encoded server response in bytes.
+    pub const BYTE_SIZE: usize = 96;
+
+    /// Parse a server response from the concatenation `r || s2 || s3`.
+    pub fn from_bytes(bytes: &[u8; Self::BYTE_SIZE]) -> Result<Self> {
+        let (r, rest) = bytes.split_at(Self::BYTE_SIZE / 3);
+        let (s2, s3) = rest.split_at(Self::BYTE_SIZE / 3);
+
+        Ok(Self {
+            r: scalar_from_slice(r)?,
+            s2: scalar_from_slice(s2)?,
+            s3: scalar_from_slice(s3)?,
+        })
+    }
+
+    /// Serialize this server response as `r || s2 || s3`.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut ret = [0; Self::BYTE_SIZE];
+
+        for (chunk, scalar) in ret
+            .chunks_exact_mut(Self::BYTE_SIZE / 3)
+            .zip([self.r, self.s2, self.s3])
+        {
+            chunk.copy_from_slice(&scalar.to_bytes());
+        }
+
+        ret
+    }
+}
+
+/// Compute the public share `[d^-1]G` of a key share `d`.
+fn public_share(secret_scalar: &NonZeroScalar) -> PublicKey {
+    PublicKey::from_secret_scalar(&Invert::invert(secret_scalar))
+}
+
+/// Compute the joint public key `[d^-1]P' - G` from a key share `d` and the
+/// peer's public share `P'`.
+fn public_key(secret_scalar: &NonZeroScalar, peer_public_share: &PublicKey) -> Result<PublicKey> {
+    let point = peer_public_share.to_projective() * *Invert::invert(secret_scalar)
+        - ProjectivePoint::generator();
+
+    // `PublicKey` rejects the point at infinity, i.e. `d1*d2=1` which
+    // corresponds to the secret key `d=0`
+    PublicKey::from_affine(point.to_affine()).map_err(|_| Error::new())
+}
+
+/// Parse a scalar from a big endian-encoded byte slice.
+fn scalar_from_slice(slice: &[u8]) -> Result<Scalar> {
+    Option::from(Scalar::from_repr(*FieldBytes::from_slice(slice))).ok_or_else(Error::new)
+}
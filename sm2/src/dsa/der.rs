@@ -0,0 +1,226 @@
+//! Support for ASN.1 DER-encoded SM2DSA signatures as specified in
+//! GM/T 0009-2012.
+
+use super::Signature;
+use crate::{FieldBytes, Sm2};
+use core::fmt::{self, Debug};
+use der::{
+    asn1::UintRef, Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence,
+    Writer,
+};
+use elliptic_curve::{generic_array::typenum::Unsigned, Curve};
+use signature::{Error, Result};
+
+#[cfg(feature = "alloc")]
+use {alloc::boxed::Box, signature::SignatureEncoding};
+
+/// Byte array containing a serialized ASN.1 DER signature.
+type DerSignatureBytes = [u8; DerSignature::MAX_SIZE];
+
+/// ASN.1 DER-encoded SM2DSA signature as specified in GM/T 0009-2012:
+///
+/// ```text
+/// SM2Signature ::= SEQUENCE {
+///     R INTEGER,
+///     S INTEGER
+/// }
+/// ```
+///
+/// This is the encoding used by X.509 certificates, CMS and most other
+/// protocols which carry SM2DSA signatures.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct DerSignature {
+    /// ASN.1 DER-encoded signature data.
+    bytes: DerSignatureBytes,
+
+    /// Length of the encoded signature.
+    len: usize,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl DerSignature {
+    /// Maximum size of an ASN.1 DER-encoded SM2DSA signature in bytes.
+    ///
+    /// Includes a 2-byte `SEQUENCE` header followed by two `INTEGER` values,
+    /// each with a 2-byte header and an optional leading zero byte.
+    pub const MAX_SIZE: usize = 72;
+
+    /// Parse signature from DER-encoded bytes.
+    ///
+    /// The `r` and `s` components are checked to be in the range `[1, n-1]`.
+    pub fn from_bytes(input: &[u8]) -> Result<Self> {
+        Signature::from_der(input)?;
+
+        let mut bytes = [0u8; DerSignature::MAX_SIZE];
+        bytes
+            .get_mut(..input.len())
+            .ok_or_else(Error::new)?
+            .copy_from_slice(input);
+
+        Ok(Self {
+            bytes,
+            len: input.len(),
+        })
+    }
+
+    /// Borrow this signature as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Serialize this signature as a boxed byte slice.
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self) -> Box<[u8]> {
+        self.as_bytes().to_vec().into_boxed_slice()
+    }
+
+    /// Get the length of the signature in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Signature {
+    /// Parse an SM2DSA signature from ASN.1 DER.
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        let asn1 = Asn1Signature::from_der(bytes).map_err(|_| Error::new())?;
+        Self::from_scalars(uint_to_field_bytes(asn1.r)?, uint_to_field_bytes(asn1.s)?)
+    }
+
+    /// Serialize this signature as ASN.1 DER.
+    pub fn to_der(&self) -> DerSignature {
+        let r = self.r_bytes();
+        let s = self.s_bytes();
+        let mut bytes = [0u8; DerSignature::MAX_SIZE];
+        let len = encode_der(&r, &s, &mut bytes)
+            .expect("SM2DSA signature should fit in DerSignature::MAX_SIZE");
+
+        DerSignature { bytes, len }
+    }
+}
+
+impl AsRef<[u8]> for DerSignature {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Debug for DerSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sm2::dsa::DerSignature(")?;
+
+        for byte in self.as_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl From<Signature> for DerSignature {
+    fn from(signature: Signature) -> DerSignature {
+        signature.to_der()
+    }
+}
+
+impl From<&Signature> for DerSignature {
+    fn from(signature: &Signature) -> DerSignature {
+        signature.to_der()
+    }
+}
+
+impl TryFrom<&[u8]> for DerSignature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<DerSignature> {
+        DerSignature::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<DerSignature> for Signature {
+    type Error = Error;
+
+    fn try_from(signature: DerSignature) -> Result<Signature> {
+        Signature::from_der(signature.as_bytes())
+    }
+}
+
+impl TryFrom<&DerSignature> for Signature {
+    type Error = Error;
+
+    fn try_from(signature: &DerSignature) -> Result<Signature> {
+        Signature::from_der(signature.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<DerSignature> for Box<[u8]> {
+    fn from(signature: DerSignature) -> Box<[u8]> {
+        signature.to_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SignatureEncoding for DerSignature {
+    type Repr = Box<[u8]>;
+
+    fn encoded_len(&self) -> usize {
+        self.len
+    }
+}
+
+/// ASN.1 structure of an SM2DSA signature.
+struct Asn1Signature<'a> {
+    /// `r` component of the signature.
+    r: UintRef<'a>,
+
+    /// `s` component of the signature.
+    s: UintRef<'a>,
+}
+
+impl<'a> DecodeValue<'a> for Asn1Signature<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            Ok(Self {
+                r: reader.decode()?,
+                s: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl EncodeValue for Asn1Signature<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.r.encoded_len()? + self.s.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.r.encode(writer)?;
+        self.s.encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for Asn1Signature<'a> {}
+
+/// Encode `r` and `s` as ASN.1 DER into the provided buffer, returning the
+/// length of the encoded signature.
+fn encode_der(r: &[u8], s: &[u8], buf: &mut DerSignatureBytes) -> der::Result<usize> {
+    let asn1 = Asn1Signature {
+        r: UintRef::new(r)?,
+        s: UintRef::new(s)?,
+    };
+
+    Ok(asn1.encode_to_slice(buf)?.len())
+}
+
+/// Left-pad a DER-decoded unsigned integer to the size of a field element.
+fn uint_to_field_bytes(uint: UintRef<'_>) -> Result<FieldBytes> {
+    let bytes = uint.as_bytes();
+    let offset = <Sm2 as Curve>::FieldBytesSize::USIZE
+        .checked_sub(bytes.len())
+        .ok_or_else(Error::new)?;
+
+    let mut ret = FieldBytes::default();
+    ret[offset..].copy_from_slice(bytes);
+    Ok(ret)
+}
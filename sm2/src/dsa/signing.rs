@@ -14,7 +14,7 @@
 
 #![allow(non_snake_case)]
 
-use super::{Signature, VerifyingKey};
+use super::{DerSignature, Signature, VerifyingKey};
 use crate::{
     DistId, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey, Sm2,
 };
@@ -26,8 +26,12 @@ use elliptic_curve::{
     subtle::{Choice, ConstantTimeEq},
     Curve, FieldBytesEncoding, PrimeField,
 };
-use signature::{hazmat::PrehashSigner, Error, KeypairRef, Result, Signer};
-use sm3::Sm3;
+use signature::{
+    hazmat::{PrehashSigner, RandomizedPrehashSigner},
+    rand_core::CryptoRngCore,
+    Error, KeypairRef, RandomizedDigestSigner, RandomizedSigner, Result, Signer,
+};
+use sm3::{Digest, Sm3};
 
 /// SM2DSA secret key used for signing messages and producing signatures.
 ///
@@ -37,7 +41,17 @@ use sm3::Sm3;
 /// primary API for signing:
 ///
 /// - [`Signer`]: sign a message using this key
+/// - [`RandomizedSigner`]: sign a message using a random `k` as described in
+///   GB/T 32918.2, rather than one derived deterministically via RFC6979
+/// - [`RandomizedDigestSigner`]: sign a message digest over `ZA || M` using a
+///   random `k`
 /// - [`PrehashSigner`]: sign the low-level raw output bytes of a message digest
+/// - [`RandomizedPrehashSigner`]: sign the low-level raw output bytes of a
+///   message digest using a random `k`
+///
+/// The randomized signers can produce either a [`Signature`] or a
+/// [`DerSignature`]. Deterministic signatures can be converted to ASN.1 DER
+/// using [`Signature::to_der`].
 #[derive(Clone)]
 pub struct SigningKey {
     /// Secret key.
@@ -50,24 +64,27 @@ pub struct SigningKey {
 impl SigningKey {
     /// Create signing key from a signer's distinguishing identifier and
     /// secret key.
-    pub fn new(distid: &DistId, secret_key: &SecretKey) -> Result<Self> {
+    pub fn new(distid: impl AsRef<DistId>, secret_key: &SecretKey) -> Result<Self> {
         Self::from_nonzero_scalar(distid, secret_key.to_nonzero_scalar())
     }
 
     /// Parse signing key from big endian-encoded bytes.
-    pub fn from_bytes(distid: &DistId, bytes: &FieldBytes) -> Result<Self> {
+    pub fn from_bytes(distid: impl AsRef<DistId>, bytes: &FieldBytes) -> Result<Self> {
         Self::from_slice(distid, bytes)
     }
 
     /// Parse signing key from big endian-encoded byte slice containing a secret
     /// scalar value.
-    pub fn from_slice(distid: &DistId, slice: &[u8]) -> Result<Self> {
+    pub fn from_slice(distid: impl AsRef<DistId>, slice: &[u8]) -> Result<Self> {
         let secret_scalar = NonZeroScalar::try_from(slice).map_err(|_| Error::new())?;
         Self::from_nonzero_scalar(distid, secret_scalar)
     }
 
     /// Create a signing key from a non-zero scalar.
-    pub fn from_nonzero_scalar(distid: &DistId, secret_scalar: NonZeroScalar) -> Result<Self> {
+    pub fn from_nonzero_scalar(
+        distid: impl AsRef<DistId>,
+        secret_scalar: NonZeroScalar,
+    ) -> Result<Self> {
         let public_key = PublicKey::from_secret_scalar(&secret_scalar);
         let verifying_key = VerifyingKey::new(distid, public_key)?;
         Ok(Self {
@@ -98,10 +115,21 @@ impl SigningKey {
     }
 
     /// Get the distinguishing identifier for this key.
+    ///
+    /// # Panics
+    ///
+    /// If the distinguishing identifier is not valid UTF-8. Use
+    /// [`SigningKey::distid_bytes`] for keys with binary identifiers.
     #[cfg(feature = "alloc")]
-    pub fn distid(&self) -> &DistId {
+    pub fn distid(&self) -> &str {
         self.verifying_key.distid()
     }
+
+    /// Get the distinguishing identifier for this key as a byte string.
+    #[cfg(feature = "alloc")]
+    pub fn distid_bytes(&self) -> &DistId {
+        self.verifying_key.distid_bytes()
+    }
 }
 
 //
@@ -110,12 +138,8 @@ impl SigningKey {
 
 impl PrehashSigner<Signature> for SigningKey {
     fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature> {
-        if prehash.len() != <Sm2 as Curve>::FieldBytesSize::USIZE {
-            return Err(Error::new());
-        }
-
         // A2: calculate e=Hv(M~)
-        let e = Scalar::reduce_bytes(FieldBytes::from_slice(prehash));
+        let e = prehash_to_scalar(prehash)?;
 
         // A3: pick a random number k in [1, n-1] via a random number generator
         let k = Scalar::from_repr(rfc6979::generate_k::<Sm3, _>(
@@ -126,32 +150,122 @@ impl PrehashSigner<Signature> for SigningKey {
         ))
         .unwrap();
 
+        self.sign_with_k(&e, &k)?.ok_or_else(Error::new)
+    }
+}
+
+impl RandomizedPrehashSigner<Signature> for SigningKey {
+    fn sign_prehash_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature> {
+        // A2: calculate e=Hv(M~)
+        let e = prehash_to_scalar(prehash)?;
+
+        loop {
+            // A3: pick a random number k in [1, n-1] via a random number generator
+            let k = NonZeroScalar::random(&mut *rng);
+
+            // A4-A7, returning to A3 if r=0, r+k=n or s=0
+            if let Some(signature) = self.sign_with_k(&e, &k)? {
+                return Ok(signature);
+            }
+        }
+    }
+}
+
+impl RandomizedPrehashSigner<DerSignature> for SigningKey {
+    fn sign_prehash_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<DerSignature> {
+        RandomizedPrehashSigner::<Signature>::sign_prehash_with_rng(self, rng, prehash)
+            .map(Into::into)
+    }
+}
+
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature> {
+        // A1: set M~=ZA || M
+        let hash = self.verifying_key.hash_msg(msg);
+        self.sign_prehash(&hash)
+    }
+}
+
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> Result<Signature> {
+        // A1: set M~=ZA || M
+        let hash = self.verifying_key.hash_msg(msg);
+        self.sign_prehash_with_rng(rng, &hash)
+    }
+}
+
+impl RandomizedSigner<DerSignature> for SigningKey {
+    fn try_sign_with_rng(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> Result<DerSignature> {
+        RandomizedSigner::<Signature>::try_sign_with_rng(self, rng, msg).map(Into::into)
+    }
+}
+
+/// The provided digest must have been computed over `ZA || M`, i.e. it must
+/// have been updated with the signer's user information hash `Z` prior to the
+/// message, which can be computed using [`hash_z`](crate::hash_z).
+impl RandomizedDigestSigner<Sm3, Signature> for SigningKey {
+    fn try_sign_digest_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        digest: Sm3,
+    ) -> Result<Signature> {
+        self.sign_prehash_with_rng(rng, &digest.finalize())
+    }
+}
+
+impl RandomizedDigestSigner<Sm3, DerSignature> for SigningKey {
+    fn try_sign_digest_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        digest: Sm3,
+    ) -> Result<DerSignature> {
+        RandomizedDigestSigner::<Sm3, Signature>::try_sign_digest_with_rng(self, rng, digest)
+            .map(Into::into)
+    }
+}
+
+impl SigningKey {
+    /// Compute the signature of the message hash `e` using the ephemeral
+    /// scalar `k`, i.e. steps A4-A7 of the signing algorithm.
+    ///
+    /// Returns `None` if a different `k` needs to be chosen, or an error if
+    /// this key can't produce signatures at all.
+    fn sign_with_k(&self, e: &Scalar, k: &Scalar) -> Result<Option<Signature>> {
         // A4: calculate the elliptic curve point (x1, y1)=[k]G
-        let R = ProjectivePoint::mul_by_generator(&k).to_affine();
+        let R = ProjectivePoint::mul_by_generator(k).to_affine();
 
         // A5: calculate r=(e+x1) modn, return to A3 if r=0 or r+k=n
-        let r = e + Scalar::reduce_bytes(&R.x());
+        let r = *e + Scalar::reduce_bytes(&R.x());
         if bool::from(r.is_zero() | (r + k).ct_eq(&Scalar::ZERO)) {
-            return Err(Error::new());
+            return Ok(None);
         }
 
         // A6: calculate s=((1+dA)^(-1)*(k-r*dA)) modn, return to A3 if s=0
         let d_plus_1_inv = Option::<Scalar>::from((*self.secret_scalar + Scalar::ONE).invert())
             .ok_or_else(Error::new)?;
 
-        let s = d_plus_1_inv * (k - (r * *self.secret_scalar));
+        let s = d_plus_1_inv * (k - &(r * *self.secret_scalar));
 
         // A7: the digital signature of M is (r, s)
-        Signature::from_scalars(r, s)
+        Ok(Signature::from_scalars(r, s).ok())
     }
 }
 
-impl Signer<Signature> for SigningKey {
-    fn try_sign(&self, msg: &[u8]) -> Result<Signature> {
-        // A1: set M~=ZA || M
-        let hash = self.verifying_key.hash_msg(msg);
-        self.sign_prehash(&hash)
+/// Convert the message hash `e` to a scalar, checking its length.
+pub(super) fn prehash_to_scalar(prehash: &[u8]) -> Result<Scalar> {
+    if prehash.len() != <Sm2 as Curve>::FieldBytesSize::USIZE {
+        return Err(Error::new());
     }
+
+    Ok(Scalar::reduce_bytes(FieldBytes::from_slice(prehash)))
 }
 
 //
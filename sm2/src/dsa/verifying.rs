@@ -12,23 +12,20 @@
 //! B7: calculate R=(e'+x1') modn, verification pass if yes, otherwise failed
 //! ```
 
-use super::Signature;
+use super::{DerSignature, Signature};
 use crate::{
     distid::hash_z, AffinePoint, DistId, EncodedPoint, FieldBytes, Hash, ProjectivePoint,
     PublicKey, Scalar, Sm2,
 };
 use elliptic_curve::{
-    generic_array::typenum::Unsigned,
-    ops::{LinearCombination, Reduce},
-    point::AffineCoordinates,
-    sec1::ToEncodedPoint,
+    generic_array::typenum::Unsigned, ops::Reduce, point::AffineCoordinates, sec1::ToEncodedPoint,
     Curve, Group,
 };
 use signature::{hazmat::PrehashVerifier, Error, Result, Verifier};
 use sm3::{digest::Digest, Sm3};
 
 #[cfg(feature = "alloc")]
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, vec::Vec};
 
 /// SM2DSA public key used for verifying signatures are valid for a given
 /// message.
@@ -41,6 +38,8 @@ use alloc::{boxed::Box, string::String};
 /// - [`Verifier`]: verify a message against a provided key and signature
 /// - [`PrehashVerifier`]: verify the low-level raw output bytes of a message digest
 ///
+/// Both traits are implemented for [`Signature`] and [`DerSignature`].
+///
 /// # `serde` support
 ///
 /// When the `serde` feature of this crate is enabled, it provides support for
@@ -59,13 +58,14 @@ pub struct VerifyingKey {
 
     /// Distinguishing identifier used to compute `Z`.
     #[cfg(feature = "alloc")]
-    distid: String,
+    distid: Vec<u8>,
 }
 
 impl VerifyingKey {
     /// Initialize [`VerifyingKey`] from a signer's distinguishing identifier
     /// and public key.
-    pub fn new(distid: &DistId, public_key: PublicKey) -> Result<Self> {
+    pub fn new(distid: impl AsRef<DistId>, public_key: PublicKey) -> Result<Self> {
+        let distid = distid.as_ref();
         let identity_hash = hash_z(distid, &public_key).map_err(|_| Error::new())?;
 
         Ok(Self {
@@ -77,7 +77,7 @@ impl VerifyingKey {
     }
 
     /// Initialize [`VerifyingKey`] from a SEC1-encoded public key.
-    pub fn from_sec1_bytes(distid: &DistId, bytes: &[u8]) -> Result<Self> {
+    pub fn from_sec1_bytes(distid: impl AsRef<DistId>, bytes: &[u8]) -> Result<Self> {
         let public_key = PublicKey::from_sec1_bytes(bytes).map_err(|_| Error::new())?;
         Self::new(distid, public_key)
     }
@@ -86,7 +86,7 @@ impl VerifyingKey {
     ///
     /// Returns an [`Error`] if the given affine point is the additive identity
     /// (a.k.a. point at infinity).
-    pub fn from_affine(distid: &DistId, affine: AffinePoint) -> Result<Self> {
+    pub fn from_affine(distid: impl AsRef<DistId>, affine: AffinePoint) -> Result<Self> {
         let public_key = PublicKey::from_affine(affine).map_err(|_| Error::new())?;
         Self::new(distid, public_key)
     }
@@ -97,9 +97,20 @@ impl VerifyingKey {
     }
 
     /// Get the distinguishing identifier for this key.
+    ///
+    /// # Panics
+    ///
+    /// If the distinguishing identifier is not valid UTF-8. Use
+    /// [`VerifyingKey::distid_bytes`] for keys with binary identifiers.
+    #[cfg(feature = "alloc")]
+    pub fn distid(&self) -> &str {
+        core::str::from_utf8(&self.distid).expect("distinguishing identifier is not UTF-8")
+    }
+
+    /// Get the distinguishing identifier for this key as a byte string.
     #[cfg(feature = "alloc")]
-    pub fn distid(&self) -> &DistId {
-        self.distid.as_str()
+    pub fn distid_bytes(&self) -> &DistId {
+        self.distid.as_slice()
     }
 
     /// Convert this [`VerifyingKey`] into the
@@ -149,7 +160,8 @@ impl PrehashVerifier<Signature> for VerifyingKey {
         }
 
         // B6: calculate the point (x1', y1')=[s']G + [t]PA
-        let x = ProjectivePoint::lincomb(
+        // (all inputs are public, so this can be computed in variable time)
+        let x = ProjectivePoint::lincomb_vartime(
             &ProjectivePoint::generator(),
             &s,
             &ProjectivePoint::from(&self.public_key),
@@ -167,6 +179,13 @@ impl PrehashVerifier<Signature> for VerifyingKey {
     }
 }
 
+impl PrehashVerifier<DerSignature> for VerifyingKey {
+    fn verify_prehash(&self, prehash: &[u8], signature: &DerSignature) -> Result<()> {
+        let signature = Signature::try_from(signature)?;
+        PrehashVerifier::<Signature>::verify_prehash(self, prehash, &signature)
+    }
+}
+
 impl Verifier<Signature> for VerifyingKey {
     fn verify(&self, msg: &[u8], signature: &Signature) -> Result<()> {
         // B3: set M'~=ZA || M'
@@ -175,6 +194,13 @@ impl Verifier<Signature> for VerifyingKey {
     }
 }
 
+impl Verifier<DerSignature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &DerSignature) -> Result<()> {
+        let signature = Signature::try_from(signature)?;
+        Verifier::<Signature>::verify(self, msg, &signature)
+    }
+}
+
 //
 // Other trait impls
 //
@@ -0,0 +1,38 @@
+//! Key derivation function.
+
+use crate::{EncodedPoint, Hash};
+use elliptic_curve::{sec1::Coordinates, Error, Result};
+use sm3::{Digest, Sm3};
+
+/// Key derivation function `KDF(Z, klen)` based on SM3, filling `output`
+/// with `klen = output.len()` bytes of key material derived from the
+/// concatenation `Z` of `z_parts`.
+///
+/// ```text
+/// Ha_i = Hv(Z || ct), ct = 1, 2, ...
+/// K = Ha_1 || Ha_2 || ... truncated to klen bytes
+/// ```
+pub(crate) fn kdf(z_parts: &[&[u8]], output: &mut [u8]) -> Result<()> {
+    let mut ct: u32 = 1;
+
+    for chunk in output.chunks_mut(Hash::default().len()) {
+        let hash = z_parts
+            .iter()
+            .fold(Sm3::new(), |sm3, part| sm3.chain_update(part))
+            .chain_update(ct.to_be_bytes())
+            .finalize();
+
+        chunk.copy_from_slice(&hash[..chunk.len()]);
+        ct = ct.checked_add(1).ok_or(Error)?;
+    }
+
+    Ok(())
+}
+
+/// Get the coordinates of an uncompressed point.
+pub(crate) fn coordinates(point: &EncodedPoint) -> Result<(&[u8], &[u8])> {
+    match point.coordinates() {
+        Coordinates::Uncompressed { x, y } => Ok((x, y)),
+        _ => Err(Error),
+    }
+}
@@ -0,0 +1,398 @@
+//! SM2 Key Exchange Protocol (SM2KEP) as defined in [GB/T 32918.3-2016].
+//!
+//! The protocol is an authenticated key agreement between an initiator `A`
+//! and a responder `B`, each with a static key pair and a distinguishing
+//! identifier, and takes two passes plus an optional third one for key
+//! confirmation:
+//!
+//! 1. `A` sends its ephemeral public key `RA` ([`Initiator::new`]).
+//! 2. `B` derives the shared key, and sends its ephemeral public key `RB`
+//!    along with the confirmation hash `SB` ([`Responder::new`]).
+//! 3. `A` derives the shared key, checks `SB`, and sends the confirmation
+//!    hash `SA` ([`Initiator::finish`]), which `B` then checks
+//!    ([`Responder::confirm`]).
+//!
+//! ## Usage
+//!
+//! NOTE: requires the `kex` crate feature enabled, and `rand_core` dependency
+//! with `getrandom` feature enabled.
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use rand_core::OsRng; // requires 'getrandom` feature
+//! use sm2::{
+//!     kex::{Initiator, Responder},
+//!     SecretKey
+//! };
+//!
+//! let alice_distid = "alice@rustcrypto.org";
+//! let alice_secret_key = SecretKey::random(&mut OsRng);
+//! let bob_distid = "bob@rustcrypto.org";
+//! let bob_secret_key = SecretKey::random(&mut OsRng);
+//!
+//! // Alice initiates the key exchange and sends `RA` to Bob
+//! let alice = Initiator::new(
+//!     &mut OsRng,
+//!     alice_distid,
+//!     &alice_secret_key,
+//!     bob_distid,
+//!     &bob_secret_key.public_key(),
+//! )?;
+//!
+//! // Bob derives the shared key and sends `RB` and `SB` to Alice
+//! let mut bob_key = [0u8; 16];
+//! let bob = Responder::new(
+//!     &mut OsRng,
+//!     bob_distid,
+//!     &bob_secret_key,
+//!     alice_distid,
+//!     &alice_secret_key.public_key(),
+//!     alice.ephemeral_public_key(),
+//!     &mut bob_key,
+//! )?;
+//!
+//! // Alice derives the shared key, checks `SB` and sends `SA` to Bob
+//! let mut alice_key = [0u8; 16];
+//! let alice_confirmation = alice.finish(
+//!     bob.ephemeral_public_key(),
+//!     Some(bob.confirmation()),
+//!     &mut alice_key,
+//! )?;
+//!
+//! // Bob checks `SA`
+//! bob.confirm(&alice_confirmation)?;
+//! assert_eq!(alice_key, bob_key);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [GB/T 32918.3-2016]: https://www.chinesestandard.net/PDF.aspx/GBT32918.3-2016
+
+use crate::{
+    distid::hash_z,
+    kdf::{coordinates, kdf},
+    AffinePoint, DistId, EncodedPoint, Hash, NonZeroScalar, ProjectivePoint, PublicKey, Scalar,
+    SecretKey, U256,
+};
+use elliptic_curve::{
+    ops::{LinearCombination, Reduce},
+    point::AffineCoordinates,
+    rand_core::CryptoRngCore,
+    sec1::ToEncodedPoint,
+    subtle::ConstantTimeEq,
+    zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing},
+    Error, Result,
+};
+use sm3::{Digest, Sm3};
+
+/// Key confirmation hash (`S1`, `S2`, `SA` or `SB`).
+pub type Confirmation = [u8; 32];
+
+/// Prefix of the responder's confirmation hashes `SB` and `S1`.
+const RESPONDER_CONFIRMATION_PREFIX: u8 = 0x02;
+
+/// Prefix of the initiator's confirmation hashes `SA` and `S2`.
+const INITIATOR_CONFIRMATION_PREFIX: u8 = 0x03;
+
+/// Initiator `A` of a key exchange.
+///
+/// ## Algorithm
+///
+/// ```text
+/// A1: pick a random number rA in [1, n-1] via a random number generator
+/// A2: calculate the elliptic curve point RA=[rA]G=(x1,y1)
+/// A3: send RA to B
+/// A4: calculate x1~=2^w+(x1&(2^w-1))
+/// A5: calculate tA=(dA+x1~*rA) modn
+/// A6: verify that RB is a point on the curve, and calculate x2~=2^w+(x2&(2^w-1))
+/// A7: calculate the elliptic curve point U=[h*tA](PB+[x2~]RB)=(xU,yU), error if U is the point at infinity
+/// A8: calculate KA=KDF(xU||yU||ZA||ZB, klen)
+/// A9: calculate S1=Hash(0x02||yU||Hash(xU||ZA||ZB||x1||y1||x2||y2)), error if S1 != SB
+/// A10: calculate SA=Hash(0x03||yU||Hash(xU||ZA||ZB||x1||y1||x2||y2)) and send it to B
+/// ```
+pub struct Initiator {
+    /// Static secret key `dA`.
+    secret_scalar: NonZeroScalar,
+
+    /// User information hash `ZA`.
+    z: Hash,
+
+    /// Static public key `PB` of the responder.
+    peer_public_key: PublicKey,
+
+    /// User information hash `ZB` of the responder.
+    peer_z: Hash,
+
+    /// Ephemeral secret key `rA`.
+    ephemeral_scalar: NonZeroScalar,
+
+    /// Ephemeral public key `RA`.
+    ephemeral_public_key: PublicKey,
+}
+
+impl Initiator {
+    /// Initiate a key exchange with the responder identified by
+    /// `peer_distid` and `peer_public_key`, using the given distinguishing
+    /// identifier and secret key.
+    pub fn new(
+        rng: &mut impl CryptoRngCore,
+        distid: impl AsRef<DistId>,
+        secret_key: &SecretKey,
+        peer_distid: impl AsRef<DistId>,
+        peer_public_key: &PublicKey,
+    ) -> Result<Self> {
+        let secret_scalar = secret_key.to_nonzero_scalar();
+        let z = hash_z(distid, &secret_key.public_key())?;
+        let peer_z = hash_z(peer_distid, peer_public_key)?;
+
+        // A1: pick a random number rA in [1, n-1] via a random number generator
+        let ephemeral_scalar = NonZeroScalar::random(rng);
+
+        // A2: calculate the elliptic curve point RA=[rA]G=(x1,y1)
+        let ephemeral_public_key = PublicKey::from_secret_scalar(&ephemeral_scalar);
+
+        Ok(Self {
+            secret_scalar,
+            z,
+            peer_public_key: *peer_public_key,
+            peer_z,
+            ephemeral_scalar,
+            ephemeral_public_key,
+        })
+    }
+
+    /// Get the ephemeral public key `RA`, which is sent to the responder.
+    pub fn ephemeral_public_key(&self) -> &PublicKey {
+        &self.ephemeral_public_key
+    }
+
+    /// Finish the key exchange given the responder's ephemeral public key
+    /// `RB` and, if key confirmation is used, its confirmation hash `SB`,
+    /// filling `key` with the shared key.
+    ///
+    /// Returns the confirmation hash `SA`, which is sent to the responder if
+    /// key confirmation is used.
+    pub fn finish(
+        self,
+        peer_ephemeral_public_key: &PublicKey,
+        peer_confirmation: Option<&Confirmation>,
+        key: &mut [u8],
+    ) -> Result<Confirmation> {
+        // A4-A7: calculate the elliptic curve point U
+        let u = shared_point(
+            &self.secret_scalar,
+            &self.ephemeral_scalar,
+            &self.ephemeral_public_key,
+            &self.peer_public_key,
+            peer_ephemeral_public_key,
+        )?;
+        let (xu, yu) = coordinates(&u)?;
+
+        // A8: calculate KA=KDF(xU||yU||ZA||ZB, klen)
+        kdf(&[xu, yu, &self.z, &self.peer_z], key)?;
+
+        let inner = inner_hash(
+            xu,
+            &self.z,
+            &self.peer_z,
+            &self.ephemeral_public_key,
+            peer_ephemeral_public_key,
+        )?;
+
+        // A9: calculate S1 and verify that S1=SB
+        if let Some(peer_confirmation) = peer_confirmation {
+            let s1 = confirmation(RESPONDER_CONFIRMATION_PREFIX, yu, &inner);
+
+            if !bool::from(s1.ct_eq(peer_confirmation)) {
+                key.zeroize();
+                return Err(Error);
+            }
+        }
+
+        // A10: calculate SA
+        Ok(confirmation(INITIATOR_CONFIRMATION_PREFIX, yu, &inner))
+    }
+}
+
+impl Drop for Initiator {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+        self.ephemeral_scalar.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Initiator {}
+
+/// Responder `B` of a key exchange.
+///
+/// ## Algorithm
+///
+/// ```text
+/// B1: pick a random number rB in [1, n-1] via a random number generator
+/// B2: calculate the elliptic curve point RB=[rB]G=(x2,y2)
+/// B3: calculate x2~=2^w+(x2&(2^w-1))
+/// B4: calculate tB=(dB+x2~*rB) modn
+/// B5: verify that RA is a point on the curve, and calculate x1~=2^w+(x1&(2^w-1))
+/// B6: calculate the elliptic curve point V=[h*tB](PA+[x1~]RA)=(xV,yV), error if V is the point at infinity
+/// B7: calculate KB=KDF(xV||yV||ZA||ZB, klen)
+/// B8: calculate SB=Hash(0x02||yV||Hash(xV||ZA||ZB||x1||y1||x2||y2))
+/// B9: send RB and SB to A
+/// B10: calculate S2=Hash(0x03||yV||Hash(xV||ZA||ZB||x1||y1||x2||y2)), error if S2 != SA
+/// ```
+#[derive(Clone, Debug)]
+pub struct Responder {
+    /// Ephemeral public key `RB`.
+    ephemeral_public_key: PublicKey,
+
+    /// Confirmation hash `SB`.
+    confirmation: Confirmation,
+
+    /// Expected confirmation hash `S2` of the initiator.
+    peer_confirmation: Confirmation,
+}
+
+impl Responder {
+    /// Respond to a key exchange initiated by the initiator identified by
+    /// `peer_distid` and `peer_public_key` with the ephemeral public key
+    /// `RA`, using the given distinguishing identifier and secret key, and
+    /// filling `key` with the shared key.
+    ///
+    /// If key confirmation is used, the key must not be used before the
+    /// initiator's confirmation hash has been checked with
+    /// [`Responder::confirm`].
+    pub fn new(
+        rng: &mut impl CryptoRngCore,
+        distid: impl AsRef<DistId>,
+        secret_key: &SecretKey,
+        peer_distid: impl AsRef<DistId>,
+        peer_public_key: &PublicKey,
+        peer_ephemeral_public_key: &PublicKey,
+        key: &mut [u8],
+    ) -> Result<Self> {
+        let public_key = secret_key.public_key();
+        let z = hash_z(distid, &public_key)?;
+        let peer_z = hash_z(peer_distid, peer_public_key)?;
+
+        let secret_scalar = Zeroizing::new(secret_key.to_nonzero_scalar());
+
+        // B1: pick a random number rB in [1, n-1] via a random number generator
+        let ephemeral_scalar = Zeroizing::new(NonZeroScalar::random(rng));
+
+        // B2: calculate the elliptic curve point RB=[rB]G=(x2,y2)
+        let ephemeral_public_key = PublicKey::from_secret_scalar(&ephemeral_scalar);
+
+        // B3-B6: calculate the elliptic curve point V
+        let v = shared_point(
+            &secret_scalar,
+            &ephemeral_scalar,
+            &ephemeral_public_key,
+            peer_public_key,
+            peer_ephemeral_public_key,
+        )?;
+        let (xv, yv) = coordinates(&v)?;
+
+        // B7: calculate KB=KDF(xV||yV||ZA||ZB, klen)
+        kdf(&[xv, yv, &peer_z, &z], key)?;
+
+        let inner = inner_hash(
+            xv,
+            &peer_z,
+            &z,
+            peer_ephemeral_public_key,
+            &ephemeral_public_key,
+        )?;
+
+        Ok(Self {
+            ephemeral_public_key,
+            // B8: calculate SB
+            confirmation: confirmation(RESPONDER_CONFIRMATION_PREFIX, yv, &inner),
+            // B10: calculate S2
+            peer_confirmation: confirmation(INITIATOR_CONFIRMATION_PREFIX, yv, &inner),
+        })
+    }
+
+    /// Get the ephemeral public key `RB`, which is sent to the initiator.
+    pub fn ephemeral_public_key(&self) -> &PublicKey {
+        &self.ephemeral_public_key
+    }
+
+    /// Get the confirmation hash `SB`, which is sent to the initiator if key
+    /// confirmation is used.
+    pub fn confirmation(&self) -> &Confirmation {
+        &self.confirmation
+    }
+
+    /// Check the initiator's confirmation hash `SA`.
+    pub fn confirm(&self, peer_confirmation: &Confirmation) -> Result<()> {
+        if self.peer_confirmation.ct_eq(peer_confirmation).into() {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+/// Compute the shared point `[h*t](P'+[x'~]R')`, where `t=(d+x~*r) modn`,
+/// from a party's static and ephemeral secret keys `d` and `r` and
+/// ephemeral public key `R`, and the peer's static and ephemeral public keys
+/// `P'` and `R'`. The cofactor `h` is 1.
+fn shared_point(
+    secret_scalar: &NonZeroScalar,
+    ephemeral_scalar: &NonZeroScalar,
+    ephemeral_public_key: &PublicKey,
+    peer_public_key: &PublicKey,
+    peer_ephemeral_public_key: &PublicKey,
+) -> Result<EncodedPoint> {
+    let t = **secret_scalar + x_bar(ephemeral_public_key.as_affine()) * **ephemeral_scalar;
+    let peer_x_bar = x_bar(peer_ephemeral_public_key.as_affine());
+
+    let point = ProjectivePoint::lincomb(
+        &peer_public_key.to_projective(),
+        &t,
+        &peer_ephemeral_public_key.to_projective(),
+        &(t * peer_x_bar),
+    );
+
+    // `PublicKey` rejects the point at infinity
+    PublicKey::from_affine(point.to_affine()).map(|point| point.to_encoded_point(false))
+}
+
+/// Compute `x~=2^w+(x&(2^w-1))` for the x-coordinate `x` of a point, where
+/// `w=ceil(ceil(log2(n))/2)-1=127`.
+fn x_bar(point: &AffinePoint) -> Scalar {
+    let mut bytes = point.x();
+    let (high, low) = bytes.split_at_mut(16);
+    high.fill(0);
+    low[0] |= 0x80;
+    <Scalar as Reduce<U256>>::reduce_bytes(&bytes)
+}
+
+/// Compute the inner hash `Hash(xU||ZA||ZB||x1||y1||x2||y2)` of the
+/// confirmation hashes, where `RA=(x1,y1)` and `RB=(x2,y2)`.
+fn inner_hash(x: &[u8], za: &Hash, zb: &Hash, ra: &PublicKey, rb: &PublicKey) -> Result<Hash> {
+    let ra = ra.to_encoded_point(false);
+    let (x1, y1) = coordinates(&ra)?;
+    let rb = rb.to_encoded_point(false);
+    let (x2, y2) = coordinates(&rb)?;
+
+    Ok(Sm3::new()
+        .chain_update(x)
+        .chain_update(za)
+        .chain_update(zb)
+        .chain_update(x1)
+        .chain_update(y1)
+        .chain_update(x2)
+        .chain_update(y2)
+        .finalize())
+}
+
+/// Compute the confirmation hash `Hash(prefix||y||inner)`.
+fn confirmation(prefix: u8, y: &[u8], inner: &Hash) -> Confirmation {
+    Sm3::new()
+        .chain_update([prefix])
+        .chain_update(y)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
@@ -31,11 +31,19 @@ extern crate alloc;
 
 #[cfg(feature = "dsa")]
 pub mod dsa;
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+#[cfg(feature = "kex")]
+pub mod kex;
+#[cfg(feature = "pke")]
+pub mod pke;
 
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
-#[cfg(feature = "dsa")]
+#[cfg(any(feature = "dsa", feature = "kex"))]
 mod distid;
+#[cfg(any(feature = "kex", feature = "pke"))]
+mod kdf;
 
 pub use elliptic_curve::{self, bigint::U256};
 
@@ -52,15 +60,15 @@ use elliptic_curve::{
     FieldBytesEncoding,
 };
 
-#[cfg(feature = "dsa")]
-use crate::distid::DistId;
+#[cfg(any(feature = "dsa", feature = "kex"))]
+pub use crate::distid::{hash_z, DistId, DEFAULT_DISTID};
 
 /// Order of SM2's elliptic curve group (i.e. scalar modulus) serialized as
 /// hexadecimal.
 const ORDER_HEX: &str = "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54123";
 
 /// SM3 hash output.
-#[cfg(feature = "dsa")]
+#[cfg(any(feature = "dsa", feature = "kex", feature = "pke"))]
 type Hash = sm3::digest::Output<sm3::Sm3>;
 
 /// SM2 elliptic curve.
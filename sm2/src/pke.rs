@@ -0,0 +1,122 @@
+//! SM2 Encryption Algorithm (SM2PKE) as defined in [GB/T 32918.4-2016].
+//!
+//! ## Usage
+//!
+//! NOTE: requires the `pke` crate feature enabled, and `rand_core` dependency
+//! with `getrandom` feature enabled.
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use rand_core::OsRng; // requires 'getrandom` feature
+//! use sm2::{
+//!     pke::{DecryptingKey, EncryptingKey},
+//!     SecretKey
+//! };
+//!
+//! // Encrypting
+//! let secret_key = SecretKey::random(&mut OsRng); // serialize with `::to_bytes()`
+//! let public_key_bytes = secret_key.public_key().to_sec1_bytes();
+//! let encrypting_key = EncryptingKey::from_sec1_bytes(&public_key_bytes)?;
+//! let plaintext = b"plaintext";
+//! let ciphertext = encrypting_key.encrypt(&mut OsRng, plaintext)?;
+//!
+//! // Decrypting
+//! let decrypting_key = DecryptingKey::new(&secret_key);
+//! assert_eq!(decrypting_key.decrypt(&ciphertext)?, plaintext);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [GB/T 32918.4-2016]: https://www.chinesestandard.net/PDF.aspx/GBT32918.4-2016
+
+mod decrypting;
+mod encrypting;
+
+pub use self::{decrypting::DecryptingKey, encrypting::EncryptingKey};
+
+use der::{
+    asn1::{OctetStringRef, UintRef},
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, Reader, Sequence, Writer,
+};
+use elliptic_curve::{Error, Result};
+
+/// Layout of the components of a raw (i.e. non-DER) ciphertext, where `C1`
+/// is the ephemeral public key, `C2` the encrypted message and `C3` the
+/// hash of the message.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// `C1 || C2 || C3`, as in the original GM/T 0003.4-2012 standard.
+    C1C2C3,
+
+    /// `C1 || C3 || C2`, as in GB/T 32918.4-2016.
+    #[default]
+    C1C3C2,
+}
+
+/// ASN.1 DER encoding of a ciphertext as defined in GM/T 0009-2012:
+///
+/// ```text
+/// SM2Cipher ::= SEQUENCE {
+///     XCoordinate INTEGER,
+///     YCoordinate INTEGER,
+///     HASH        OCTET STRING SIZE(32),
+///     CipherText  OCTET STRING
+/// }
+/// ```
+struct Cipher<'a> {
+    /// x-coordinate of `C1`.
+    x: UintRef<'a>,
+
+    /// y-coordinate of `C1`.
+    y: UintRef<'a>,
+
+    /// `C3`.
+    digest: OctetStringRef<'a>,
+
+    /// `C2`.
+    cipher: OctetStringRef<'a>,
+}
+
+impl<'a> Cipher<'a> {
+    /// Parse DER-encoded ciphertext.
+    fn from_der(bytes: &'a [u8]) -> Result<Self> {
+        Decode::from_der(bytes).map_err(|_| Error)
+    }
+
+    /// Serialize as DER.
+    fn to_der(&self) -> Result<alloc::vec::Vec<u8>> {
+        Encode::to_der(self).map_err(|_| Error)
+    }
+}
+
+impl<'a> DecodeValue<'a> for Cipher<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            Ok(Self {
+                x: reader.decode()?,
+                y: reader.decode()?,
+                digest: reader.decode()?,
+                cipher: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl EncodeValue for Cipher<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.x.encoded_len()?
+            + self.y.encoded_len()?
+            + self.digest.encoded_len()?
+            + self.cipher.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.x.encode(writer)?;
+        self.y.encode(writer)?;
+        self.digest.encode(writer)?;
+        self.cipher.encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for Cipher<'a> {}
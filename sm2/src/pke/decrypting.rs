@@ -0,0 +1,208 @@
+//! Support for SM2PKE decryption.
+//!
+//! ## Algorithm
+//!
+//! ```text
+//! B1: extract C1 from the ciphertext, error if it isn't a point on the curve
+//! B2: calculate the elliptic curve point S=[h]C1, error if S is the point at infinity
+//! B3: calculate the elliptic curve point [dB]C1=(x2,y2)
+//! B4: calculate t=KDF(x2||y2, klen), error if t is all zeroes
+//! B5: extract C2 from the ciphertext and calculate M'=C2^t
+//! B6: calculate u=Hash(x2||M'||y2), error if u != C3
+//! B7: the plaintext is M'
+//! ```
+
+use super::{Cipher, EncryptingKey, Mode};
+use crate::{
+    kdf::{coordinates, kdf},
+    EncodedPoint, FieldBytes, NonZeroScalar, PublicKey, SecretKey, Sm2,
+};
+use alloc::{vec, vec::Vec};
+use core::fmt::{self, Debug};
+use elliptic_curve::{
+    generic_array::typenum::Unsigned,
+    sec1::{Tag, ToEncodedPoint},
+    subtle::ConstantTimeEq,
+    Curve, Error, Result,
+};
+use sm3::{digest::Digest, Sm3};
+
+/// Size of `C3` in bytes.
+const HASH_SIZE: usize = 32;
+
+/// SM2PKE secret key used for decrypting messages.
+#[derive(Clone)]
+pub struct DecryptingKey {
+    /// Secret key.
+    secret_scalar: NonZeroScalar,
+
+    /// Encrypting key for this decrypting key.
+    encrypting_key: EncryptingKey,
+}
+
+impl DecryptingKey {
+    /// Create decrypting key from a secret key, expecting raw ciphertexts in
+    /// the default [`Mode`].
+    pub fn new(secret_key: &SecretKey) -> Self {
+        Self::new_with_mode(secret_key, Mode::default())
+    }
+
+    /// Create decrypting key from a secret key, expecting raw ciphertexts in
+    /// the given [`Mode`].
+    pub fn new_with_mode(secret_key: &SecretKey, mode: Mode) -> Self {
+        Self::from_nonzero_scalar_with_mode(secret_key.to_nonzero_scalar(), mode)
+    }
+
+    /// Parse decrypting key from big endian-encoded bytes.
+    pub fn from_bytes(bytes: &FieldBytes) -> Result<Self> {
+        Self::from_slice(bytes)
+    }
+
+    /// Parse decrypting key from big endian-encoded byte slice containing a
+    /// secret scalar value.
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        let secret_scalar = NonZeroScalar::try_from(slice).map_err(|_| Error)?;
+        Ok(Self::from_nonzero_scalar(secret_scalar))
+    }
+
+    /// Create a decrypting key from a non-zero scalar.
+    pub fn from_nonzero_scalar(secret_scalar: NonZeroScalar) -> Self {
+        Self::from_nonzero_scalar_with_mode(secret_scalar, Mode::default())
+    }
+
+    /// Create a decrypting key from a non-zero scalar, expecting raw
+    /// ciphertexts in the given [`Mode`].
+    pub fn from_nonzero_scalar_with_mode(secret_scalar: NonZeroScalar, mode: Mode) -> Self {
+        let public_key = PublicKey::from_secret_scalar(&secret_scalar);
+
+        Self {
+            secret_scalar,
+            encrypting_key: EncryptingKey::new_with_mode(public_key, mode),
+        }
+    }
+
+    /// Serialize as bytes.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.secret_scalar.to_bytes()
+    }
+
+    /// Borrow the secret [`NonZeroScalar`] value for this key.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This value is key material.
+    ///
+    /// Please treat it with the care it deserves!
+    pub fn as_nonzero_scalar(&self) -> &NonZeroScalar {
+        &self.secret_scalar
+    }
+
+    /// Get the [`EncryptingKey`] which corresponds to this [`DecryptingKey`].
+    pub fn encrypting_key(&self) -> &EncryptingKey {
+        &self.encrypting_key
+    }
+
+    /// Decrypt a raw ciphertext whose layout is given by the [`Mode`] of
+    /// this key.
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        // B1: extract C1 from the ciphertext
+        let c1_len = Tag::from_u8(*ciphertext.first().ok_or(Error)?)?
+            .message_len(<Sm2 as Curve>::FieldBytesSize::USIZE);
+
+        if ciphertext.len() <= c1_len.checked_add(HASH_SIZE).ok_or(Error)? {
+            return Err(Error);
+        }
+
+        let (c1, rest) = ciphertext.split_at(c1_len);
+
+        let (c2, c3) = match self.encrypting_key.mode() {
+            Mode::C1C2C3 => rest.split_at(rest.len().checked_sub(HASH_SIZE).ok_or(Error)?),
+            Mode::C1C3C2 => {
+                let (c3, c2) = rest.split_at(HASH_SIZE);
+                (c2, c3)
+            }
+        };
+
+        self.decrypt_parts(&PublicKey::from_sec1_bytes(c1)?, c2, c3)
+    }
+
+    /// Decrypt an ASN.1 DER-encoded ciphertext.
+    pub fn decrypt_der(&self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let cipher = Cipher::from_der(ciphertext)?;
+
+        // B1: extract C1 from the ciphertext
+        let c1 = EncodedPoint::from_affine_coordinates(
+            &field_bytes(cipher.x.as_bytes())?,
+            &field_bytes(cipher.y.as_bytes())?,
+            false,
+        );
+
+        self.decrypt_parts(
+            &PublicKey::from_sec1_bytes(c1.as_bytes())?,
+            cipher.cipher.as_bytes(),
+            cipher.digest.as_bytes(),
+        )
+    }
+
+    /// Decrypt the ciphertext components `C1`, `C2` and `C3`.
+    fn decrypt_parts(&self, c1: &PublicKey, c2: &[u8], c3: &[u8]) -> Result<Vec<u8>> {
+        if c2.is_empty() || c3.len() != HASH_SIZE {
+            return Err(Error);
+        }
+
+        // B2: the cofactor h is 1 and `PublicKey` is never the point at infinity
+        // B3: calculate the elliptic curve point [dB]C1=(x2,y2)
+        let s = (c1.to_projective() * *self.secret_scalar)
+            .to_affine()
+            .to_encoded_point(false);
+        let (x2, y2) = coordinates(&s)?;
+
+        // B4: calculate t=KDF(x2||y2, klen), error if t is all zeroes
+        let mut msg = vec![0; c2.len()];
+        kdf(&[x2, y2], &mut msg)?;
+
+        if msg.iter().all(|&b| b == 0) {
+            return Err(Error);
+        }
+
+        // B5: extract C2 from the ciphertext and calculate M'=C2^t
+        for (m, c) in msg.iter_mut().zip(c2) {
+            *m ^= c;
+        }
+
+        // B6: calculate u=Hash(x2||M'||y2), error if u != C3
+        let u = Sm3::new()
+            .chain_update(x2)
+            .chain_update(&msg)
+            .chain_update(y2)
+            .finalize();
+
+        if u.as_slice().ct_eq(c3).into() {
+            Ok(msg)
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl Debug for DecryptingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecryptingKey")
+            .field("encrypting_key", &self.encrypting_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<&SecretKey> for DecryptingKey {
+    fn from(secret_key: &SecretKey) -> Self {
+        Self::new(secret_key)
+    }
+}
+
+/// Convert a DER-encoded unsigned integer into a field element's bytes.
+fn field_bytes(bytes: &[u8]) -> Result<FieldBytes> {
+    let mut field_bytes = FieldBytes::default();
+    let offset = field_bytes.len().checked_sub(bytes.len()).ok_or(Error)?;
+    field_bytes[offset..].copy_from_slice(bytes);
+    Ok(field_bytes)
+}
@@ -0,0 +1,155 @@
+//! Support for SM2PKE encryption.
+//!
+//! ## Algorithm
+//!
+//! ```text
+//! A1: pick a random number k in [1, n-1] via a random number generator
+//! A2: calculate the elliptic curve point C1=[k]G=(x1,y1)
+//! A3: calculate the elliptic curve point S=[h]PB, error if S is the point at infinity
+//! A4: calculate the elliptic curve point [k]PB=(x2,y2)
+//! A5: calculate t=KDF(x2||y2, klen), return to A1 if t is all zeroes
+//! A6: calculate C2=M^t
+//! A7: calculate C3=Hash(x2||M||y2)
+//! A8: the ciphertext is C1||C3||C2 (or C1||C2||C3)
+//! ```
+
+use super::{Cipher, Mode};
+use crate::{
+    kdf::{coordinates, kdf},
+    AffinePoint, Hash, NonZeroScalar, ProjectivePoint, PublicKey,
+};
+use alloc::{vec, vec::Vec};
+use der::asn1::{OctetStringRef, UintRef};
+use elliptic_curve::{
+    ops::MulByGenerator, rand_core::CryptoRngCore, sec1::ToEncodedPoint, Error, Result,
+};
+use sm3::{digest::Digest, Sm3};
+
+/// SM2PKE public key used for encrypting messages.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EncryptingKey {
+    /// Public key.
+    public_key: PublicKey,
+
+    /// Layout of raw ciphertexts.
+    mode: Mode,
+}
+
+impl EncryptingKey {
+    /// Initialize [`EncryptingKey`] from a [`PublicKey`], producing raw
+    /// ciphertexts in the default [`Mode`].
+    pub fn new(public_key: PublicKey) -> Self {
+        Self::new_with_mode(public_key, Mode::default())
+    }
+
+    /// Initialize [`EncryptingKey`] from a [`PublicKey`], producing raw
+    /// ciphertexts in the given [`Mode`].
+    pub fn new_with_mode(public_key: PublicKey, mode: Mode) -> Self {
+        Self { public_key, mode }
+    }
+
+    /// Initialize [`EncryptingKey`] from a SEC1-encoded public key.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        PublicKey::from_sec1_bytes(bytes).map(Self::new)
+    }
+
+    /// Borrow the inner [`PublicKey`] for this encrypting key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Get the layout of the raw ciphertexts produced by this key.
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Encrypt `msg`, producing a raw ciphertext whose layout is given by
+    /// [`EncryptingKey::mode`].
+    pub fn encrypt(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> Result<Vec<u8>> {
+        let (c1, c2, c3) = self.encrypt_parts(rng, msg)?;
+        let c1 = c1.to_encoded_point(false);
+
+        let mut ciphertext = c1.as_bytes().to_vec();
+
+        match self.mode {
+            Mode::C1C2C3 => {
+                ciphertext.extend_from_slice(&c2);
+                ciphertext.extend_from_slice(&c3);
+            }
+            Mode::C1C3C2 => {
+                ciphertext.extend_from_slice(&c3);
+                ciphertext.extend_from_slice(&c2);
+            }
+        }
+
+        Ok(ciphertext)
+    }
+
+    /// Encrypt `msg`, producing an ASN.1 DER-encoded ciphertext.
+    pub fn encrypt_der(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> Result<Vec<u8>> {
+        let (c1, c2, c3) = self.encrypt_parts(rng, msg)?;
+        let c1 = c1.to_encoded_point(false);
+        let (x, y) = coordinates(&c1)?;
+
+        Cipher {
+            x: UintRef::new(x).map_err(|_| Error)?,
+            y: UintRef::new(y).map_err(|_| Error)?,
+            digest: OctetStringRef::new(&c3).map_err(|_| Error)?,
+            cipher: OctetStringRef::new(&c2).map_err(|_| Error)?,
+        }
+        .to_der()
+    }
+
+    /// Compute the ciphertext components `C1`, `C2` and `C3`.
+    fn encrypt_parts(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<(AffinePoint, Vec<u8>, Hash)> {
+        // An empty message would result in an all-zero `t` in A5
+        if msg.is_empty() {
+            return Err(Error);
+        }
+
+        // A3: the cofactor h is 1 and `PublicKey` is never the point at infinity
+        let public_key = self.public_key.to_projective();
+        let mut t = vec![0; msg.len()];
+
+        loop {
+            // A1: pick a random number k in [1, n-1] via a random number generator
+            let k = NonZeroScalar::random(rng);
+
+            // A2: calculate the elliptic curve point C1=[k]G=(x1,y1)
+            let c1 = ProjectivePoint::mul_by_generator(&k).to_affine();
+
+            // A4: calculate the elliptic curve point [k]PB=(x2,y2)
+            let s = (public_key * *k).to_affine().to_encoded_point(false);
+            let (x2, y2) = coordinates(&s)?;
+
+            // A5: calculate t=KDF(x2||y2, klen), return to A1 if t is all zeroes
+            kdf(&[x2, y2], &mut t)?;
+
+            if t.iter().all(|&b| b == 0) {
+                continue;
+            }
+
+            // A6: calculate C2=M^t
+            let c2 = msg.iter().zip(&t).map(|(m, t)| m ^ t).collect();
+
+            // A7: calculate C3=Hash(x2||M||y2)
+            let c3 = Sm3::new()
+                .chain_update(x2)
+                .chain_update(msg)
+                .chain_update(y2)
+                .finalize();
+
+            return Ok((c1, c2, c3));
+        }
+    }
+}
+
+impl From<PublicKey> for EncryptingKey {
+    fn from(public_key: PublicKey) -> Self {
+        Self::new(public_key)
+    }
+}
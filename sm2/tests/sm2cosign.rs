@@ -0,0 +1,154 @@
+//! Two-party cooperative SM2DSA signing tests.
+
+#![cfg(feature = "dsa")]
+
+use elliptic_curve::ops::Reduce;
+use hex_literal::hex;
+use proptest::prelude::*;
+use rand_core::OsRng;
+use sm2::{
+    dsa::{
+        cosign::{ClientKeyShare, ServerKeyShare, ServerResponse},
+        signature::Verifier,
+        SigningKey, VerifyingKey,
+    },
+    NonZeroScalar, Scalar, U256,
+};
+
+const IDENTITY: &str = "example@rustcrypto.org";
+const MSG: &[u8] = b"testing";
+
+const CLIENT_SECRET_SHARE: [u8; 32] =
+    hex!("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8");
+const SERVER_SECRET_SHARE: [u8; 32] =
+    hex!("785129917D45A9EA5437A59356B82338EAADDA6CEB199088F14AE10DEFA229B5");
+
+fn key_shares() -> (ClientKeyShare, ServerKeyShare) {
+    let d1 = NonZeroScalar::try_from(&CLIENT_SECRET_SHARE[..]).unwrap();
+    let d2 = NonZeroScalar::try_from(&SERVER_SECRET_SHARE[..]).unwrap();
+    (
+        ClientKeyShare::from_nonzero_scalar(d1),
+        ServerKeyShare::from_nonzero_scalar(d2),
+    )
+}
+
+fn joint_verifying_key(client: &ClientKeyShare, server: &ServerKeyShare) -> VerifyingKey {
+    let public_key = client.public_key(server.public_share()).unwrap();
+    VerifyingKey::new(IDENTITY, public_key).unwrap()
+}
+
+#[test]
+fn joint_public_key() {
+    let (client, server) = key_shares();
+    let client_public_key = client.public_key(server.public_share()).unwrap();
+    let server_public_key = server.public_key(client.public_share()).unwrap();
+    assert_eq!(client_public_key, server_public_key);
+
+    // d=(d1*d2)^-1-1
+    let d1 = NonZeroScalar::try_from(&CLIENT_SECRET_SHARE[..]).unwrap();
+    let d2 = NonZeroScalar::try_from(&SERVER_SECRET_SHARE[..]).unwrap();
+    let d = (*d1 * *d2).invert().unwrap() - Scalar::ONE;
+    let signing_key =
+        SigningKey::from_nonzero_scalar(IDENTITY, NonZeroScalar::new(d).unwrap()).unwrap();
+
+    assert_eq!(
+        signing_key.verifying_key().as_affine(),
+        client_public_key.as_affine()
+    );
+}
+
+#[test]
+fn sign_and_verify() {
+    let (client, server) = key_shares();
+    let verifying_key = joint_verifying_key(&client, &server);
+
+    let signer = client.sign(&mut OsRng, &verifying_key, MSG);
+    let response = server
+        .respond(&mut OsRng, signer.prehash(), signer.commitment())
+        .unwrap();
+    let signature = signer.finish(&response).unwrap();
+
+    assert!(verifying_key.verify(MSG, &signature).is_ok());
+}
+
+#[test]
+fn reject_tampered_response() {
+    let (client, server) = key_shares();
+    let verifying_key = joint_verifying_key(&client, &server);
+
+    let signer = client.sign(&mut OsRng, &verifying_key, MSG);
+    let response = server
+        .respond(&mut OsRng, signer.prehash(), signer.commitment())
+        .unwrap();
+
+    // tweak `s2`
+    let mut response_bytes = response.to_bytes();
+    response_bytes[63] ^= 1;
+    let response = ServerResponse::from_bytes(&response_bytes).unwrap();
+
+    assert!(signer.finish(&response).is_err());
+}
+
+#[test]
+fn reject_response_for_other_message() {
+    let (client, server) = key_shares();
+    let verifying_key = joint_verifying_key(&client, &server);
+
+    let signer = client.sign(&mut OsRng, &verifying_key, MSG);
+    let other_signer = client.sign(&mut OsRng, &verifying_key, b"other message");
+    let response = server
+        .respond(&mut OsRng, other_signer.prehash(), signer.commitment())
+        .unwrap();
+
+    assert!(signer.finish(&response).is_err());
+}
+
+#[test]
+fn server_response_encoding() {
+    let (client, server) = key_shares();
+    let verifying_key = joint_verifying_key(&client, &server);
+
+    let signer = client.sign(&mut OsRng, &verifying_key, MSG);
+    let response = server
+        .respond(&mut OsRng, signer.prehash(), signer.commitment())
+        .unwrap();
+    assert_eq!(
+        ServerResponse::from_bytes(&response.to_bytes()).unwrap(),
+        response
+    );
+
+    // scalars must be reduced
+    assert!(ServerResponse::from_bytes(&[0xFF; ServerResponse::BYTE_SIZE]).is_err());
+}
+
+fn nonzero_scalar(bytes: [u8; 32]) -> NonZeroScalar {
+    let scalar = <Scalar as Reduce<U256>>::reduce_bytes(&bytes.into());
+    NonZeroScalar::new(scalar).unwrap()
+}
+
+prop_compose! {
+    fn client_key_share()(bytes in any::<[u8; 32]>()) -> ClientKeyShare {
+        ClientKeyShare::from_nonzero_scalar(nonzero_scalar(bytes))
+    }
+}
+
+prop_compose! {
+    fn server_key_share()(bytes in any::<[u8; 32]>()) -> ServerKeyShare {
+        ServerKeyShare::from_nonzero_scalar(nonzero_scalar(bytes))
+    }
+}
+
+proptest! {
+    #[test]
+    fn cosign_and_verify(client in client_key_share(), server in server_key_share()) {
+        let verifying_key = joint_verifying_key(&client, &server);
+
+        let signer = client.sign(&mut OsRng, &verifying_key, MSG);
+        let response = server
+            .respond(&mut OsRng, signer.prehash(), signer.commitment())
+            .unwrap();
+        let signature = signer.finish(&response).unwrap();
+
+        prop_assert!(verifying_key.verify(MSG, &signature).is_ok());
+    }
+}
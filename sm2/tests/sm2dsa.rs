@@ -4,14 +4,19 @@
 
 use elliptic_curve::ops::Reduce;
 use hex_literal::hex;
+use primeorder::dev::FixedRng;
 use proptest::prelude::*;
+use rand_core::OsRng;
 use sm2::{
     dsa::{
-        signature::{Signer, Verifier},
-        Signature, SigningKey, VerifyingKey,
+        signature::{
+            RandomizedDigestSigner, RandomizedSigner, SignatureEncoding, Signer, Verifier,
+        },
+        DerSignature, Signature, SigningKey, VerifyingKey,
     },
-    NonZeroScalar, Scalar, U256,
+    hash_z, NonZeroScalar, PublicKey, Scalar, DEFAULT_DISTID, U256,
 };
+use sm3::{Digest, Sm3};
 
 const PUBLIC_KEY: [u8; 65] = hex!("0408D77AE04C01CC4C1104360DD8AF6B6F7DF334283D7C1A6AFD5652407B87BEE5014E2A57C36C150D16324DC664E31E6432359609C4E79847A5B161C8C7364C8A");
 const IDENTITY: &str = "example@rustcrypto.org";
@@ -24,6 +29,41 @@ const SIG: [u8; 64] = hex!(
     "f7d1eae6e3a157b36c65f672f738ca8b46298bf149a6510072c431b49cd88b1c" // s
 );
 
+// Signature example on the recommended curve parameters from GB/T 32918
+mod gbt32918 {
+    use hex_literal::hex;
+
+    pub const IDENTITY: &str = "1234567812345678";
+    pub const MSG: &[u8] = b"message digest";
+
+    pub const SECRET_KEY: [u8; 32] =
+        hex!("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8");
+    pub const PUBLIC_KEY: [u8; 65] = hex!(
+        "04"
+        "09F9DF311E5421A150DD7D161E4BC5C672179FAD1833FC076BB08FF356F35020"
+        "CCEA490CE26775A52DC6EA718CC1AA600AED05FBF35E084A6632F6072DA9AD13"
+    );
+
+    /// User information hash `ZA`.
+    pub const Z: [u8; 32] =
+        hex!("B2E14C5C79C6DF5B85F4FE7ED8DB7A262B9DA7E07CCB0EA9F4747B8CCDA8A4F3");
+
+    /// Ephemeral scalar `k`.
+    pub const K: [u8; 32] =
+        hex!("59276E27D506861A16680F3AD9C02DCCEF3CC1FA3CDBE4CE6D54B80DEAC1BC21");
+
+    pub const SIG: [u8; 64] = hex!(
+        "F5A03B0648D2C4630EEAC513E1BB81A15944DA3827D5B74143AC7EACEEE720B3" // r
+        "B1B6AA29DF212FD8763182BC0D421CA1BB9038FD1F7F42D4840B69C485BBC1AA" // s
+    );
+
+    pub const DER_SIG: [u8; 72] = hex!(
+        "3046"
+        "022100F5A03B0648D2C4630EEAC513E1BB81A15944DA3827D5B74143AC7EACEEE720B3"
+        "022100B1B6AA29DF212FD8763182BC0D421CA1BB9038FD1F7F42D4840B69C485BBC1AA"
+    );
+}
+
 #[test]
 fn verify_test_vector() {
     let vk = VerifyingKey::from_sec1_bytes(IDENTITY, &PUBLIC_KEY).unwrap();
@@ -31,6 +71,141 @@ fn verify_test_vector() {
     assert!(vk.verify(MSG, &sig).is_ok());
 }
 
+#[test]
+fn verify_der_test_vector() {
+    let vk = VerifyingKey::from_sec1_bytes(IDENTITY, &PUBLIC_KEY).unwrap();
+    let sig = Signature::try_from(&SIG).unwrap().to_der();
+    assert!(vk.verify(MSG, &sig).is_ok());
+}
+
+#[test]
+fn sign_randomized_test_vector() {
+    let sk = SigningKey::from_bytes(gbt32918::IDENTITY, &gbt32918::SECRET_KEY.into()).unwrap();
+    assert_eq!(
+        sk.verifying_key().to_sec1_bytes().as_ref(),
+        &gbt32918::PUBLIC_KEY[..]
+    );
+
+    let sig: Signature = sk.sign_with_rng(&mut FixedRng(&gbt32918::K), gbt32918::MSG);
+    assert_eq!(sig.to_bytes(), gbt32918::SIG);
+    assert!(sk.verifying_key().verify(gbt32918::MSG, &sig).is_ok());
+
+    let der_sig: DerSignature = sk.sign_with_rng(&mut FixedRng(&gbt32918::K), gbt32918::MSG);
+    assert_eq!(der_sig.as_bytes(), &gbt32918::DER_SIG[..]);
+    assert!(sk.verifying_key().verify(gbt32918::MSG, &der_sig).is_ok());
+}
+
+#[test]
+fn sign_digest_randomized_test_vector() {
+    let sk = SigningKey::from_bytes(gbt32918::IDENTITY, &gbt32918::SECRET_KEY.into()).unwrap();
+    let digest = Sm3::new_with_prefix(gbt32918::Z).chain_update(gbt32918::MSG);
+
+    let sig: Signature = sk.sign_digest_with_rng(&mut FixedRng(&gbt32918::K), digest);
+    assert_eq!(sig.to_bytes(), gbt32918::SIG);
+}
+
+#[test]
+fn hash_z_test_vector() {
+    assert_eq!(DEFAULT_DISTID, gbt32918::IDENTITY.as_bytes());
+
+    let public_key = PublicKey::from_sec1_bytes(&gbt32918::PUBLIC_KEY).unwrap();
+    assert_eq!(
+        hash_z(DEFAULT_DISTID, &public_key).unwrap(),
+        gbt32918::Z.into()
+    );
+}
+
+#[test]
+fn sign_digest_streaming() {
+    let sk = SigningKey::from_bytes(DEFAULT_DISTID, &gbt32918::SECRET_KEY.into()).unwrap();
+    let z = hash_z(DEFAULT_DISTID, sk.verifying_key()).unwrap();
+
+    let mut digest = Sm3::new_with_prefix(z);
+    for chunk in gbt32918::MSG.chunks(3) {
+        digest.update(chunk);
+    }
+
+    let sig: Signature = sk.sign_digest_with_rng(&mut OsRng, digest);
+    assert!(sk.verifying_key().verify(gbt32918::MSG, &sig).is_ok());
+}
+
+#[test]
+fn distid_accessors() {
+    let sk = SigningKey::from_bytes(gbt32918::IDENTITY, &gbt32918::SECRET_KEY.into()).unwrap();
+    assert_eq!(sk.distid(), gbt32918::IDENTITY);
+    assert_eq!(sk.distid_bytes(), gbt32918::IDENTITY.as_bytes());
+}
+
+#[test]
+fn binary_distid() {
+    // not valid UTF-8
+    let distid = hex!("FF00FE80C0");
+    let sk = SigningKey::from_bytes(distid, &gbt32918::SECRET_KEY.into()).unwrap();
+    assert_eq!(sk.distid_bytes(), &distid[..]);
+
+    let sig = sk.sign(MSG);
+    assert!(sk.verifying_key().verify(MSG, &sig).is_ok());
+
+    let vk = VerifyingKey::from_sec1_bytes(DEFAULT_DISTID, &gbt32918::PUBLIC_KEY).unwrap();
+    assert!(vk.verify(MSG, &sig).is_err());
+}
+
+#[test]
+fn distid_length_limit() {
+    let distid = [0x42; 8192];
+    assert!(VerifyingKey::from_sec1_bytes(&distid[..8191], &PUBLIC_KEY).is_ok());
+    assert!(VerifyingKey::from_sec1_bytes(distid, &PUBLIC_KEY).is_err());
+}
+
+#[test]
+fn der_signature_test_vector() {
+    let sig = Signature::try_from(&gbt32918::SIG).unwrap();
+    let der_sig = DerSignature::try_from(&gbt32918::DER_SIG[..]).unwrap();
+
+    assert_eq!(sig.to_der(), der_sig);
+    assert_eq!(der_sig.to_vec(), gbt32918::DER_SIG);
+    assert_eq!(der_sig.encoded_len(), gbt32918::DER_SIG.len());
+    assert_eq!(Signature::from_der(&gbt32918::DER_SIG).unwrap(), sig);
+    assert_eq!(Signature::try_from(der_sig).unwrap(), sig);
+}
+
+#[test]
+fn der_signature_short_integers() {
+    let sig = Signature::from_scalars(Scalar::ONE, Scalar::from(0x80u64)).unwrap();
+    let der_sig = sig.to_der();
+
+    assert_eq!(der_sig.as_bytes(), hex!("300702010102020080").as_slice());
+    assert_eq!(Signature::try_from(der_sig).unwrap(), sig);
+}
+
+#[test]
+fn reject_invalid_der_signature() {
+    // Empty and truncated input
+    assert!(DerSignature::try_from(&[][..]).is_err());
+    assert!(DerSignature::try_from(&gbt32918::DER_SIG[..71]).is_err());
+
+    // Trailing data
+    let mut trailing = gbt32918::DER_SIG.to_vec();
+    trailing.push(0);
+    assert!(DerSignature::try_from(trailing.as_slice()).is_err());
+
+    // Non-minimal length encoding
+    assert!(DerSignature::try_from(&hex!("30810602010102010101")[..]).is_err());
+
+    // `r = 0`
+    assert!(DerSignature::try_from(&hex!("3006020100020101")[..]).is_err());
+
+    // `r = n`
+    assert!(DerSignature::try_from(
+        &hex!(
+            "3026"
+            "022100FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54123"
+            "020101"
+        )[..]
+    )
+    .is_err());
+}
+
 prop_compose! {
     fn signing_key()(bytes in any::<[u8; 32]>()) -> SigningKey {
         loop {
@@ -49,6 +224,19 @@ proptest! {
         prop_assert!(sk.verifying_key().verify(MSG, &signature).is_ok());
     }
 
+    #[test]
+    fn sign_randomized_and_verify(sk in signing_key()) {
+        let signature: Signature = sk.sign_with_rng(&mut OsRng, MSG);
+        prop_assert!(sk.verifying_key().verify(MSG, &signature).is_ok());
+    }
+
+    #[test]
+    fn der_signature_roundtrip(sk in signing_key()) {
+        let signature: Signature = sk.sign(MSG);
+        let der_signature = DerSignature::try_from(signature.to_der().as_bytes()).unwrap();
+        prop_assert_eq!(Signature::try_from(der_signature).unwrap(), signature);
+    }
+
     #[test]
     fn reject_invalid_signature(sk in signing_key(), byte in 0usize..32, bit in 0usize..8) {
         let mut signature_bytes = sk.sign(MSG).to_bytes();
@@ -0,0 +1,189 @@
+//! SM2KEP tests.
+
+#![cfg(feature = "kex")]
+
+use elliptic_curve::rand_core::OsRng;
+use hex_literal::hex;
+use primeorder::dev::FixedRng;
+use proptest::prelude::*;
+use sm2::{
+    kex::{Initiator, Responder},
+    PublicKey, SecretKey,
+};
+
+// Key exchange example from GM/T 0003.5-2012 using the recommended curve,
+// where both parties use the default distinguishing identifier
+const INITIATOR_DISTID: &str = "1234567812345678";
+const INITIATOR_SECRET_KEY: [u8; 32] =
+    hex!("81EB26E941BB5AF16DF116495F90695272AE2CD63D6C4AE1678418BE48230029");
+const INITIATOR_PUBLIC_KEY: [u8; 65] = hex!(
+    "04"
+    "160E12897DF4EDB61DD812FEB96748FBD3CCF4FFE26AA6F6DB9540AF49C94232"
+    "4A7DAD08BB9A459531694BEB20AA489D6649975E1BFCF8C4741B78B4B223007F"
+);
+const INITIATOR_EPHEMERAL_SECRET_KEY: [u8; 32] =
+    hex!("D4DE15474DB74D06491C440D305E012400990F3E390C7E87153C12DB2EA60BB3");
+const INITIATOR_EPHEMERAL_PUBLIC_KEY: [u8; 65] = hex!(
+    "04"
+    "64CED1BDBC99D590049B434D0FD73428CF608A5DB8FE5CE07F15026940BAE40E"
+    "376629C7AB21E7DB260922499DDB118F07CE8EAAE3E7720AFEF6A5CC062070C0"
+);
+
+const RESPONDER_DISTID: &str = "1234567812345678";
+const RESPONDER_SECRET_KEY: [u8; 32] =
+    hex!("785129917D45A9EA5437A59356B82338EAADDA6CEB199088F14AE10DEFA229B5");
+const RESPONDER_PUBLIC_KEY: [u8; 65] = hex!(
+    "04"
+    "6AE848C57C53C7B1B5FA99EB2286AF078BA64C64591B8B566F7357D576F16DFB"
+    "EE489D771621A27B36C5C7992062E9CD09A9264386F3FBEA54DFF69305621C4D"
+);
+const RESPONDER_EPHEMERAL_SECRET_KEY: [u8; 32] =
+    hex!("7E07124814B309489125EAED101113164EBF0F3458C5BD88335C1F9D596243D6");
+const RESPONDER_EPHEMERAL_PUBLIC_KEY: [u8; 65] = hex!(
+    "04"
+    "ACC27688A6F7B706098BC91FF3AD1BFF7DC2802CDB14CCCCDB0A90471F9BD707"
+    "2FEDAC0494B2FFC4D6853876C79B8F301C6573AD0AA50F39FC87181E1A1B46FE"
+);
+
+const KEY: [u8; 16] = hex!("6C89347354DE2484C60B4AB1FDE4C6E5");
+const RESPONDER_CONFIRMATION: [u8; 32] =
+    hex!("D3A0FE15DEE185CEAE907A6B595CC32A266ED7B3367E9983A896DC32FA20F8EB");
+const INITIATOR_CONFIRMATION: [u8; 32] =
+    hex!("18C7894B3816DF16CF07B05C5EC0BEF5D655D58F779CC1B400A4F3884644DB88");
+
+const ALICE_DISTID: &str = "alice@rustcrypto.org";
+const BOB_DISTID: &str = "bob@rustcrypto.org";
+
+#[test]
+fn key_exchange_test_vector() {
+    let initiator_secret_key = SecretKey::from_slice(&INITIATOR_SECRET_KEY).unwrap();
+    let initiator_public_key = PublicKey::from_sec1_bytes(&INITIATOR_PUBLIC_KEY).unwrap();
+    assert_eq!(initiator_secret_key.public_key(), initiator_public_key);
+
+    let responder_secret_key = SecretKey::from_slice(&RESPONDER_SECRET_KEY).unwrap();
+    let responder_public_key = PublicKey::from_sec1_bytes(&RESPONDER_PUBLIC_KEY).unwrap();
+    assert_eq!(responder_secret_key.public_key(), responder_public_key);
+
+    let initiator = Initiator::new(
+        &mut FixedRng(&INITIATOR_EPHEMERAL_SECRET_KEY),
+        INITIATOR_DISTID,
+        &initiator_secret_key,
+        RESPONDER_DISTID,
+        &responder_public_key,
+    )
+    .unwrap();
+    assert_eq!(
+        initiator.ephemeral_public_key().to_sec1_bytes().as_ref(),
+        INITIATOR_EPHEMERAL_PUBLIC_KEY
+    );
+
+    let mut responder_key = [0u8; 16];
+    let responder = Responder::new(
+        &mut FixedRng(&RESPONDER_EPHEMERAL_SECRET_KEY),
+        RESPONDER_DISTID,
+        &responder_secret_key,
+        INITIATOR_DISTID,
+        &initiator_public_key,
+        initiator.ephemeral_public_key(),
+        &mut responder_key,
+    )
+    .unwrap();
+    assert_eq!(
+        responder.ephemeral_public_key().to_sec1_bytes().as_ref(),
+        RESPONDER_EPHEMERAL_PUBLIC_KEY
+    );
+    assert_eq!(responder_key, KEY);
+    assert_eq!(responder.confirmation(), &RESPONDER_CONFIRMATION);
+
+    let mut initiator_key = [0u8; 16];
+    let initiator_confirmation = initiator
+        .finish(
+            responder.ephemeral_public_key(),
+            Some(responder.confirmation()),
+            &mut initiator_key,
+        )
+        .unwrap();
+    assert_eq!(initiator_key, KEY);
+    assert_eq!(initiator_confirmation, INITIATOR_CONFIRMATION);
+
+    responder.confirm(&initiator_confirmation).unwrap();
+}
+
+fn secret_key() -> impl Strategy<Value = SecretKey> {
+    any::<[u8; 32]>().prop_filter_map("invalid secret key", |bytes| {
+        SecretKey::from_slice(&bytes).ok()
+    })
+}
+
+proptest! {
+    #[test]
+    fn key_exchange(initiator_secret_key in secret_key(), responder_secret_key in secret_key(), key_len in 1usize..100) {
+        let initiator = Initiator::new(
+            &mut OsRng,
+            ALICE_DISTID,
+            &initiator_secret_key,
+            BOB_DISTID,
+            &responder_secret_key.public_key(),
+        )
+        .unwrap();
+
+        let mut responder_key = vec![0u8; key_len];
+        let responder = Responder::new(
+            &mut OsRng,
+            BOB_DISTID,
+            &responder_secret_key,
+            ALICE_DISTID,
+            &initiator_secret_key.public_key(),
+            initiator.ephemeral_public_key(),
+            &mut responder_key,
+        )
+        .unwrap();
+
+        let mut initiator_key = vec![0u8; key_len];
+        let initiator_confirmation = initiator
+            .finish(
+                responder.ephemeral_public_key(),
+                Some(responder.confirmation()),
+                &mut initiator_key,
+            )
+            .unwrap();
+
+        prop_assert_eq!(initiator_key, responder_key);
+        prop_assert!(responder.confirm(&initiator_confirmation).is_ok());
+    }
+
+    #[test]
+    fn reject_wrong_distid(initiator_secret_key in secret_key(), responder_secret_key in secret_key()) {
+        let initiator = Initiator::new(
+            &mut OsRng,
+            ALICE_DISTID,
+            &initiator_secret_key,
+            BOB_DISTID,
+            &responder_secret_key.public_key(),
+        )
+        .unwrap();
+
+        // the responder believes it's talking to someone else
+        let mut responder_key = [0u8; 16];
+        let responder = Responder::new(
+            &mut OsRng,
+            BOB_DISTID,
+            &responder_secret_key,
+            "mallory@rustcrypto.org",
+            &initiator_secret_key.public_key(),
+            initiator.ephemeral_public_key(),
+            &mut responder_key,
+        )
+        .unwrap();
+
+        let mut initiator_key = [0u8; 16];
+        prop_assert!(initiator
+            .finish(
+                responder.ephemeral_public_key(),
+                Some(responder.confirmation()),
+                &mut initiator_key,
+            )
+            .is_err());
+        prop_assert!(responder.confirm(&[0u8; 32]).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+//! SM2PKE tests.
+
+#![cfg(feature = "pke")]
+
+use elliptic_curve::rand_core::OsRng;
+use hex_literal::hex;
+use primeorder::dev::FixedRng;
+use proptest::prelude::*;
+use sm2::pke::{DecryptingKey, EncryptingKey, Mode};
+
+// Encryption example from GB/T 32918.4-2016 using the recommended curve
+const SECRET_KEY: [u8; 32] =
+    hex!("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8");
+const PUBLIC_KEY: [u8; 65] = hex!(
+    "04"
+    "09F9DF311E5421A150DD7D161E4BC5C672179FAD1833FC076BB08FF356F35020"
+    "CCEA490CE26775A52DC6EA718CC1AA600AED05FBF35E084A6632F6072DA9AD13"
+);
+const MSG: &[u8] = b"encryption standard";
+const K: [u8; 32] = hex!("59276E27D506861A16680F3AD9C02DCCEF3CC1FA3CDBE4CE6D54B80DEAC1BC21");
+const C1: [u8; 65] = hex!(
+    "04"
+    "04EBFC718E8D1798620432268E77FEB6415E2EDE0E073C0F4F640ECD2E149A73"
+    "E858F9D81E5430A57B36DAAB8F950A3C64E6EE6A63094D99283AFF767E124DF0"
+);
+const C2: [u8; 19] = hex!("21886CA989CA9C7D58087307CA93092D651EFA");
+const C3: [u8; 32] = hex!("59983C18F809E262923C53AEC295D30383B54E39D609D160AFCB1908D0BD8766");
+
+#[test]
+fn encrypt_test_vector() {
+    let dk = DecryptingKey::from_slice(&SECRET_KEY).unwrap();
+    assert_eq!(
+        dk.encrypting_key().public_key().to_sec1_bytes().as_ref(),
+        PUBLIC_KEY
+    );
+
+    let ek = EncryptingKey::from_sec1_bytes(&PUBLIC_KEY).unwrap();
+    let ciphertext = ek.encrypt(&mut FixedRng(&K), MSG).unwrap();
+    assert_eq!(ciphertext, [&C1[..], &C3, &C2].concat());
+
+    let ek = EncryptingKey::new_with_mode(*ek.public_key(), Mode::C1C2C3);
+    let ciphertext = ek.encrypt(&mut FixedRng(&K), MSG).unwrap();
+    assert_eq!(ciphertext, [&C1[..], &C2, &C3].concat());
+}
+
+#[test]
+fn decrypt_test_vector() {
+    let dk = DecryptingKey::from_slice(&SECRET_KEY).unwrap();
+    assert_eq!(dk.decrypt(&[&C1[..], &C3, &C2].concat()).unwrap(), MSG);
+
+    let secret_key = sm2::SecretKey::from_slice(&SECRET_KEY).unwrap();
+    let dk = DecryptingKey::new_with_mode(&secret_key, Mode::C1C2C3);
+    assert_eq!(dk.decrypt(&[&C1[..], &C2, &C3].concat()).unwrap(), MSG);
+}
+
+#[test]
+fn der_test_vector() {
+    let ek = EncryptingKey::from_sec1_bytes(&PUBLIC_KEY).unwrap();
+    let ciphertext = ek.encrypt_der(&mut FixedRng(&K), MSG).unwrap();
+
+    // SEQUENCE { INTEGER x, INTEGER y, OCTET STRING C3, OCTET STRING C2 },
+    // where y needs a leading zero byte to be positive
+    let expected = [
+        &hex!("307C 0220")[..],
+        &C1[1..33],
+        &hex!("0221 00"),
+        &C1[33..],
+        &hex!("0420"),
+        &C3,
+        &hex!("0413"),
+        &C2,
+    ]
+    .concat();
+    assert_eq!(ciphertext, expected);
+
+    let dk = DecryptingKey::from_slice(&SECRET_KEY).unwrap();
+    assert_eq!(dk.decrypt_der(&ciphertext).unwrap(), MSG);
+}
+
+#[test]
+fn reject_empty_message() {
+    let ek = EncryptingKey::from_sec1_bytes(&PUBLIC_KEY).unwrap();
+    assert!(ek.encrypt(&mut OsRng, &[]).is_err());
+}
+
+fn decrypting_key() -> impl Strategy<Value = DecryptingKey> {
+    any::<[u8; 32]>().prop_filter_map("invalid secret key", |bytes| {
+        DecryptingKey::from_slice(&bytes).ok()
+    })
+}
+
+proptest! {
+    #[test]
+    fn encrypt_and_decrypt(dk in decrypting_key(), msg in prop::collection::vec(any::<u8>(), 1..100)) {
+        let ek = dk.encrypting_key();
+        let ciphertext = ek.encrypt(&mut OsRng, &msg).unwrap();
+        prop_assert_eq!(dk.decrypt(&ciphertext).unwrap(), msg.clone());
+
+        let ciphertext = ek.encrypt_der(&mut OsRng, &msg).unwrap();
+        prop_assert_eq!(dk.decrypt_der(&ciphertext).unwrap(), msg);
+    }
+
+    #[test]
+    fn reject_invalid_ciphertext(dk in decrypting_key(), byte in 65usize..(65 + 32 + 19), bit in 0usize..8) {
+        let mut ciphertext = dk.encrypting_key().encrypt(&mut OsRng, MSG).unwrap();
+
+        // tweak C3 or C2 to make the ciphertext invalid
+        ciphertext[byte] ^= 1 << bit;
+
+        prop_assert!(dk.decrypt(&ciphertext).is_err());
+    }
+}